@@ -0,0 +1,57 @@
+//! Compares `IndexedBTreeGraph::insert_graph` with repeated `insert`.
+//!
+//! Run with `cargo bench --bench insert_graph`.
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+use rdf_types::{dataset::IndexedBTreeGraph, Triple};
+use std::time::{Duration, Instant};
+
+const RUNS: u32 = 5;
+
+fn generate(rng: &mut SmallRng, len: usize, resources: u32) -> IndexedBTreeGraph<u32> {
+	(0..len)
+		.map(|_| {
+			Triple(
+				rng.gen_range(0..resources),
+				rng.gen_range(0..64),
+				rng.gen_range(0..resources),
+			)
+		})
+		.collect()
+}
+
+fn measure(base: &IndexedBTreeGraph<u32>, f: impl Fn(&mut IndexedBTreeGraph<u32>)) -> Duration {
+	let mut total = Duration::ZERO;
+	for _ in 0..RUNS {
+		let mut graph = base.clone();
+		let start = Instant::now();
+		f(&mut graph);
+		total += start.elapsed();
+		drop(graph)
+	}
+
+	total / RUNS
+}
+
+fn main() {
+	let mut rng = SmallRng::seed_from_u64(0);
+	let base = generate(&mut rng, 1_000_000, 250_000);
+
+	for len in [100, 10_000, 1_000_000] {
+		// Half of the delta is already in the base graph.
+		let mut delta = generate(&mut rng, len / 2, 250_000);
+		delta.extend(base.iter().take(len / 2).map(|t| t.cloned()));
+
+		let insert = measure(&base, |graph| {
+			for triple in &delta {
+				graph.insert(triple.cloned());
+			}
+		});
+		let insert_graph = measure(&base, |graph| {
+			graph.insert_graph(&delta);
+		});
+		println!(
+			"{len:>9} triples: insert {insert:>10.2?}, insert_graph {insert_graph:>10.2?} ({:.2}x)",
+			insert.as_secs_f64() / insert_graph.as_secs_f64()
+		)
+	}
+}
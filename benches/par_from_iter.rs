@@ -0,0 +1,47 @@
+//! Compares `IndexedBTreeDataset::par_from_iter` with `from_iter`.
+//!
+//! Run with `cargo bench --features rayon --bench par_from_iter`.
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+use rdf_types::{dataset::IndexedBTreeDataset, Quad};
+use std::time::{Duration, Instant};
+
+const RUNS: u32 = 5;
+
+fn generate(len: usize) -> Vec<Quad<u32>> {
+	let mut rng = SmallRng::seed_from_u64(0);
+	(0..len)
+		.map(|_| {
+			Quad(
+				rng.gen_range(0..len as u32 / 4),
+				rng.gen_range(0..64),
+				rng.gen_range(0..len as u32 / 4),
+				Some(rng.gen_range(0..16)),
+			)
+		})
+		.collect()
+}
+
+fn measure(f: impl Fn() -> IndexedBTreeDataset<u32>) -> Duration {
+	let mut total = Duration::ZERO;
+	for _ in 0..RUNS {
+		let start = Instant::now();
+		let dataset = f();
+		total += start.elapsed();
+		drop(dataset)
+	}
+
+	total / RUNS
+}
+
+fn main() {
+	println!("threads: {}", rayon::current_num_threads());
+	for len in [10_000, 100_000, 1_000_000] {
+		let quads = generate(len);
+		let sequential = measure(|| quads.iter().copied().collect());
+		let parallel = measure(|| IndexedBTreeDataset::par_from_iter(quads.clone()));
+		println!(
+			"{len:>9} quads: from_iter {sequential:>10.2?}, par_from_iter {parallel:>10.2?} ({:.2}x)",
+			sequential.as_secs_f64() / parallel.as_secs_f64()
+		)
+	}
+}
@@ -0,0 +1,91 @@
+//! Compares `RdfDisplay::rdf_fmt_into` with `rdf_display().to_string()`.
+//!
+//! Run with `cargo bench --bench rdf_fmt_into`.
+use iref::IriBuf;
+use rdf_types::{BlankIdBuf, Literal, LiteralType, RdfDisplay, Term, XSD_STRING};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+const RUNS: u32 = 5;
+
+/// Allocator counting the number of allocations.
+struct Counting;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+	unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+		ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+		System.alloc(layout)
+	}
+
+	unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+		System.dealloc(ptr, layout)
+	}
+
+	unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+		ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+		System.realloc(ptr, layout, new_size)
+	}
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+fn generate(len: usize) -> Vec<Term> {
+	(0..len)
+		.map(|i| match i % 3 {
+			0 => Term::iri(IriBuf::new(format!("http://example.org/{i}")).unwrap()),
+			1 => Term::blank(BlankIdBuf::from_u32(i as u32)),
+			_ => Term::Literal(Literal::new(
+				format!("value \"{i}\""),
+				LiteralType::Any(XSD_STRING.to_owned()),
+			)),
+		})
+		.collect()
+}
+
+/// Returns the average duration and number of allocations of `f`.
+fn measure(f: impl Fn() -> usize) -> (Duration, usize) {
+	let mut total = Duration::ZERO;
+	let mut allocations = 0;
+	for _ in 0..RUNS {
+		let before = ALLOCATIONS.load(Ordering::Relaxed);
+		let start = Instant::now();
+		std::hint::black_box(f());
+		total += start.elapsed();
+		allocations += ALLOCATIONS.load(Ordering::Relaxed) - before
+	}
+
+	(total / RUNS, allocations / RUNS as usize)
+}
+
+fn main() {
+	for len in [10_000, 100_000, 1_000_000] {
+		let terms = generate(len);
+
+		let (to_string, to_string_allocations) = measure(|| {
+			terms
+				.iter()
+				.map(|term| term.rdf_display().to_string().len())
+				.sum()
+		});
+
+		let (fmt_into, fmt_into_allocations) = measure(|| {
+			let mut buffer = String::new();
+			terms
+				.iter()
+				.map(|term| {
+					buffer.clear();
+					term.rdf_fmt_into(&mut buffer).unwrap();
+					buffer.len()
+				})
+				.sum()
+		});
+
+		println!(
+			"{len:>9} terms: to_string {to_string:>10.2?} ({to_string_allocations} allocations), rdf_fmt_into {fmt_into:>10.2?} ({fmt_into_allocations} allocations)"
+		)
+	}
+}
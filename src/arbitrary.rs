@@ -0,0 +1,154 @@
+//! [`Arbitrary`] implementations for fuzzing.
+//!
+//! Generated IRIs, blank node identifiers and language tags are always
+//! valid.
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+use iref::IriBuf;
+use langtag::LangTagBuf;
+
+use crate::{BlankIdBuf, Direction, Id, Literal, LiteralType, Term};
+
+const LOWER_ALPHANUM: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789";
+
+const LOWER_ALPHA: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+
+const UPPER_ALPHA: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+const XSD_DATATYPES: &[&str] = &[
+	"http://www.w3.org/2001/XMLSchema#string",
+	"http://www.w3.org/2001/XMLSchema#boolean",
+	"http://www.w3.org/2001/XMLSchema#integer",
+	"http://www.w3.org/2001/XMLSchema#decimal",
+	"http://www.w3.org/2001/XMLSchema#double",
+	"http://www.w3.org/2001/XMLSchema#dateTime",
+];
+
+fn arbitrary_word(u: &mut Unstructured, alphabet: &[u8], min: usize, max: usize) -> Result<String> {
+	let len = u.int_in_range(min..=max)?;
+	let mut result = String::with_capacity(len);
+	for _ in 0..len {
+		result.push(*u.choose(alphabet)? as char)
+	}
+
+	Ok(result)
+}
+
+fn arbitrary_iri(u: &mut Unstructured) -> Result<IriBuf> {
+	let iri = match u.int_in_range(0u8..=2)? {
+		0 => format!(
+			"http://{}.org/{}",
+			arbitrary_word(u, LOWER_ALPHANUM, 1, 8)?,
+			arbitrary_word(u, LOWER_ALPHANUM, 0, 8)?
+		),
+		1 => format!(
+			"https://example.com/{}#{}",
+			arbitrary_word(u, LOWER_ALPHANUM, 0, 8)?,
+			arbitrary_word(u, LOWER_ALPHANUM, 0, 8)?
+		),
+		_ => format!("urn:{}", arbitrary_word(u, LOWER_ALPHANUM, 1, 16)?),
+	};
+
+	Ok(IriBuf::new(iri).unwrap())
+}
+
+fn arbitrary_lang_tag(u: &mut Unstructured) -> Result<LangTagBuf> {
+	let mut tag = arbitrary_word(u, LOWER_ALPHA, 2, 3)?;
+
+	if bool::arbitrary(u)? {
+		tag.push('-');
+		tag.push_str(&arbitrary_word(u, UPPER_ALPHA, 2, 2)?);
+	}
+
+	Ok(LangTagBuf::new(tag).unwrap())
+}
+
+impl<'a> Arbitrary<'a> for BlankIdBuf {
+	fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+		if bool::arbitrary(u)? {
+			Ok(Self::from_u32(u.int_in_range(0..=64)?))
+		} else {
+			Ok(Self::from_external(&String::arbitrary(u)?))
+		}
+	}
+}
+
+impl<'a> Arbitrary<'a> for Id {
+	fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+		if bool::arbitrary(u)? {
+			arbitrary_iri(u).map(Self::Iri)
+		} else {
+			BlankIdBuf::arbitrary(u).map(Self::Blank)
+		}
+	}
+}
+
+impl<'a> Arbitrary<'a> for LiteralType {
+	fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+		match u.int_in_range(0u8..=3)? {
+			0 => Ok(Self::Any(
+				IriBuf::new(u.choose(XSD_DATATYPES)?.to_string()).unwrap(),
+			)),
+			1 => arbitrary_iri(u).map(Self::Any),
+			2 => arbitrary_lang_tag(u).map(Self::LangString),
+			_ => Ok(Self::DirLangString(
+				arbitrary_lang_tag(u)?,
+				*u.choose(&[Direction::Ltr, Direction::Rtl])?,
+			)),
+		}
+	}
+}
+
+impl<'a> Arbitrary<'a> for Literal {
+	fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+		Ok(Self::new(String::arbitrary(u)?, LiteralType::arbitrary(u)?))
+	}
+}
+
+impl<'a> Arbitrary<'a> for Term {
+	fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+		if bool::arbitrary(u)? {
+			Id::arbitrary(u).map(Self::Id)
+		} else {
+			Literal::arbitrary(u).map(Self::Literal)
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use ::arbitrary::{Arbitrary, Unstructured};
+	use rand::{rngs::SmallRng, RngCore, SeedableRng};
+
+	use crate::{BlankId, Id, LiteralType, Quad, Term};
+
+	#[test]
+	fn valid_terms() {
+		let mut rng = SmallRng::from_seed([0; 32]);
+		let mut data = vec![0; 4096];
+
+		for _ in 0..64 {
+			rng.fill_bytes(&mut data);
+			let mut u = Unstructured::new(&data);
+
+			while let Ok(quad) = Quad::<Term>::arbitrary(&mut u) {
+				let Quad(s, p, o, g) = quad;
+				for term in [Some(s), Some(p), Some(o), g].into_iter().flatten() {
+					match term {
+						Term::Id(Id::Iri(iri)) => assert!(iref::Iri::new(iri.as_str()).is_ok()),
+						Term::Id(Id::Blank(b)) => assert!(BlankId::new(b.as_str()).is_ok()),
+						Term::Literal(l) => match l.type_ {
+							LiteralType::Any(iri) => assert!(iref::Iri::new(iri.as_str()).is_ok()),
+							LiteralType::LangString(tag) | LiteralType::DirLangString(tag, _) => {
+								assert!(langtag::LangTag::new(tag.as_str()).is_ok())
+							}
+						},
+					}
+				}
+
+				if u.is_empty() {
+					break;
+				}
+			}
+		}
+	}
+}
@@ -21,6 +21,9 @@ pub struct InvalidBlankId<T>(pub T);
 /// [158s] PN_CHARS_U       ::= PN_CHARS_BASE | '_' | ':'
 /// [160s] PN_CHARS         ::= PN_CHARS_U | '-' | [0-9] | #x00B7 | [#x0300-#x036F] | [#x203F-#x2040]
 /// ```
+///
+/// The [`AsRef<str>`] and [`Borrow<str>`] implementations expose the full
+/// `_:label` form, including the `_:` prefix.
 #[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct BlankId(str);
 
@@ -126,6 +129,20 @@ impl PartialEq<str> for BlankId {
 /// [158s] PN_CHARS_U       ::= PN_CHARS_BASE | '_' | ':'
 /// [160s] PN_CHARS         ::= PN_CHARS_U | '-' | [0-9] | #x00B7 | [#x0300-#x036F] | [#x203F-#x2040]
 /// ```
+///
+/// The [`AsRef<str>`] and [`Borrow<str>`] implementations expose the full
+/// `_:label` form, including the `_:` prefix. Blank node identifiers can hence
+/// be looked up by `&str` in maps keyed by [`BlankIdBuf`].
+///
+/// ```
+/// use std::collections::HashMap;
+/// use rdf_types::BlankIdBuf;
+///
+/// let mut map = HashMap::new();
+/// map.insert(BlankIdBuf::new("_:b0".to_owned()).unwrap(), 0);
+/// assert_eq!(map.get("_:b0"), Some(&0));
+/// assert_eq!(map.get("b0"), None);
+/// ```
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde(transparent))]
 pub struct BlankIdBuf(String);
@@ -181,6 +198,75 @@ impl BlankIdBuf {
 		Self::new(format!("_:{suffix}"))
 	}
 
+	/// Creates a blank node identifier from an arbitrary external
+	/// identifier.
+	///
+	/// The resulting identifier is `_:x` followed by the external identifier
+	/// where every byte that is not an ASCII letter or digit is replaced by
+	/// `_` and its two digit (uppercase) hexadecimal value. This encoding is
+	/// injective: two different external identifiers never give the same
+	/// blank node identifier.
+	///
+	/// ```
+	/// use rdf_types::BlankIdBuf;
+	///
+	/// let b = BlankIdBuf::from_external("user/42");
+	/// assert_eq!(b.as_str(), "_:xuser_2F42");
+	/// ```
+	pub fn from_external(external: &str) -> Self {
+		let mut result = String::with_capacity(3 + external.len());
+		result.push_str("_:x");
+
+		for b in external.bytes() {
+			if b.is_ascii_alphanumeric() {
+				result.push(b as char)
+			} else {
+				result.push('_');
+				result.push(HEX_DIGITS[(b >> 4) as usize] as char);
+				result.push(HEX_DIGITS[(b & 0xf) as usize] as char);
+			}
+		}
+
+		unsafe { Self::new_unchecked(result) }
+	}
+
+	/// Creates a content-addressed blank node identifier by hashing the
+	/// given bytes.
+	///
+	/// The resulting identifier is `_:h` followed by the unpadded, lowercase
+	/// base32 encoding of the 128-bit FNV-1a hash of `bytes`. The hash
+	/// function is fixed, so the same bytes always produce the same
+	/// identifier, across runs and platforms. It is not a cryptographic hash.
+	///
+	/// ```
+	/// use rdf_types::BlankIdBuf;
+	///
+	/// let b = BlankIdBuf::from_hash(b"hello");
+	/// assert_eq!(b.as_str(), "_:h4pq67vkcqpmu64ebgffvthjrwm");
+	/// assert_eq!(b, BlankIdBuf::from_hash(b"hello"));
+	/// ```
+	pub fn from_hash(bytes: &[u8]) -> Self {
+		let mut hash = FNV_OFFSET_BASIS;
+		for &b in bytes {
+			hash ^= b as u128;
+			hash = hash.wrapping_mul(FNV_PRIME);
+		}
+
+		let mut result = String::with_capacity(29);
+		result.push_str("_:h");
+
+		for i in 0..26i32 {
+			let digit = match 123 - 5 * i {
+				shift if shift >= 0 => hash >> shift,
+				shift => hash << -shift,
+			};
+
+			result.push(BASE32_DIGITS[(digit & 0x1f) as usize] as char)
+		}
+
+		unsafe { Self::new_unchecked(result) }
+	}
+
 	/// Returns a reference to this blank id as a `BlankId`.
 	#[inline(always)]
 	pub fn as_blank_id_ref(&self) -> &BlankId {
@@ -299,6 +385,14 @@ impl PartialEq<BlankIdBuf> for BlankId {
 	}
 }
 
+const HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+const BASE32_DIGITS: &[u8; 32] = b"abcdefghijklmnopqrstuvwxyz234567";
+
+const FNV_OFFSET_BASIS: u128 = 0x6c62272e07bb014262b821756295c58d;
+
+const FNV_PRIME: u128 = 0x0000000001000000000000000000013b;
+
 fn check<C: Iterator<Item = char>>(mut chars: C) -> bool {
 	match chars.next() {
 		Some('_') => match chars.next() {
@@ -375,3 +469,36 @@ impl<'de> serde::Deserialize<'de> for BlankIdBuf {
 		deserializer.deserialize_string(Visitor)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::{BlankId, BlankIdBuf};
+
+	#[test]
+	fn from_hash() {
+		assert_eq!(
+			BlankIdBuf::from_hash(b"").as_str(),
+			"_:hnrrcolqhxmaueyvyef2wffofru"
+		);
+		for bytes in [b"".as_slice(), b"a", b"b", b"hello"] {
+			let b = BlankIdBuf::from_hash(bytes);
+			assert!(BlankId::new(b.as_str()).is_ok());
+			assert_eq!(b.len(), 29)
+		}
+
+		assert_ne!(BlankIdBuf::from_hash(b"a"), BlankIdBuf::from_hash(b"b"))
+	}
+
+	#[test]
+	fn from_external() {
+		for external in ["", "a", "_", "x_41", "user/42", "élément", "a.b", "-"] {
+			let b = BlankIdBuf::from_external(external);
+			assert!(BlankId::new(b.as_str()).is_ok())
+		}
+
+		assert_ne!(
+			BlankIdBuf::from_external("a_41"),
+			BlankIdBuf::from_external("aA")
+		);
+	}
+}
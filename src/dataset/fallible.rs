@@ -24,6 +24,18 @@ pub trait FallibleTraversableDataset: FallibleDataset {
 		Self: 'a;
 
 	fn try_quads(&self) -> Self::TryQuads<'_>;
+
+	/// Counts the quads of the dataset, stopping at the first error.
+	fn try_quads_count(&self) -> Result<usize, Self::Error> {
+		let mut count = 0;
+
+		for quad in self.try_quads() {
+			quad?;
+			count += 1
+		}
+
+		Ok(count)
+	}
 }
 
 impl<D: TraversableDataset> FallibleTraversableDataset for D {
@@ -32,6 +44,10 @@ impl<D: TraversableDataset> FallibleTraversableDataset for D {
 	fn try_quads(&self) -> Self::TryQuads<'_> {
 		InfallibleIterator(self.quads())
 	}
+
+	fn try_quads_count(&self) -> Result<usize, Self::Error> {
+		Ok(self.quads_count())
+	}
 }
 
 /// Pattern-matching-capable fallible dataset.
@@ -47,6 +63,15 @@ pub trait FalliblePatternMatchingDataset: FallibleDataset {
 		pattern: CanonicalQuadPattern<&'p Self::Resource>,
 	) -> Self::TryQuadPatternMatching<'_, 'p>;
 
+	/// Checks if the dataset contains the given quad.
+	fn try_contains_quad(&self, quad: Quad<&Self::Resource>) -> Result<bool, Self::Error> {
+		Ok(self
+			.try_quad_pattern_matching(quad.into())
+			.next()
+			.transpose()?
+			.is_some())
+	}
+
 	fn try_contains_triple(&self, triple: Triple<&Self::Resource>) -> Result<bool, Self::Error> {
 		Ok(self
 			.try_quad_pattern_matching(triple.into())
@@ -65,6 +90,10 @@ impl<D: PatternMatchingDataset> FalliblePatternMatchingDataset for D {
 	) -> Self::TryQuadPatternMatching<'_, 'p> {
 		InfallibleIterator(self.quad_pattern_matching(pattern))
 	}
+
+	fn try_contains_quad(&self, quad: Quad<&Self::Resource>) -> Result<bool, Self::Error> {
+		Ok(self.contains_quad(quad))
+	}
 }
 
 /// Fallible mutable dataset.
@@ -78,3 +107,303 @@ impl<D: DatasetMut> FallibleDatasetMut for D {
 		Ok(())
 	}
 }
+
+/// Fallible traversable dataset yielding owned quads.
+///
+/// Unlike [`FallibleTraversableDataset`], the quads are not borrowed from
+/// the dataset, which allows datasets whose quads are produced on the fly
+/// (such as [`IterDataset`]) to be traversed without storing them. Every
+/// fallible traversable dataset also implements this trait, by cloning its
+/// quads.
+pub trait FallibleOwnedTraversableDataset: FallibleDataset {
+	/// Fallible owned quads iterator.
+	type TryOwnedQuads<'a>: Iterator<Item = Result<Quad<Self::Resource>, Self::Error>>
+	where
+		Self: 'a;
+
+	fn try_owned_quads(&self) -> Self::TryOwnedQuads<'_>;
+
+	/// Counts the quads of the dataset, stopping at the first error.
+	fn try_owned_quads_count(&self) -> Result<usize, Self::Error> {
+		let mut count = 0;
+
+		for quad in self.try_owned_quads() {
+			quad?;
+			count += 1
+		}
+
+		Ok(count)
+	}
+}
+
+impl<D: FallibleTraversableDataset> FallibleOwnedTraversableDataset for D
+where
+	D::Resource: Clone,
+{
+	type TryOwnedQuads<'a>
+		= std::iter::Map<
+		D::TryQuads<'a>,
+		fn(Result<Quad<&'a D::Resource>, D::Error>) -> Result<Quad<D::Resource>, D::Error>,
+	> where
+		Self: 'a;
+
+	fn try_owned_quads(&self) -> Self::TryOwnedQuads<'_> {
+		self.try_quads().map(|quad| quad.map(Quad::into_cloned))
+	}
+
+	fn try_owned_quads_count(&self) -> Result<usize, Self::Error> {
+		self.try_quads_count()
+	}
+}
+
+/// Fallible dataset backed by a stream of quads.
+///
+/// This adapter exposes any source of `Result<Quad<R>, E>` items (such as a
+/// file reader yielding `io::Result<Quad>`) as a [`FallibleDataset`], without
+/// loading the quads into memory. The source is given as a factory function
+/// called once per query to start a new pass over the quads, for instance by
+/// opening the file again. The factory itself may fail, in which case the
+/// query returns its error.
+///
+/// The source is never indexed: every query scans the stream, so counting,
+/// existence checks and pattern matching all cost O(n) per query, where n is
+/// the number of quads in the source. Collect the quads into an indexed
+/// dataset instead if the same source is queried many times.
+///
+/// Since quads are produced by value, this adapter implements
+/// [`FallibleOwnedTraversableDataset`] but not [`FallibleTraversableDataset`]
+/// nor [`FalliblePatternMatchingDataset`], which yield references to the
+/// dataset resources. Equivalent inherent methods returning owned quads are
+/// provided instead.
+///
+/// # Example
+///
+/// ```
+/// use rdf_types::{dataset::fallible::IterDataset, Quad};
+///
+/// let quads = vec![Quad(1, 2, 3, None), Quad(1, 4, 1, None)];
+/// let dataset = IterDataset::new(|| Ok::<_, std::io::Error>(quads.iter().copied().map(Ok)));
+///
+/// assert_eq!(dataset.try_quads_count().unwrap(), 2);
+/// assert!(dataset.try_contains_quad(Quad(&1, &4, &1, None)).unwrap());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct IterDataset<F>(F);
+
+impl<F> IterDataset<F> {
+	/// Creates a new dataset from the given source factory.
+	pub fn new(source: F) -> Self {
+		Self(source)
+	}
+
+	/// Returns the underlying source factory.
+	pub fn into_inner(self) -> F {
+		self.0
+	}
+}
+
+impl<R, E, I, F> FallibleDataset for IterDataset<F>
+where
+	F: Fn() -> Result<I, E>,
+	I: IntoIterator<Item = Result<Quad<R>, E>>,
+{
+	type Resource = R;
+	type Error = E;
+}
+
+impl<R, E, I, F> IterDataset<F>
+where
+	F: Fn() -> Result<I, E>,
+	I: IntoIterator<Item = Result<Quad<R>, E>>,
+{
+	/// Returns a new pass over the quads of the source.
+	///
+	/// This calls the source factory. If it fails, the returned iterator
+	/// only yields its error.
+	pub fn try_quads(&self) -> IterQuads<I::IntoIter, E> {
+		match (self.0)() {
+			Ok(source) => IterQuads::Source(source.into_iter()),
+			Err(e) => IterQuads::Failed(Some(e)),
+		}
+	}
+
+	/// Counts the quads of the source.
+	///
+	/// This scans the whole source, stopping at the first error.
+	pub fn try_quads_count(&self) -> Result<usize, E> {
+		self.try_owned_quads_count()
+	}
+
+	/// Iterates over the quads of the source matching the given pattern.
+	///
+	/// This scans the whole source. Errors are yielded as they are
+	/// encountered.
+	pub fn try_quad_pattern_matching<'p>(
+		&self,
+		pattern: CanonicalQuadPattern<&'p R>,
+	) -> IterPatternMatching<'p, IterQuads<I::IntoIter, E>, R> {
+		IterPatternMatching {
+			source: self.try_quads(),
+			pattern,
+		}
+	}
+
+	/// Checks if the source contains the given quad.
+	///
+	/// This scans the source until the quad is found.
+	pub fn try_contains_quad(&self, quad: Quad<&R>) -> Result<bool, E>
+	where
+		R: PartialEq,
+	{
+		Ok(self
+			.try_quad_pattern_matching(quad.into())
+			.next()
+			.transpose()?
+			.is_some())
+	}
+
+	/// Checks if the source contains the given triple, in any graph.
+	///
+	/// This scans the source until the triple is found.
+	pub fn try_contains_triple(&self, triple: Triple<&R>) -> Result<bool, E>
+	where
+		R: PartialEq,
+	{
+		Ok(self
+			.try_quad_pattern_matching(triple.into())
+			.next()
+			.transpose()?
+			.is_some())
+	}
+}
+
+impl<R, E, I, F> FallibleOwnedTraversableDataset for IterDataset<F>
+where
+	F: Fn() -> Result<I, E>,
+	I: IntoIterator<Item = Result<Quad<R>, E>>,
+{
+	type TryOwnedQuads<'a> = IterQuads<I::IntoIter, E> where Self: 'a;
+
+	fn try_owned_quads(&self) -> Self::TryOwnedQuads<'_> {
+		self.try_quads()
+	}
+}
+
+/// Pass over the quads of an [`IterDataset`].
+///
+/// Returned by [`IterDataset::try_quads`].
+pub enum IterQuads<I, E> {
+	/// The source factory succeeded.
+	Source(I),
+
+	/// The source factory failed with the given error, not yet yielded.
+	Failed(Option<E>),
+}
+
+impl<R, E, I: Iterator<Item = Result<Quad<R>, E>>> Iterator for IterQuads<I, E> {
+	type Item = Result<Quad<R>, E>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		match self {
+			Self::Source(source) => source.next(),
+			Self::Failed(e) => e.take().map(Err),
+		}
+	}
+}
+
+/// Iterator over the quads of an [`IterDataset`] matching a given pattern.
+///
+/// Returned by [`IterDataset::try_quad_pattern_matching`].
+pub struct IterPatternMatching<'p, I, R> {
+	source: I,
+	pattern: CanonicalQuadPattern<&'p R>,
+}
+
+impl<'p, R: PartialEq, E, I: Iterator<Item = Result<Quad<R>, E>>> Iterator
+	for IterPatternMatching<'p, I, R>
+{
+	type Item = Result<Quad<R>, E>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		for quad in self.source.by_ref() {
+			match quad {
+				Ok(quad) => {
+					if self.pattern.matches(quad.as_ref().as_ref()) {
+						return Some(Ok(quad));
+					}
+				}
+				Err(e) => return Some(Err(e)),
+			}
+		}
+
+		None
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{
+		FallibleOwnedTraversableDataset, FalliblePatternMatchingDataset,
+		FallibleTraversableDataset, IterDataset,
+	};
+	use crate::{dataset::IndexedBTreeDataset, Quad};
+
+	#[test]
+	fn try_count_contains() {
+		let dataset: IndexedBTreeDataset<u32> = [Quad(0, 1, 2, None), Quad(0, 1, 2, Some(3))]
+			.into_iter()
+			.collect();
+
+		assert_eq!(dataset.try_quads_count(), Ok(2));
+		assert_eq!(
+			dataset.try_contains_quad(Quad(&0, &1, &2, Some(&3))),
+			Ok(true)
+		);
+		assert_eq!(
+			dataset.try_contains_quad(Quad(&0, &1, &2, Some(&4))),
+			Ok(false)
+		);
+	}
+
+	#[test]
+	fn owned_quads() {
+		let dataset: IndexedBTreeDataset<u32> = [Quad(0, 1, 2, None), Quad(0, 1, 2, Some(3))]
+			.into_iter()
+			.collect();
+
+		let quads: Result<Vec<_>, _> = dataset.try_owned_quads().collect();
+		assert_eq!(quads, Ok(vec![Quad(0, 1, 2, None), Quad(0, 1, 2, Some(3))]));
+		assert_eq!(dataset.try_owned_quads_count(), Ok(2));
+	}
+
+	#[test]
+	fn iter_dataset() {
+		let quads = [
+			Ok(Quad(0, 1, 2, None)),
+			Err("invalid quad"),
+			Ok(Quad(2, 1, 0, None)),
+		];
+		let dataset = IterDataset::new(|| Ok(quads));
+
+		assert_eq!(dataset.try_quads_count(), Err("invalid quad"));
+		assert_eq!(dataset.try_contains_quad(Quad(&0, &1, &2, None)), Ok(true));
+		assert_eq!(
+			dataset.try_contains_quad(Quad(&2, &1, &0, None)),
+			Err("invalid quad")
+		);
+		assert_eq!(
+			dataset
+				.try_quad_pattern_matching(Quad(None, None, Some(&0), None).into())
+				.collect::<Vec<_>>(),
+			[Err("invalid quad"), Ok(Quad(2, 1, 0, None))]
+		);
+		assert_eq!(dataset.try_owned_quads().collect::<Vec<_>>(), quads);
+
+		let failing = IterDataset::new(|| Err::<[Result<Quad<u32>, _>; 0], _>("unavailable"));
+		assert_eq!(failing.try_quads_count(), Err("unavailable"));
+		assert_eq!(
+			failing.try_owned_quads().collect::<Vec<_>>(),
+			[Err("unavailable")]
+		);
+	}
+}
@@ -4,10 +4,11 @@ use educe::Educe;
 use raw_btree::RawBTree;
 use slab::Slab;
 
-use super::{super::Graph, IndexedBTreeGraph};
+use super::{super::Graph, IndexedBTreeGraph, MaybeIndexedGraph};
 use crate::{
 	dataset::{GraphMut, ResourceTraversableGraph, TraversableGraph},
-	RdfDisplay, Term, Triple,
+	utils::clone_raw_btree,
+	BlankId, BlankIdBuf, Id, PrefixMap, PrettyRdfDisplay, RdfDisplay, Term, Triple,
 };
 
 fn resource_cmp<R: Ord>(resources: &Slab<Resource<R>>) -> impl '_ + Fn(&usize, &R) -> Ordering {
@@ -49,7 +50,6 @@ fn triple_index_cmp<'a, R: Ord>(
 }
 
 /// BTree-based RDF graph.
-#[derive(Clone)]
 pub struct BTreeGraph<R = Term> {
 	pub(crate) resources: Slab<Resource<R>>,
 	pub(crate) triples: Slab<Triple<usize>>,
@@ -57,6 +57,17 @@ pub struct BTreeGraph<R = Term> {
 	pub(crate) triples_indexes: RawBTree<usize>,
 }
 
+impl<R: Clone> Clone for BTreeGraph<R> {
+	fn clone(&self) -> Self {
+		Self {
+			resources: self.resources.clone(),
+			triples: self.triples.clone(),
+			resources_indexes: clone_raw_btree(&self.resources_indexes),
+			triples_indexes: clone_raw_btree(&self.triples_indexes),
+		}
+	}
+}
+
 impl<R> Default for BTreeGraph<R> {
 	fn default() -> Self {
 		Self {
@@ -74,6 +85,17 @@ impl<R> BTreeGraph<R> {
 		Self::default()
 	}
 
+	/// Creates a new empty graph with room for `triples` triples and `resources` resources.
+	///
+	/// Only the triple and resource slabs are preallocated, not the B-tree indexes.
+	pub fn with_capacity(triples: usize, resources: usize) -> Self {
+		Self {
+			triples: Slab::with_capacity(triples),
+			resources: Slab::with_capacity(resources),
+			..Self::default()
+		}
+	}
+
 	/// Returns the number of triples in the graph.
 	pub fn len(&self) -> usize {
 		self.triples.len()
@@ -108,6 +130,20 @@ impl<R> BTreeGraph<R> {
 	pub fn into_indexed(self) -> IndexedBTreeGraph<R> {
 		IndexedBTreeGraph::from_non_indexed(self)
 	}
+
+	/// Indexes the triples only if the graph contains at least `min_triples`
+	/// triples.
+	///
+	/// Small graphs are left as is, since scanning all their triples is
+	/// cheaper than building and maintaining the indexes. Either way the
+	/// returned [`MaybeIndexedGraph`] supports pattern matching.
+	pub fn into_indexed_if(self, min_triples: usize) -> MaybeIndexedGraph<R> {
+		if self.len() >= min_triples {
+			MaybeIndexedGraph::Indexed(self.into_indexed())
+		} else {
+			MaybeIndexedGraph::NonIndexed(self)
+		}
+	}
 }
 
 impl<R: Ord> BTreeGraph<R> {
@@ -134,6 +170,16 @@ impl<R: Ord> BTreeGraph<R> {
 		self.index_of_triple(triple).is_some()
 	}
 
+	/// Returns the triple stored in the graph equal to the given one, if any.
+	///
+	/// Contrarily to [`Self::contains`], the returned triple borrows the
+	/// canonical resources stored in the graph, which can then be shared
+	/// instead of the given ones (e.g. when `R` is reference counted).
+	pub fn get_triple(&self, triple: Triple<&R>) -> Option<Triple<&R>> {
+		self.index_of_triple(triple)
+			.map(|i| triple_with_resources(&self.resources, self.triples[i]))
+	}
+
 	/// Inserts the given triple in the graph.
 	///
 	/// Returns `true` if the triple was not already in the graph, and `false`
@@ -240,6 +286,24 @@ impl<R: Ord> BTreeGraph<R> {
 	}
 }
 
+impl BTreeGraph {
+	/// Rewrites every blank node identifier of the graph using the given
+	/// function, and rebuilds the graph.
+	///
+	/// IRIs and literals are left untouched. If two blank node identifiers are
+	/// mapped to the same label, the resulting duplicate triples are merged.
+	pub fn relabel_blank_nodes(self, map: impl Fn(&BlankId) -> BlankIdBuf) -> Self {
+		self.into_iter()
+			.map(|triple| {
+				triple.map(|term| match term {
+					Term::Id(Id::Blank(b)) => Term::Id(Id::Blank(map(&b))),
+					other => other,
+				})
+			})
+			.collect()
+	}
+}
+
 impl<R: Clone + Ord> FromIterator<Triple<R>> for BTreeGraph<R> {
 	fn from_iter<T: IntoIterator<Item = Triple<R>>>(iter: T) -> Self {
 		let mut result = Self::new();
@@ -281,8 +345,8 @@ impl<R: Clone + Ord> GraphMut for BTreeGraph<R> {
 		self.insert(triple);
 	}
 
-	fn remove(&mut self, triple: Triple<&Self::Resource>) {
-		self.remove(triple);
+	fn remove(&mut self, triple: Triple<&Self::Resource>) -> bool {
+		self.remove(triple)
 	}
 }
 
@@ -419,6 +483,27 @@ impl<R: RdfDisplay> RdfDisplay for BTreeGraph<R> {
 
 		Ok(())
 	}
+
+	fn rdf_write<W: std::io::Write + ?Sized>(&self, writer: &mut W) -> std::io::Result<()> {
+		for t in self {
+			t.rdf_write(writer)?;
+			writer.write_all(b" .\n")?;
+		}
+
+		Ok(())
+	}
+}
+
+impl<R: PrettyRdfDisplay + PartialEq> PrettyRdfDisplay for BTreeGraph<R> {
+	/// Formats the graph in a Turtle-like syntax, starting with the prefix
+	/// declarations, where triples are grouped by subject and predicate.
+	fn pretty_rdf_fmt(
+		&self,
+		prefixes: &PrefixMap,
+		f: &mut std::fmt::Formatter,
+	) -> std::fmt::Result {
+		crate::pretty::pretty_rdf_fmt_triples(self, prefixes, f)
+	}
 }
 
 #[cfg(feature = "serde")]
@@ -543,4 +628,29 @@ mod tests {
 			remove_test(i as usize * 11, [i; 32]);
 		}
 	}
+
+	#[test]
+	fn relabel_blank_nodes() {
+		use crate::{BlankIdBuf, Literal, LiteralType, Term, XSD_STRING};
+		use static_iref::iri;
+
+		let p = <Term>::iri(iri!("http://example.org/p").to_owned());
+		let o = <Term>::Literal(Literal::new(
+			"value".to_owned(),
+			LiteralType::Any(XSD_STRING.to_owned()),
+		));
+
+		let graph: BTreeGraph = [
+			Triple(<Term>::blank(BlankIdBuf::from_u8(0)), p.clone(), o.clone()),
+			Triple(<Term>::blank(BlankIdBuf::from_u8(1)), p.clone(), o.clone()),
+		]
+		.into_iter()
+		.collect();
+
+		let x = BlankIdBuf::from_suffix("x").unwrap();
+		let relabeled = graph.relabel_blank_nodes(|_| x.clone());
+
+		assert_eq!(relabeled.len(), 1);
+		assert!(relabeled.contains(Triple(&<Term>::blank(x.clone()), &p, &o)));
+	}
 }
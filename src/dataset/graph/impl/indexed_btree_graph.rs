@@ -14,6 +14,7 @@ use crate::{
 		triple::canonical::{PatternObject, PatternPredicate, PatternSubject},
 		CanonicalTriplePattern,
 	},
+	utils::{btree_set_footprint, clone_raw_btree, raw_btree_footprint, slab_footprint},
 	RdfDisplay, Term, Triple,
 };
 
@@ -56,7 +57,6 @@ fn triple_index_cmp<'a, R: Ord>(
 }
 
 /// Indexed BTree-based RDF graph, optimized for pattern matching operations.
-#[derive(Clone)]
 pub struct IndexedBTreeGraph<R = Term> {
 	resources: Slab<Resource<R>>,
 	triples: Slab<Triple<usize>>,
@@ -67,6 +67,20 @@ pub struct IndexedBTreeGraph<R = Term> {
 	objects: BTreeSet<usize>,
 }
 
+impl<R: Clone> Clone for IndexedBTreeGraph<R> {
+	fn clone(&self) -> Self {
+		Self {
+			resources: self.resources.clone(),
+			triples: self.triples.clone(),
+			resources_indexes: clone_raw_btree(&self.resources_indexes),
+			triples_indexes: clone_raw_btree(&self.triples_indexes),
+			subjects: self.subjects.clone(),
+			predicates: self.predicates.clone(),
+			objects: self.objects.clone(),
+		}
+	}
+}
+
 impl<R> Default for IndexedBTreeGraph<R> {
 	fn default() -> Self {
 		Self {
@@ -87,21 +101,51 @@ impl<R> IndexedBTreeGraph<R> {
 		Self::default()
 	}
 
+	/// Creates a new empty graph with room for `triples` triples and `resources` resources.
+	///
+	/// Only the triple and resource slabs are preallocated, not the B-tree indexes.
+	pub fn with_capacity(triples: usize, resources: usize) -> Self {
+		Self {
+			triples: Slab::with_capacity(triples),
+			resources: Slab::with_capacity(resources),
+			..Self::default()
+		}
+	}
+
+	/// Estimates the number of heap bytes used by this graph.
+	///
+	/// This sums the capacities of the resource and triple storages, the
+	/// nodes of the B-tree indexes and the (estimated) sizes of the B-tree
+	/// sets indexing triples by resource. The sizes of the standard B-tree sets
+	/// are approximations, and heap memory owned by the resources themselves
+	/// (such as the text of IRIs and literals) is not taken into account.
+	pub fn memory_footprint(&self) -> usize {
+		let resources: usize = self
+			.resources
+			.iter()
+			.map(|(_, r)| {
+				btree_set_footprint(&r.as_subject)
+					+ btree_set_footprint(&r.as_predicate)
+					+ btree_set_footprint(&r.as_object)
+			})
+			.sum();
+
+		slab_footprint(&self.resources)
+			+ slab_footprint(&self.triples)
+			+ raw_btree_footprint(&self.resources_indexes)
+			+ raw_btree_footprint(&self.triples_indexes)
+			+ btree_set_footprint(&self.subjects)
+			+ btree_set_footprint(&self.predicates)
+			+ btree_set_footprint(&self.objects)
+			+ resources
+	}
+
 	/// Creates a new indexed graph from a non-indexed one.
 	pub fn from_non_indexed(graph: BTreeGraph<R>) -> Self {
 		let mut resources: Slab<Resource<R>> = graph
 			.resources
 			.into_iter()
-			.map(|(i, r)| {
-				let indexed_r = Resource {
-					value: r.value,
-					as_subject: BTreeSet::new(),
-					as_predicate: BTreeSet::new(),
-					as_object: BTreeSet::new(),
-				};
-
-				(i, indexed_r)
-			})
+			.map(|(i, r)| (i, Resource::new(r.value)))
 			.collect();
 
 		let mut subjects = BTreeSet::new();
@@ -212,6 +256,63 @@ impl<R: Ord> IndexedBTreeGraph<R> {
 		self.index_of_triple(triple).is_some()
 	}
 
+	/// Returns the triple stored in the graph equal to the given one, if any.
+	///
+	/// Contrarily to [`Self::contains`], the returned triple borrows the
+	/// canonical resources stored in the graph, which can then be shared
+	/// instead of the given ones (e.g. when `R` is reference counted).
+	pub fn get_triple(&self, triple: Triple<&R>) -> Option<Triple<&R>> {
+		self.index_of_triple(triple)
+			.map(|i| triple_with_resources(&self.resources, self.triples[i]))
+	}
+
+	/// Returns an iterator over the resources connected to the given
+	/// `resource` by a triple.
+	///
+	/// For each triple `resource p o`, the object `o` is returned with the
+	/// [`EdgeDirection::Outgoing`] marker, and for each triple
+	/// `s p resource`, the subject `s` is returned with the
+	/// [`EdgeDirection::Incoming`] marker. Only subjects and objects are
+	/// considered as nodes, predicates being edge labels: triples where
+	/// `resource` appears as predicate are ignored, so a resource only used
+	/// as predicate has no neighbors, and predicates are never returned as
+	/// neighbors.
+	///
+	/// A neighbor is returned once per connecting triple. In particular a
+	/// triple `resource p resource` makes `resource` its own neighbor in
+	/// both directions.
+	pub fn neighbors(&self, resource: &R) -> Neighbors<'_, R> {
+		let resource = self.get_resource(resource);
+		Neighbors {
+			resources: &self.resources,
+			triples: &self.triples,
+			outgoing: resource.map(|r| r.as_subject.iter()),
+			incoming: resource.map(|r| r.as_object.iter()),
+		}
+	}
+
+	/// Returns an iterator over all the triples whose subject is one of the
+	/// given `subjects`.
+	///
+	/// The subject indexes of all the given subjects are merged upfront,
+	/// so each triple is returned once, even if its subject is repeated.
+	pub fn triples_with_subjects(&self, subjects: &[R]) -> TriplesWithSubjects<'_, R> {
+		let mut indexes: Vec<usize> = subjects
+			.iter()
+			.filter_map(|s| self.get_resource(s))
+			.flat_map(|r| r.as_subject.iter().copied())
+			.collect();
+
+		indexes.sort_unstable();
+		indexes.dedup();
+
+		TriplesWithSubjects {
+			resources: &self.resources,
+			triples: &self.triples,
+			indexes: indexes.into_iter(),
+		}
+	}
+
 	/// Inserts the given triple in the graph.
 	///
 	/// Returns `true` if the triple was not already in the graph, and `false`
@@ -220,62 +321,49 @@ impl<R: Ord> IndexedBTreeGraph<R> {
 		if self.contains(triple.as_ref()) {
 			false
 		} else {
-			let s_i = self.index_of_resource(&triple.0);
-			let p_i = self.index_of_resource(&triple.1);
-			let o_i = self.index_of_resource(&triple.2);
+			self.insert_absent(triple);
+			true
+		}
+	}
 
-			let e = self.triples.vacant_entry();
-			let i = e.key();
+	/// Inserts the given triple, assuming it is not already in the graph.
+	fn insert_absent(&mut self, triple: Triple<R>) {
+		let Triple(s, p, o) = triple;
+		let i = self.triples.vacant_key();
 
-			let s_i = match s_i {
-				Some(s_i) => {
-					self.resources[s_i].as_subject.insert(i);
-					s_i
-				}
-				None => {
-					let s_i = self.resources.insert(Resource::subject(triple.0, i));
-					self.resources_indexes
-						.insert(resource_index_cmp(&self.resources), s_i);
-					s_i
-				}
-			};
+		let s_i = self.get_or_insert_resource(s);
+		self.resources[s_i].as_subject.insert(i);
 
-			let p_i = match p_i {
-				Some(p_i) => {
-					self.resources[p_i].as_predicate.insert(i);
-					p_i
-				}
-				None => {
-					let p_i = self.resources.insert(Resource::predicate(triple.1, i));
-					self.resources_indexes
-						.insert(resource_index_cmp(&self.resources), p_i);
-					p_i
-				}
-			};
+		let p_i = self.get_or_insert_resource(p);
+		self.resources[p_i].as_predicate.insert(i);
 
-			let o_i = match o_i {
-				Some(o_i) => {
-					self.resources[o_i].as_object.insert(i);
-					o_i
-				}
-				None => {
-					let o_i = self.resources.insert(Resource::object(triple.2, i));
-					self.resources_indexes
-						.insert(resource_index_cmp(&self.resources), o_i);
-					o_i
-				}
-			};
+		let o_i = self.get_or_insert_resource(o);
+		self.resources[o_i].as_object.insert(i);
 
-			self.subjects.insert(s_i);
-			self.predicates.insert(p_i);
-			self.objects.insert(o_i);
+		self.subjects.insert(s_i);
+		self.predicates.insert(p_i);
+		self.objects.insert(o_i);
 
-			e.insert(Triple(s_i, p_i, o_i));
+		self.triples.insert(Triple(s_i, p_i, o_i));
 
-			self.triples_indexes
-				.insert(triple_index_cmp(&self.resources, &self.triples), i);
+		self.triples_indexes
+			.insert(triple_index_cmp(&self.resources, &self.triples), i);
+	}
 
-			true
+	/// Returns the index of the given resource, inserting it first if it is
+	/// not yet in the graph.
+	///
+	/// A newly inserted resource does not appear in any triple until the
+	/// caller registers it in the relevant position indexes.
+	fn get_or_insert_resource(&mut self, resource: R) -> usize {
+		match self.index_of_resource(&resource) {
+			Some(i) => i,
+			None => {
+				let i = self.resources.insert(Resource::new(resource));
+				self.resources_indexes
+					.insert(resource_index_cmp(&self.resources), i);
+				i
+			}
 		}
 	}
 
@@ -337,6 +425,91 @@ impl<R: Ord> IndexedBTreeGraph<R> {
 			i: 0,
 		}
 	}
+
+	/// Checks if every given pattern matches at least one triple of the graph.
+	///
+	/// Patterns are checked from the most to the least selective, using the
+	/// number of triples sharing their given subject, predicate or object as
+	/// an estimate. Stops at the first pattern that matches no triple.
+	pub fn ask(&self, patterns: &[CanonicalTriplePattern<&R>]) -> bool {
+		let mut candidates = Vec::with_capacity(patterns.len());
+		for &pattern in patterns {
+			match self.candidate_count(pattern) {
+				Some(0) | None => return false,
+				Some(n) => candidates.push((n, pattern)),
+			}
+		}
+
+		candidates.sort_by_key(|(n, _)| *n);
+		candidates
+			.into_iter()
+			.all(|(_, pattern)| self.pattern_matching(pattern).next().is_some())
+	}
+
+	/// Returns an upper bound on the number of triples matching the given
+	/// pattern, or `None` if one of its given resources is not in the graph.
+	fn candidate_count(&self, pattern: CanonicalTriplePattern<&R>) -> Option<usize> {
+		let mut count = self.len();
+
+		if let Some(s) = pattern.into_subject().into_id() {
+			count = count.min(self.get_resource(s)?.as_subject.len())
+		}
+
+		if let Some(p) = pattern.into_predicate().into_id() {
+			count = count.min(self.get_resource(p)?.as_predicate.len())
+		}
+
+		if let Some(o) = pattern.into_object().into_id() {
+			count = count.min(self.get_resource(o)?.as_object.len())
+		}
+
+		Some(count)
+	}
+}
+
+impl<R: Clone + Ord> IndexedBTreeGraph<R> {
+	/// Inserts all the triples of the given graph.
+	///
+	/// If `other` is small compared to `self`, each of its triples is looked
+	/// up in the index of `self`. Otherwise, both graphs are walked in order
+	/// at the same time to find the triples of `other` missing from `self`.
+	/// Missing triples are then inserted without any further membership test.
+	///
+	/// Returns the number of inserted triples.
+	pub fn insert_graph(&mut self, other: &Self) -> usize {
+		// Probing costs `O(log |self|)` per triple of `other`, while the
+		// merge walk costs `O(|self| + |other|)` overall.
+		let log_len = self.len().max(2).ilog2() as usize;
+		let probe = other.len() * log_len < self.len();
+
+		let missing: Vec<_> = if probe {
+			other
+				.iter()
+				.filter(|triple| !self.contains(*triple))
+				.map(|triple| triple.cloned())
+				.collect()
+		} else {
+			let mut missing = Vec::new();
+			let mut triples = self.iter().peekable();
+
+			for triple in other {
+				while triples.next_if(|t| *t < triple).is_some() {}
+
+				if triples.next_if_eq(&triple).is_none() {
+					missing.push(triple.cloned())
+				}
+			}
+
+			missing
+		};
+
+		let count = missing.len();
+		for triple in missing {
+			self.insert_absent(triple)
+		}
+
+		count
+	}
 }
 
 impl<R> From<BTreeGraph<R>> for IndexedBTreeGraph<R> {
@@ -426,8 +599,8 @@ impl<R: Clone + Ord> GraphMut for IndexedBTreeGraph<R> {
 		self.insert(triple);
 	}
 
-	fn remove(&mut self, triple: Triple<&Self::Resource>) {
-		self.remove(triple);
+	fn remove(&mut self, triple: Triple<&Self::Resource>) -> bool {
+		self.remove(triple)
 	}
 }
 
@@ -444,6 +617,10 @@ impl<R: Ord> PatternMatchingGraph for IndexedBTreeGraph<R> {
 	fn contains_triple(&self, triple: Triple<&Self::Resource>) -> bool {
 		self.contains(triple)
 	}
+
+	fn ask(&self, patterns: &[CanonicalTriplePattern<&Self::Resource>]) -> bool {
+		self.ask(patterns)
+	}
 }
 
 /// Iterator over the triples of a [`BTreeGraph`].
@@ -465,6 +642,70 @@ impl<'a, R> Iterator for Triples<'a, R> {
 	}
 }
 
+/// Edge direction, relative to a given resource.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EdgeDirection {
+	/// The given resource is the subject of the edge triple.
+	Outgoing,
+
+	/// The given resource is the object of the edge triple.
+	Incoming,
+}
+
+/// Iterator over the neighbors of a resource in an [`IndexedBTreeGraph`].
+///
+/// See [`IndexedBTreeGraph::neighbors`].
+pub struct Neighbors<'a, R> {
+	resources: &'a Slab<Resource<R>>,
+	triples: &'a Slab<Triple<usize>>,
+	outgoing: Option<std::collections::btree_set::Iter<'a, usize>>,
+	incoming: Option<std::collections::btree_set::Iter<'a, usize>>,
+}
+
+impl<'a, R> Iterator for Neighbors<'a, R> {
+	type Item = (&'a R, EdgeDirection);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if let Some(&i) = self.outgoing.as_mut().and_then(Iterator::next) {
+			return Some((
+				&self.resources[self.triples[i].2].value,
+				EdgeDirection::Outgoing,
+			));
+		}
+
+		self.incoming.as_mut().and_then(Iterator::next).map(|&i| {
+			(
+				&self.resources[self.triples[i].0].value,
+				EdgeDirection::Incoming,
+			)
+		})
+	}
+}
+
+/// Iterator over the triples of an [`IndexedBTreeGraph`] with the given
+/// subjects.
+///
+/// See [`IndexedBTreeGraph::triples_with_subjects`].
+pub struct TriplesWithSubjects<'a, R> {
+	resources: &'a Slab<Resource<R>>,
+	triples: &'a Slab<Triple<usize>>,
+	indexes: std::vec::IntoIter<usize>,
+}
+
+impl<'a, R> Iterator for TriplesWithSubjects<'a, R> {
+	type Item = Triple<&'a R>;
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.indexes.size_hint()
+	}
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.indexes
+			.next()
+			.map(|i| triple_with_resources(self.resources, self.triples[i]))
+	}
+}
+
 /// Iterator over the triples of a [`BTreeGraph`].
 pub struct IntoTriples<R> {
 	resources: Slab<Resource<R>>,
@@ -601,7 +842,11 @@ impl<'a, R> Iterator for PatternMatching<'a, R> {
 	fn next(&mut self) -> Option<Self::Item> {
 		while self.i < self.triples.capacity() {
 			let i = self.subject.next(self.i)?;
-			let triple = *self.triples.get(i)?;
+			let Some(&triple) = self.triples.get(i) else {
+				// Vacant slot left by a removed triple.
+				self.i = i + 1;
+				continue;
+			};
 			match self.predicate.next(i, triple) {
 				Ok(()) => match self.object.next(i, triple) {
 					Ok(()) => {
@@ -766,30 +1011,12 @@ pub(crate) struct Resource<R> {
 }
 
 impl<R> Resource<R> {
-	pub fn subject(value: R, i: usize) -> Self {
-		Self {
-			value,
-			as_subject: std::iter::once(i).collect(),
-			as_predicate: BTreeSet::new(),
-			as_object: BTreeSet::new(),
-		}
-	}
-
-	pub fn predicate(value: R, i: usize) -> Self {
-		Self {
-			value,
-			as_subject: BTreeSet::new(),
-			as_predicate: std::iter::once(i).collect(),
-			as_object: BTreeSet::new(),
-		}
-	}
-
-	pub fn object(value: R, i: usize) -> Self {
+	pub fn new(value: R) -> Self {
 		Self {
 			value,
 			as_subject: BTreeSet::new(),
 			as_predicate: BTreeSet::new(),
-			as_object: std::iter::once(i).collect(),
+			as_object: BTreeSet::new(),
 		}
 	}
 
@@ -812,6 +1039,15 @@ impl<R: RdfDisplay> RdfDisplay for IndexedBTreeGraph<R> {
 
 		Ok(())
 	}
+
+	fn rdf_write<W: std::io::Write + ?Sized>(&self, writer: &mut W) -> std::io::Result<()> {
+		for t in self {
+			t.rdf_write(writer)?;
+			writer.write_all(b" .\n")?;
+		}
+
+		Ok(())
+	}
 }
 
 #[cfg(feature = "serde")]
@@ -868,6 +1104,26 @@ mod tests {
 
 	use super::IndexedBTreeGraph;
 
+	#[test]
+	fn ask() {
+		let graph: IndexedBTreeGraph<u32> = [Triple(0, 1, 2), Triple(0, 1, 3), Triple(2, 1, 3)]
+			.into_iter()
+			.collect();
+
+		assert!(graph.ask(&[]));
+		assert!(graph.ask(&[
+			Triple(Some(&0), Some(&1), None).into(),
+			Triple(None, None, Some(&3)).into(),
+			Triple(&2, &1, &3).into(),
+		]));
+		assert!(!graph.ask(&[
+			Triple(Some(&0), Some(&1), None).into(),
+			Triple(Some(&3), None, None).into(),
+		]));
+		assert!(!graph.ask(&[Triple(&0, &1, &4).into()]));
+		assert!(!graph.ask(&[Triple(&2, &1, &2).into()]));
+	}
+
 	fn insert_test(n: usize, seed: [u8; 32]) {
 		let mut rng = SmallRng::from_seed(seed);
 		let mut triples = Vec::new();
@@ -938,4 +1194,134 @@ mod tests {
 			remove_test(i as usize * 11, [i; 32]);
 		}
 	}
+
+	#[test]
+	fn insert_graph() {
+		for i in 0u8..16 {
+			let mut rng = SmallRng::from_seed([i; 32]);
+			let mut random_graph = |n: usize| -> IndexedBTreeGraph<u32> {
+				std::iter::repeat_with(|| {
+					Triple(rng.next_u32() % 4, rng.next_u32() % 4, rng.next_u32() % 4)
+				})
+				.take(n)
+				.collect()
+			};
+
+			let mut graph = random_graph(i as usize * 4);
+			let delta = random_graph(i as usize);
+
+			let mut expected = graph.clone();
+			let mut expected_count = 0;
+			for t in &delta {
+				if expected.insert(t.cloned()) {
+					expected_count += 1
+				}
+			}
+
+			assert_eq!(graph.insert_graph(&delta), expected_count);
+			assert_eq!(graph, expected);
+		}
+	}
+
+	#[test]
+	fn insert_small_graph() {
+		use std::cell::Cell;
+
+		thread_local! {
+			static COMPARISONS: Cell<usize> = const { Cell::new(0) };
+		}
+
+		#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+		struct Counted(u32);
+
+		impl PartialOrd for Counted {
+			fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+				Some(self.cmp(other))
+			}
+		}
+
+		impl Ord for Counted {
+			fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+				COMPARISONS.with(|c| c.set(c.get() + 1));
+				self.0.cmp(&other.0)
+			}
+		}
+
+		let mut graph: IndexedBTreeGraph<Counted> = (0..10_000)
+			.map(|i| Triple(Counted(i), Counted(0), Counted(i)))
+			.collect();
+		let delta: IndexedBTreeGraph<Counted> = (0..16)
+			.map(|i| Triple(Counted(i * 1000), Counted(0), Counted(i)))
+			.collect();
+
+		COMPARISONS.with(|c| c.set(0));
+		assert_eq!(graph.insert_graph(&delta), 15);
+		assert_eq!(graph.len(), 10_015);
+
+		// Walking the whole graph would require at least one comparison per
+		// triple of the graph.
+		assert!(COMPARISONS.with(Cell::get) < graph.len() / 2)
+	}
+
+	#[test]
+	fn triples_with_subjects() {
+		use crate::dataset::PatternMatchingGraph;
+
+		for i in 0u8..8 {
+			let mut rng = SmallRng::from_seed([i; 32]);
+			let graph: IndexedBTreeGraph<u32> = std::iter::repeat_with(|| {
+				Triple(rng.next_u32() % 8, rng.next_u32() % 8, rng.next_u32() % 8)
+			})
+			.take(i as usize * 8)
+			.collect();
+
+			let subjects = [1, 3, 3, 7, 42];
+
+			let mut expected: Vec<_> = graph.iter().filter(|t| subjects.contains(t.0)).collect();
+			expected.sort_unstable();
+
+			let mut merged: Vec<_> = graph.triples_with_subjects(&subjects).collect();
+			merged.sort_unstable();
+			assert_eq!(merged, expected);
+
+			let mut generic: Vec<_> =
+				PatternMatchingGraph::triples_with_subjects(&graph, &subjects).collect();
+			generic.sort_unstable();
+			assert_eq!(generic, expected);
+		}
+	}
+
+	#[test]
+	fn neighbors() {
+		use super::EdgeDirection;
+
+		let graph: IndexedBTreeGraph<u32> = [
+			Triple(0, 1, 2),
+			Triple(0, 1, 3),
+			Triple(4, 5, 0),
+			Triple(0, 5, 0),
+			Triple(6, 0, 7),
+		]
+		.into_iter()
+		.collect();
+
+		let mut neighbors: Vec<_> = graph.neighbors(&0).map(|(r, d)| (*r, d)).collect();
+		neighbors.sort_unstable();
+
+		assert_eq!(
+			neighbors,
+			[
+				(0, EdgeDirection::Outgoing),
+				(0, EdgeDirection::Incoming),
+				(2, EdgeDirection::Outgoing),
+				(3, EdgeDirection::Outgoing),
+				(4, EdgeDirection::Incoming)
+			]
+		);
+
+		// Predicate positions are not edges: `1` and `5` are predicates only.
+		assert_eq!(graph.neighbors(&1).next(), None);
+		assert_eq!(graph.neighbors(&5).next(), None);
+		assert_eq!(graph.neighbors(&8).next(), None)
+	}
 }
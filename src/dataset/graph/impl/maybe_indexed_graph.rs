@@ -0,0 +1,206 @@
+use crate::{
+	dataset::{Graph, PatternMatchingGraph, TraversableGraph},
+	pattern::CanonicalTriplePattern,
+	Triple,
+};
+
+use super::{btree_graph, indexed_btree_graph, BTreeGraph, IndexedBTreeGraph};
+
+/// RDF graph that may or may not be indexed.
+///
+/// Building the indexes of an [`IndexedBTreeGraph`] is not worth it for small
+/// graphs, where scanning every triple is just as fast. This type lets
+/// generic code work with either representation, typically chosen using
+/// [`BTreeGraph::into_indexed_if`].
+#[derive(Clone)]
+pub enum MaybeIndexedGraph<R> {
+	/// Non-indexed graph. Pattern matching scans every triple.
+	NonIndexed(BTreeGraph<R>),
+
+	/// Indexed graph.
+	Indexed(IndexedBTreeGraph<R>),
+}
+
+impl<R> MaybeIndexedGraph<R> {
+	/// Checks if the graph is indexed.
+	pub fn is_indexed(&self) -> bool {
+		matches!(self, Self::Indexed(_))
+	}
+
+	/// Returns the number of triples in the graph.
+	pub fn len(&self) -> usize {
+		match self {
+			Self::NonIndexed(g) => g.len(),
+			Self::Indexed(g) => g.len(),
+		}
+	}
+
+	/// Checks if the graph is empty.
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	/// Returns an iterator over the triples of the graph.
+	pub fn iter(&self) -> Triples<'_, R> {
+		match self {
+			Self::NonIndexed(g) => Triples::NonIndexed(g.iter()),
+			Self::Indexed(g) => Triples::Indexed(g.iter()),
+		}
+	}
+
+	/// Turns this graph into an indexed graph, building the indexes if
+	/// necessary.
+	pub fn into_indexed(self) -> IndexedBTreeGraph<R> {
+		match self {
+			Self::NonIndexed(g) => g.into_indexed(),
+			Self::Indexed(g) => g,
+		}
+	}
+}
+
+impl<R: Ord> MaybeIndexedGraph<R> {
+	/// Checks if the graph contains the given triple.
+	pub fn contains(&self, triple: Triple<&R>) -> bool {
+		match self {
+			Self::NonIndexed(g) => g.contains(triple),
+			Self::Indexed(g) => g.contains(triple),
+		}
+	}
+
+	/// Returns an iterator over the triples matching the given pattern.
+	pub fn pattern_matching<'p>(
+		&self,
+		pattern: CanonicalTriplePattern<&'p R>,
+	) -> PatternMatching<'_, 'p, R> {
+		match self {
+			Self::NonIndexed(g) => PatternMatching::NonIndexed {
+				triples: g.iter(),
+				pattern,
+			},
+			Self::Indexed(g) => PatternMatching::Indexed(g.pattern_matching(pattern)),
+		}
+	}
+}
+
+impl<R> From<BTreeGraph<R>> for MaybeIndexedGraph<R> {
+	fn from(value: BTreeGraph<R>) -> Self {
+		Self::NonIndexed(value)
+	}
+}
+
+impl<R> From<IndexedBTreeGraph<R>> for MaybeIndexedGraph<R> {
+	fn from(value: IndexedBTreeGraph<R>) -> Self {
+		Self::Indexed(value)
+	}
+}
+
+impl<R> Graph for MaybeIndexedGraph<R> {
+	type Resource = R;
+}
+
+impl<R> TraversableGraph for MaybeIndexedGraph<R> {
+	type Triples<'a> = Triples<'a, R> where R: 'a;
+
+	fn triples(&self) -> Self::Triples<'_> {
+		self.iter()
+	}
+
+	fn triples_count(&self) -> usize {
+		self.len()
+	}
+}
+
+impl<R: Ord> PatternMatchingGraph for MaybeIndexedGraph<R> {
+	type TriplePatternMatching<'a, 'p> = PatternMatching<'a, 'p, R> where R: 'a, Self::Resource: 'p;
+
+	fn triple_pattern_matching<'p>(
+		&self,
+		pattern: CanonicalTriplePattern<&'p Self::Resource>,
+	) -> Self::TriplePatternMatching<'_, 'p> {
+		self.pattern_matching(pattern)
+	}
+
+	fn contains_triple(&self, triple: Triple<&Self::Resource>) -> bool {
+		self.contains(triple)
+	}
+}
+
+/// Iterator over the triples of a [`MaybeIndexedGraph`].
+pub enum Triples<'a, R> {
+	NonIndexed(btree_graph::Triples<'a, R>),
+	Indexed(indexed_btree_graph::Triples<'a, R>),
+}
+
+impl<'a, R> Iterator for Triples<'a, R> {
+	type Item = Triple<&'a R>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		match self {
+			Self::NonIndexed(t) => t.next(),
+			Self::Indexed(t) => t.next(),
+		}
+	}
+}
+
+/// Iterator over the triples of a [`MaybeIndexedGraph`] matching a given
+/// pattern.
+pub enum PatternMatching<'a, 'p, R> {
+	NonIndexed {
+		triples: btree_graph::Triples<'a, R>,
+		pattern: CanonicalTriplePattern<&'p R>,
+	},
+	Indexed(indexed_btree_graph::PatternMatching<'a, R>),
+}
+
+impl<'a, 'p, R: PartialEq> Iterator for PatternMatching<'a, 'p, R> {
+	type Item = Triple<&'a R>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		match self {
+			Self::NonIndexed { triples, pattern } => {
+				triples.find(|triple| pattern.matches(triple.as_ref()))
+			}
+			Self::Indexed(m) => m.next(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{
+		dataset::{BTreeGraph, PatternMatchingGraph},
+		pattern::CanonicalTriplePattern,
+		Triple,
+	};
+
+	#[test]
+	fn pattern_matching() {
+		let graph: BTreeGraph<u32> = [
+			Triple(0, 1, 2),
+			Triple(0, 1, 3),
+			Triple(1, 1, 1),
+			Triple(2, 0, 2),
+		]
+		.into_iter()
+		.collect();
+
+		let small = graph.clone().into_indexed_if(10);
+		let large = graph.into_indexed_if(2);
+		assert!(!small.is_indexed());
+		assert!(large.is_indexed());
+
+		let patterns = [
+			CanonicalTriplePattern::from(Triple(&0, &1, &2)),
+			CanonicalTriplePattern::from_option_triple(Triple(Some(&0), None, None)),
+			CanonicalTriplePattern::from_option_triple(Triple(None, Some(&1), None)),
+		];
+
+		for pattern in patterns {
+			let mut a: Vec<_> = small.triple_pattern_matching(pattern).collect();
+			let mut b: Vec<_> = large.triple_pattern_matching(pattern).collect();
+			a.sort_unstable();
+			b.sort_unstable();
+			assert_eq!(a, b)
+		}
+	}
+}
@@ -1,5 +1,7 @@
 pub mod btree_graph;
 pub mod indexed_btree_graph;
+pub mod maybe_indexed_graph;
 
 pub use btree_graph::BTreeGraph;
 pub use indexed_btree_graph::IndexedBTreeGraph;
+pub use maybe_indexed_graph::MaybeIndexedGraph;
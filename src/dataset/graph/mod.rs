@@ -87,6 +87,16 @@ pub trait PatternMatchingGraph: Graph {
 		self.triple_pattern_matching(triple.into()).next().is_some()
 	}
 
+	/// Checks if every given pattern matches at least one triple of the graph.
+	///
+	/// Patterns are checked in order, stopping at the first one that matches
+	/// no triple.
+	fn ask(&self, patterns: &[CanonicalTriplePattern<&Self::Resource>]) -> bool {
+		patterns
+			.iter()
+			.all(|&pattern| self.triple_pattern_matching(pattern).next().is_some())
+	}
+
 	/// Checks if the graph contains the given subject.
 	fn contains_triple_subject(&self, subject: &Self::Resource) -> bool {
 		use crate::pattern::triple::canonical::{GivenSubject, GivenSubjectAnyPredicate};
@@ -146,6 +156,71 @@ pub trait PatternMatchingGraph: Graph {
 			)),
 		}
 	}
+
+	/// Returns an iterator over all the triples whose subject is one of the
+	/// given `subjects`.
+	///
+	/// Each subject is matched in turn, skipping subjects already present
+	/// earlier in the slice so that no triple is returned twice.
+	fn triples_with_subjects<'p>(
+		&self,
+		subjects: &'p [Self::Resource],
+	) -> TriplesWithSubjects<'_, 'p, Self>
+	where
+		Self::Resource: PartialEq,
+	{
+		TriplesWithSubjects {
+			subjects,
+			i: 0,
+			current: None,
+			graph: self,
+		}
+	}
+}
+
+/// Iterator over the triples whose subject is one of the given subjects.
+///
+/// See [`PatternMatchingGraph::triples_with_subjects`].
+pub struct TriplesWithSubjects<'a, 'p, G: 'a + ?Sized + PatternMatchingGraph>
+where
+	G::Resource: 'p,
+{
+	subjects: &'p [G::Resource],
+	i: usize,
+	current: Option<G::TriplePatternMatching<'a, 'p>>,
+	graph: &'a G,
+}
+
+impl<'a, 'p, G: 'a + ?Sized + PatternMatchingGraph> Iterator for TriplesWithSubjects<'a, 'p, G>
+where
+	G::Resource: 'p + PartialEq,
+{
+	type Item = Triple<&'a G::Resource>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		use crate::pattern::triple::canonical::{GivenSubject, GivenSubjectAnyPredicate};
+		loop {
+			if let Some(current) = &mut self.current {
+				match current.next() {
+					Some(triple) => break Some(triple),
+					None => self.current = None,
+				}
+			}
+
+			let subject = self.subjects.get(self.i)?;
+			let seen = self.subjects[..self.i].contains(subject);
+			self.i += 1;
+
+			if !seen {
+				self.current = Some(self.graph.triple_pattern_matching(
+					CanonicalTriplePattern::GivenSubject(
+						subject,
+						GivenSubject::AnyPredicate(GivenSubjectAnyPredicate::AnyObject),
+					),
+				))
+			}
+		}
+	}
 }
 
 pub struct TriplePredicatesObjects<
@@ -214,7 +289,10 @@ where
 pub trait GraphMut: Graph {
 	fn insert(&mut self, triple: Triple<Self::Resource>);
 
-	fn remove(&mut self, triple: Triple<&Self::Resource>);
+	/// Removes the given triple from the graph.
+	///
+	/// Returns `true` if the triple was in the graph.
+	fn remove(&mut self, triple: Triple<&Self::Resource>) -> bool;
 }
 
 /// Graph view focusing on a given resource.
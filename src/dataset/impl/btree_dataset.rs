@@ -6,7 +6,14 @@ use slab::Slab;
 
 use super::super::Dataset;
 use crate::{
-	dataset::{DatasetMut, IndexedBTreeDataset, ResourceTraversableDataset, TraversableDataset},
+	dataset::{
+		isomorphism::are_equivalent, DatasetMut, IndexedBTreeDataset, ResourceTraversableDataset,
+		TraversableDataset,
+	},
+	utils::{clone_raw_btree, map_quads_literals},
+	vocabulary::{
+		EmbedIntoVocabulary, EmbeddedIntoVocabulary, ExtractFromVocabulary, ExtractedFromVocabulary,
+	},
 	Quad, RdfDisplay, Term,
 };
 
@@ -47,7 +54,6 @@ fn quad_index_cmp<'a, R: Ord>(
 }
 
 /// BTree-based RDF dataset.
-#[derive(Clone)]
 pub struct BTreeDataset<R = Term> {
 	pub(crate) resources: Slab<Resource<R>>,
 	pub(crate) quads: Slab<Quad<usize>>,
@@ -55,6 +61,17 @@ pub struct BTreeDataset<R = Term> {
 	pub(crate) quads_indexes: RawBTree<usize>,
 }
 
+impl<R: Clone> Clone for BTreeDataset<R> {
+	fn clone(&self) -> Self {
+		Self {
+			resources: self.resources.clone(),
+			quads: self.quads.clone(),
+			resources_indexes: clone_raw_btree(&self.resources_indexes),
+			quads_indexes: clone_raw_btree(&self.quads_indexes),
+		}
+	}
+}
+
 impl<R> Default for BTreeDataset<R> {
 	fn default() -> Self {
 		Self {
@@ -72,6 +89,17 @@ impl<R> BTreeDataset<R> {
 		Self::default()
 	}
 
+	/// Creates a new empty dataset with room for `quads` quads and `resources` resources.
+	///
+	/// Only the quad and resource slabs are preallocated, not the B-tree indexes.
+	pub fn with_capacity(quads: usize, resources: usize) -> Self {
+		Self {
+			quads: Slab::with_capacity(quads),
+			resources: Slab::with_capacity(resources),
+			..Self::default()
+		}
+	}
+
 	/// Returns the number of quads in the dataset.
 	pub fn len(&self) -> usize {
 		self.quads.len()
@@ -108,6 +136,25 @@ impl<R> BTreeDataset<R> {
 	}
 }
 
+impl<I, L> BTreeDataset<Term<I, L>> {
+	/// Maps every literal of the dataset with the given function, returning
+	/// a new dataset.
+	///
+	/// Identifiers are left untouched. Since mapping literals may change
+	/// their order, the dataset is rebuilt from scratch.
+	pub fn map_literals<M>(self, f: impl FnMut(L) -> M) -> BTreeDataset<Term<I, M>>
+	where
+		I: Clone + Ord,
+		M: Clone + Ord,
+	{
+		map_quads_literals(
+			self.resources.into_iter().map(|(i, r)| (i, r.value)),
+			self.quads.into_iter().map(|(_, quad)| quad),
+			f,
+		)
+	}
+}
+
 impl<R: Ord> BTreeDataset<R> {
 	fn index_of_resource(&self, resource: &R) -> Option<usize> {
 		self.resources_indexes
@@ -132,6 +179,16 @@ impl<R: Ord> BTreeDataset<R> {
 		self.index_of_quad(quad).is_some()
 	}
 
+	/// Returns the quad stored in the dataset equal to the given one, if any.
+	///
+	/// Contrarily to [`Self::contains`], the returned quad borrows the
+	/// canonical resources stored in the dataset, which can then be shared
+	/// instead of the given ones (e.g. when `R` is reference counted).
+	pub fn get_quad(&self, quad: Quad<&R>) -> Option<Quad<&R>> {
+		self.index_of_quad(quad)
+			.map(|i| quad_with_resources(&self.resources, self.quads[i]))
+	}
+
 	/// Inserts the given quad in the dataset.
 	///
 	/// Returns `true` if the quad was not already in the dataset, and `false`
@@ -266,6 +323,16 @@ impl<R: Ord> BTreeDataset<R> {
 	}
 }
 
+impl BTreeDataset {
+	/// Checks that this dataset is equivalent to `other`, up to blank node
+	/// renaming.
+	///
+	/// See [`are_equivalent`].
+	pub fn equivalent(&self, other: &Self) -> bool {
+		are_equivalent(self, other)
+	}
+}
+
 impl<R: Clone + Ord> FromIterator<Quad<R>> for BTreeDataset<R> {
 	fn from_iter<T: IntoIterator<Item = Quad<R>>>(iter: T) -> Self {
 		let mut result = Self::new();
@@ -282,6 +349,58 @@ impl<R: Clone + Ord> Extend<Quad<R>> for BTreeDataset<R> {
 	}
 }
 
+impl<V, R: Clone + EmbedIntoVocabulary<V>> EmbedIntoVocabulary<V> for BTreeDataset<R>
+where
+	R::Embedded: Clone + Ord,
+{
+	type Embedded = BTreeDataset<R::Embedded>;
+
+	fn embed_into_vocabulary(self, vocabulary: &mut V) -> Self::Embedded {
+		self.into_iter()
+			.map(|quad| quad.map(|r| r.embed_into_vocabulary(vocabulary)))
+			.collect()
+	}
+}
+
+impl<V, R: EmbeddedIntoVocabulary<V>> EmbeddedIntoVocabulary<V> for BTreeDataset<R>
+where
+	R::Embedded: Clone + Ord,
+{
+	type Embedded = BTreeDataset<R::Embedded>;
+
+	fn embedded_into_vocabulary(&self, vocabulary: &mut V) -> Self::Embedded {
+		self.iter()
+			.map(|quad| quad.map(|r| r.embedded_into_vocabulary(vocabulary)))
+			.collect()
+	}
+}
+
+impl<V, R: Clone + ExtractFromVocabulary<V>> ExtractFromVocabulary<V> for BTreeDataset<R>
+where
+	R::Extracted: Clone + Ord,
+{
+	type Extracted = BTreeDataset<R::Extracted>;
+
+	fn extract_from_vocabulary(self, vocabulary: &V) -> Self::Extracted {
+		self.into_iter()
+			.map(|quad| quad.map(|r| r.extract_from_vocabulary(vocabulary)))
+			.collect()
+	}
+}
+
+impl<V, R: ExtractedFromVocabulary<V>> ExtractedFromVocabulary<V> for BTreeDataset<R>
+where
+	R::Extracted: Clone + Ord,
+{
+	type Extracted = BTreeDataset<R::Extracted>;
+
+	fn extracted_from_vocabulary(&self, vocabulary: &V) -> Self::Extracted {
+		self.iter()
+			.map(|quad| quad.map(|r| r.extracted_from_vocabulary(vocabulary)))
+			.collect()
+	}
+}
+
 impl<R> Dataset for BTreeDataset<R> {
 	type Resource = R;
 }
@@ -307,8 +426,8 @@ impl<R: Clone + Ord> DatasetMut for BTreeDataset<R> {
 		self.insert(quad);
 	}
 
-	fn remove(&mut self, quad: Quad<&Self::Resource>) {
-		self.remove(quad);
+	fn remove(&mut self, quad: Quad<&Self::Resource>) -> bool {
+		self.remove(quad)
 	}
 }
 
@@ -445,6 +564,15 @@ impl<R: RdfDisplay> RdfDisplay for BTreeDataset<R> {
 
 		Ok(())
 	}
+
+	fn rdf_write<W: std::io::Write + ?Sized>(&self, writer: &mut W) -> std::io::Result<()> {
+		for t in self {
+			t.rdf_write(writer)?;
+			writer.write_all(b" .\n")?;
+		}
+
+		Ok(())
+	}
 }
 
 #[cfg(feature = "serde")]
@@ -592,4 +720,146 @@ mod tests {
 			remove_test(i as usize * 11, [i; 32]);
 		}
 	}
+
+	#[test]
+	fn map_literals() {
+		use crate::Term;
+
+		let dataset: BTreeDataset<Term<u32, u32>> = [
+			Quad(Term::Id(0), Term::Id(1), Term::Literal(2), None),
+			Quad(
+				Term::Id(0),
+				Term::Id(1),
+				Term::Literal(3),
+				Some(Term::Id(4)),
+			),
+			Quad(Term::Id(0), Term::Id(1), Term::Id(5), None),
+		]
+		.into_iter()
+		.collect();
+
+		let mapped = dataset.map_literals(|l| l % 2 == 0);
+
+		let expected: BTreeDataset<Term<u32, bool>> = [
+			Quad(Term::Id(0), Term::Id(1), Term::Literal(true), None),
+			Quad(
+				Term::Id(0),
+				Term::Id(1),
+				Term::Literal(false),
+				Some(Term::Id(4)),
+			),
+			Quad(Term::Id(0), Term::Id(1), Term::Id(5), None),
+		]
+		.into_iter()
+		.collect();
+
+		assert_eq!(mapped, expected)
+	}
+
+	#[test]
+	fn equivalent() {
+		use crate::Term;
+		use static_iref::iri;
+
+		let p = Term::iri(iri!("http://example.org/p").to_owned());
+		let a = Term::iri(iri!("http://example.org/a").to_owned());
+
+		let left: BTreeDataset = [
+			Quad(
+				Term::blank("_:x".parse().unwrap()),
+				p.clone(),
+				a.clone(),
+				None,
+			),
+			Quad(
+				a.clone(),
+				p.clone(),
+				Term::blank("_:y".parse().unwrap()),
+				None,
+			),
+		]
+		.into_iter()
+		.collect();
+
+		let right: BTreeDataset = [
+			Quad(
+				Term::blank("_:b0".parse().unwrap()),
+				p.clone(),
+				a.clone(),
+				None,
+			),
+			Quad(
+				a.clone(),
+				p.clone(),
+				Term::blank("_:b1".parse().unwrap()),
+				None,
+			),
+		]
+		.into_iter()
+		.collect();
+
+		assert_ne!(left, right);
+		assert!(left.equivalent(&right));
+
+		let ground: BTreeDataset = [Quad(a.clone(), p.clone(), a.clone(), None)]
+			.into_iter()
+			.collect();
+
+		assert!(ground.equivalent(&ground.clone()));
+		assert!(!ground.equivalent(&left))
+	}
+
+	#[test]
+	fn embed_into_vocabulary() {
+		use crate::{
+			vocabulary::{EmbedIntoVocabulary, ExtractFromVocabulary, IndexVocabulary},
+			Literal, LiteralType, Term,
+		};
+		use static_iref::iri;
+
+		let dataset: BTreeDataset = [
+			Quad(
+				Term::iri(iri!("http://example.org/a").to_owned()),
+				Term::iri(iri!("http://example.org/p").to_owned()),
+				Term::Literal(Literal::new(
+					"1".to_owned(),
+					LiteralType::Any(iri!("http://www.w3.org/2001/XMLSchema#integer").to_owned()),
+				)),
+				None,
+			),
+			Quad(
+				Term::blank("_:b".parse().unwrap()),
+				Term::iri(iri!("http://example.org/p").to_owned()),
+				Term::iri(iri!("http://example.org/a").to_owned()),
+				Some(Term::iri(iri!("http://example.org/g").to_owned())),
+			),
+		]
+		.into_iter()
+		.collect();
+
+		let mut vocabulary: IndexVocabulary = IndexVocabulary::new();
+		let embedded = dataset.clone().embed_into_vocabulary(&mut vocabulary);
+		assert_eq!(embedded.len(), 2);
+		assert_eq!(embedded.resources().count(), 5);
+
+		let extracted: BTreeDataset = embedded.extract_from_vocabulary(&vocabulary);
+		assert_eq!(extracted, dataset)
+	}
+
+	#[test]
+	fn get_quad() {
+		use std::sync::Arc;
+
+		let a: Arc<str> = Arc::from("a");
+		let b: Arc<str> = Arc::from("b");
+		let dataset: BTreeDataset<Arc<str>> = [Quad(a.clone(), b.clone(), a.clone(), None)]
+			.into_iter()
+			.collect();
+
+		let query: Arc<str> = Arc::from("a");
+		let Quad(s, p, o, g) = dataset.get_quad(Quad(&query, &b, &query, None)).unwrap();
+		assert!(Arc::ptr_eq(s, &a) && Arc::ptr_eq(o, &a) && Arc::ptr_eq(p, &b));
+		assert!(g.is_none());
+		assert_eq!(dataset.get_quad(Quad(&query, &b, &b, None)), None)
+	}
 }
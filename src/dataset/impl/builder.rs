@@ -0,0 +1,98 @@
+use educe::Educe;
+
+use super::IndexedBTreeDataset;
+use crate::Quad;
+
+/// Dataset builder reporting duplicate quads.
+///
+/// Accumulates quads into an [`IndexedBTreeDataset`], keeping track of every
+/// inserted quad that was already present in the dataset.
+#[derive(Clone, Educe)]
+#[educe(Default)]
+pub struct DatasetBuilder<R> {
+	dataset: IndexedBTreeDataset<R>,
+	duplicates: Vec<Quad<R>>,
+}
+
+impl<R> DatasetBuilder<R> {
+	/// Creates a new empty builder.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Returns the number of distinct quads inserted so far.
+	pub fn len(&self) -> usize {
+		self.dataset.len()
+	}
+
+	/// Checks if no quad has been inserted yet.
+	pub fn is_empty(&self) -> bool {
+		self.dataset.is_empty()
+	}
+
+	/// Returns the duplicate quads inserted so far.
+	pub fn duplicates(&self) -> &[Quad<R>] {
+		&self.duplicates
+	}
+
+	/// Builds the dataset.
+	///
+	/// Returns the dataset along with the list of inserted quads that were
+	/// duplicates of a quad already present, in insertion order.
+	pub fn build(self) -> (IndexedBTreeDataset<R>, Vec<Quad<R>>) {
+		(self.dataset, self.duplicates)
+	}
+}
+
+impl<R: Ord> DatasetBuilder<R> {
+	/// Inserts the given quad.
+	///
+	/// Returns `true` if the quad was not already inserted, and `false` if it
+	/// was, in which case it is recorded as a duplicate.
+	pub fn insert(&mut self, quad: Quad<R>) -> bool {
+		if self.dataset.contains(quad.as_ref()) {
+			self.duplicates.push(quad);
+			false
+		} else {
+			self.dataset.insert(quad)
+		}
+	}
+}
+
+impl<R: Ord> Extend<Quad<R>> for DatasetBuilder<R> {
+	fn extend<T: IntoIterator<Item = Quad<R>>>(&mut self, iter: T) {
+		for quad in iter {
+			self.insert(quad);
+		}
+	}
+}
+
+impl<R: Ord> FromIterator<Quad<R>> for DatasetBuilder<R> {
+	fn from_iter<T: IntoIterator<Item = Quad<R>>>(iter: T) -> Self {
+		let mut result = Self::new();
+		result.extend(iter);
+		result
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::DatasetBuilder;
+	use crate::Quad;
+
+	#[test]
+	fn duplicates() {
+		let mut builder: DatasetBuilder<u32> = DatasetBuilder::new();
+		assert!(builder.insert(Quad(0, 1, 2, None)));
+		assert!(builder.insert(Quad(0, 1, 2, Some(3))));
+		assert!(!builder.insert(Quad(0, 1, 2, None)));
+		assert!(builder.insert(Quad(4, 1, 2, None)));
+		assert!(!builder.insert(Quad(0, 1, 2, Some(3))));
+		assert_eq!(builder.len(), 3);
+
+		let (dataset, duplicates) = builder.build();
+		assert_eq!(dataset.len(), 3);
+		assert!(dataset.contains(Quad(&4, &1, &2, None)));
+		assert_eq!(duplicates, [Quad(0, 1, 2, None), Quad(0, 1, 2, Some(3))])
+	}
+}
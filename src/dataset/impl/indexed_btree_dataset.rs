@@ -1,7 +1,13 @@
-use std::{cmp::Ordering, collections::BTreeSet, fmt::Debug, hash::Hash};
+use std::{
+	cmp::Ordering,
+	collections::{btree_map, BTreeMap, BTreeSet},
+	fmt::Debug,
+	hash::Hash,
+};
 
 use educe::Educe;
-use raw_btree::RawBTree;
+use langtag::LangTag;
+use raw_btree::{storage::BoxStorage, Address, RawBTree, Storage};
 use slab::Slab;
 
 use super::{
@@ -10,15 +16,19 @@ use super::{
 };
 use crate::{
 	dataset::{
-		BTreeGraph, DatasetMut, NamedGraphTraversableDataset, ObjectTraversableDataset,
-		PredicateTraversableDataset, ResourceTraversableDataset, SubjectTraversableDataset,
-		TraversableDataset,
+		isomorphism::are_equivalent, BTreeGraph, DatasetMut, NamedGraphTraversableDataset,
+		ObjectTraversableDataset, PredicateTraversableDataset, ResourceTraversableDataset,
+		SubjectTraversableDataset, TraversableDataset,
 	},
 	pattern::{
 		quad::canonical::{PatternGraph, PatternObject, PatternPredicate, PatternSubject},
 		CanonicalQuadPattern,
 	},
-	Quad, RdfDisplay, Term,
+	utils::{
+		btree_set_footprint, clone_raw_btree, map_quads_literals, raw_btree_footprint,
+		slab_footprint,
+	},
+	LiteralRef, Quad, RdfDisplay, Term,
 };
 
 fn resource_cmp<R: Ord>(resources: &Slab<Resource<R>>) -> impl '_ + Fn(&usize, &R) -> Ordering {
@@ -58,7 +68,6 @@ fn quad_index_cmp<'a, R: Ord>(
 }
 
 /// Indexed BTree-based RDF dataset, optimized for pattern matching operations.
-#[derive(Clone)]
 pub struct IndexedBTreeDataset<R = Term> {
 	resources: Slab<Resource<R>>,
 	quads: Slab<Quad<usize>>,
@@ -71,6 +80,22 @@ pub struct IndexedBTreeDataset<R = Term> {
 	named_graphs: BTreeSet<usize>,
 }
 
+impl<R: Clone> Clone for IndexedBTreeDataset<R> {
+	fn clone(&self) -> Self {
+		Self {
+			resources: self.resources.clone(),
+			quads: self.quads.clone(),
+			resources_indexes: clone_raw_btree(&self.resources_indexes),
+			quads_indexes: clone_raw_btree(&self.quads_indexes),
+			subjects: self.subjects.clone(),
+			predicates: self.predicates.clone(),
+			objects: self.objects.clone(),
+			default_graph: self.default_graph.clone(),
+			named_graphs: self.named_graphs.clone(),
+		}
+	}
+}
+
 impl<R> Default for IndexedBTreeDataset<R> {
 	fn default() -> Self {
 		Self {
@@ -93,22 +118,54 @@ impl<R> IndexedBTreeDataset<R> {
 		Self::default()
 	}
 
+	/// Creates a new empty dataset with room for `quads` quads and `resources` resources.
+	///
+	/// Only the quad and resource slabs are preallocated, not the B-tree indexes.
+	pub fn with_capacity(quads: usize, resources: usize) -> Self {
+		Self {
+			quads: Slab::with_capacity(quads),
+			resources: Slab::with_capacity(resources),
+			..Self::default()
+		}
+	}
+
+	/// Estimates the number of heap bytes used by this dataset.
+	///
+	/// This sums the capacities of the resource and quad storages, the
+	/// nodes of the B-tree indexes and the (estimated) sizes of the B-tree
+	/// sets indexing quads by resource. The sizes of the standard B-tree sets
+	/// are approximations, and heap memory owned by the resources themselves
+	/// (such as the text of IRIs and literals) is not taken into account.
+	pub fn memory_footprint(&self) -> usize {
+		let resources: usize = self
+			.resources
+			.iter()
+			.map(|(_, r)| {
+				btree_set_footprint(&r.as_subject)
+					+ btree_set_footprint(&r.as_predicate)
+					+ btree_set_footprint(&r.as_object)
+					+ btree_set_footprint(&r.as_graph)
+			})
+			.sum();
+
+		slab_footprint(&self.resources)
+			+ slab_footprint(&self.quads)
+			+ raw_btree_footprint(&self.resources_indexes)
+			+ raw_btree_footprint(&self.quads_indexes)
+			+ btree_set_footprint(&self.subjects)
+			+ btree_set_footprint(&self.predicates)
+			+ btree_set_footprint(&self.objects)
+			+ btree_set_footprint(&self.default_graph)
+			+ btree_set_footprint(&self.named_graphs)
+			+ resources
+	}
+
 	/// Creates a new indexed dataset from a non-indexed one.
 	pub fn from_non_indexed(dataset: BTreeDataset<R>) -> Self {
 		let mut resources: Slab<Resource<R>> = dataset
 			.resources
 			.into_iter()
-			.map(|(i, r)| {
-				let indexed_r = Resource {
-					value: r.value,
-					as_subject: BTreeSet::new(),
-					as_predicate: BTreeSet::new(),
-					as_object: BTreeSet::new(),
-					as_graph: BTreeSet::new(),
-				};
-
-				(i, indexed_r)
-			})
+			.map(|(i, r)| (i, Resource::new(r.value)))
 			.collect();
 
 		let mut subjects = BTreeSet::new();
@@ -172,6 +229,68 @@ impl<R> IndexedBTreeDataset<R> {
 		}
 	}
 
+	/// Returns the first quad of the dataset, if any.
+	///
+	/// This is the first quad returned by [`Self::iter`].
+	pub fn first_quad(&self) -> Option<Quad<&R>> {
+		self.quads_indexes
+			.first()
+			.map(|&i| quad_with_resources(&self.resources, self.quads[i]))
+	}
+
+	/// Returns the last quad of the dataset, if any.
+	///
+	/// This is the last quad returned by [`Self::iter`], retrieved without
+	/// iterating over the whole dataset.
+	pub fn last_quad(&self) -> Option<Quad<&R>> {
+		self.quads_indexes
+			.last()
+			.map(|&i| quad_with_resources(&self.resources, self.quads[i]))
+	}
+
+	/// Returns the `n`-th quad of the dataset, if any.
+	///
+	/// This is the `n`-th quad returned by [`Self::iter`]. Unlike
+	/// [`Self::first_quad`] and [`Self::last_quad`], this function runs in
+	/// linear time since the underlying B-Tree does not track the size of its
+	/// subtrees.
+	pub fn nth_quad(&self, n: usize) -> Option<Quad<&R>> {
+		self.iter().nth(n)
+	}
+
+	/// Returns an iterator over the quads of the dataset, sorted with the
+	/// given comparison function.
+	///
+	/// [`Self::iter`] follows the order of `R`, which may not be the desired
+	/// order (for instance when `R` is an index into a vocabulary). This
+	/// function can be used to get any other order, for instance to produce
+	/// a canonical serialization.
+	pub fn iter_sorted_by(
+		&self,
+		mut compare: impl FnMut(&Quad<&R>, &Quad<&R>) -> Ordering,
+	) -> std::vec::IntoIter<Quad<&R>> {
+		let mut quads: Vec<_> = self.iter().collect();
+		quads.sort_by(|a, b| compare(a, b));
+		quads.into_iter()
+	}
+
+	/// Returns an iterator over the quads of the dataset, sorted by graph,
+	/// subject, predicate then object, each resource being compared using
+	/// the given key function.
+	///
+	/// The default graph comes first. The key is computed once per quad
+	/// component. With a key function returning the lexical representation
+	/// of a resource (e.g. by looking it up in a vocabulary), quads are
+	/// returned in lexical order even if `R` is an interned index.
+	pub fn iter_sorted_by_key<K: Ord>(
+		&self,
+		mut key: impl FnMut(&R) -> K,
+	) -> std::vec::IntoIter<Quad<&R>> {
+		let mut quads: Vec<_> = self.iter().collect();
+		quads.sort_by_cached_key(|Quad(s, p, o, g)| (g.map(&mut key), key(s), key(p), key(o)));
+		quads.into_iter()
+	}
+
 	/// Returns an iterator over the resources of the dataset.
 	pub fn resources(&self) -> Resources<R> {
 		Resources {
@@ -211,6 +330,43 @@ impl<R> IndexedBTreeDataset<R> {
 			indexes: self.named_graphs.iter(),
 		}
 	}
+
+	/// Returns an iterator over the quads of the dataset, grouped by
+	/// predicate.
+	///
+	/// Every predicate of the dataset is yielded exactly once, along with an
+	/// iterator over the `(subject, object, graph)` components of the quads
+	/// using it, which are all distinct. Neither the predicates nor the
+	/// components of their quads are yielded in any particular order.
+	///
+	/// This is driven by the predicate index of the dataset, so no sorting
+	/// is performed.
+	pub fn partition_by_predicate(&self) -> PredicatePartitions<'_, R> {
+		PredicatePartitions {
+			resources: &self.resources,
+			quads: &self.quads,
+			indexes: self.predicates.iter(),
+		}
+	}
+}
+
+impl<I, L> IndexedBTreeDataset<Term<I, L>> {
+	/// Maps every literal of the dataset with the given function, returning
+	/// a new dataset.
+	///
+	/// Identifiers are left untouched. Since mapping literals may change
+	/// their order, the dataset is rebuilt from scratch.
+	pub fn map_literals<M>(self, f: impl FnMut(L) -> M) -> IndexedBTreeDataset<Term<I, M>>
+	where
+		I: Clone + Ord,
+		M: Clone + Ord,
+	{
+		map_quads_literals(
+			self.resources.into_iter().map(|(i, r)| (i, r.value)),
+			self.quads.into_iter().map(|(_, quad)| quad),
+			f,
+		)
+	}
 }
 
 impl<R: Ord> IndexedBTreeDataset<R> {
@@ -241,6 +397,16 @@ impl<R: Ord> IndexedBTreeDataset<R> {
 		self.index_of_quad(quad).is_some()
 	}
 
+	/// Returns the quad stored in the dataset equal to the given one, if any.
+	///
+	/// Contrarily to [`Self::contains`], the returned quad borrows the
+	/// canonical resources stored in the dataset, which can then be shared
+	/// instead of the given ones (e.g. when `R` is reference counted).
+	pub fn get_quad(&self, quad: Quad<&R>) -> Option<Quad<&R>> {
+		self.index_of_quad(quad)
+			.map(|i| quad_with_resources(&self.resources, self.quads[i]))
+	}
+
 	/// Inserts the given quad in the dataset.
 	///
 	/// Returns `true` if the quad was not already in the dataset, and `false`
@@ -249,65 +415,23 @@ impl<R: Ord> IndexedBTreeDataset<R> {
 		if self.contains(quad.as_ref()) {
 			false
 		} else {
-			let s_i = self.index_of_resource(&quad.0);
-			let p_i = self.index_of_resource(&quad.1);
-			let o_i = self.index_of_resource(&quad.2);
-			let g_i = quad.3.map(|g| {
-				let g_i = self.index_of_resource(&g);
-				(g, g_i)
-			});
-
-			let e = self.quads.vacant_entry();
-			let i = e.key();
-
-			let s_i = match s_i {
-				Some(s_i) => {
-					self.resources[s_i].as_subject.insert(i);
-					s_i
-				}
-				None => {
-					let s_i = self.resources.insert(Resource::subject(quad.0, i));
-					self.resources_indexes
-						.insert(resource_index_cmp(&self.resources), s_i);
-					s_i
-				}
-			};
+			let Quad(s, p, o, g) = quad;
+			let i = self.quads.vacant_key();
 
-			let p_i = match p_i {
-				Some(p_i) => {
-					self.resources[p_i].as_predicate.insert(i);
-					p_i
-				}
-				None => {
-					let p_i = self.resources.insert(Resource::predicate(quad.1, i));
-					self.resources_indexes
-						.insert(resource_index_cmp(&self.resources), p_i);
-					p_i
-				}
-			};
+			let s_i = self.get_or_insert_resource(s);
+			self.resources[s_i].as_subject.insert(i);
 
-			let o_i = match o_i {
-				Some(o_i) => {
-					self.resources[o_i].as_object.insert(i);
-					o_i
-				}
-				None => {
-					let o_i = self.resources.insert(Resource::object(quad.2, i));
-					self.resources_indexes
-						.insert(resource_index_cmp(&self.resources), o_i);
-					o_i
-				}
-			};
+			let p_i = self.get_or_insert_resource(p);
+			self.resources[p_i].as_predicate.insert(i);
 
-			let g_i = match g_i {
-				Some((_, Some(g_i))) => {
+			let o_i = self.get_or_insert_resource(o);
+			self.resources[o_i].as_object.insert(i);
+
+			let g_i = match g {
+				Some(g) => {
+					let g_i = self.get_or_insert_resource(g);
 					self.resources[g_i].as_graph.insert(i);
-					Some(g_i)
-				}
-				Some((g, None)) => {
-					let g_i = self.resources.insert(Resource::graph(g, i));
-					self.resources_indexes
-						.insert(resource_index_cmp(&self.resources), g_i);
+					self.named_graphs.insert(g_i);
 					Some(g_i)
 				}
 				None => {
@@ -319,11 +443,8 @@ impl<R: Ord> IndexedBTreeDataset<R> {
 			self.subjects.insert(s_i);
 			self.predicates.insert(p_i);
 			self.objects.insert(o_i);
-			if let Some(g_i) = g_i {
-				self.named_graphs.insert(g_i);
-			}
 
-			e.insert(Quad(s_i, p_i, o_i, g_i));
+			self.quads.insert(Quad(s_i, p_i, o_i, g_i));
 
 			self.quads_indexes
 				.insert(quad_index_cmp(&self.resources, &self.quads), i);
@@ -332,6 +453,23 @@ impl<R: Ord> IndexedBTreeDataset<R> {
 		}
 	}
 
+	/// Returns the index of the given resource, inserting it first if it is
+	/// not yet in the dataset.
+	///
+	/// A newly inserted resource does not appear in any quad until the caller
+	/// registers it in the relevant position indexes.
+	fn get_or_insert_resource(&mut self, resource: R) -> usize {
+		match self.index_of_resource(&resource) {
+			Some(i) => i,
+			None => {
+				let i = self.resources.insert(Resource::new(resource));
+				self.resources_indexes
+					.insert(resource_index_cmp(&self.resources), i);
+				i
+			}
+		}
+	}
+
 	/// Removes the given quad from the dataset.
 	///
 	/// Returns whether or not the quad was in the dataset.
@@ -384,12 +522,11 @@ impl<R: Ord> IndexedBTreeDataset<R> {
 
 		let Quad(s_i, p_i, o_i, g_i) = self.quads.remove(i);
 
-		self.subjects.remove(&s_i);
-		self.predicates.remove(&p_i);
-		self.objects.remove(&o_i);
-
 		let s = &mut self.resources[s_i];
 		s.as_subject.remove(&i);
+		if s.as_subject.is_empty() {
+			self.subjects.remove(&s_i);
+		}
 		if s.is_empty() {
 			self.resources_indexes
 				.remove(resource_index_cmp(&self.resources), &s_i);
@@ -398,6 +535,9 @@ impl<R: Ord> IndexedBTreeDataset<R> {
 
 		let p = &mut self.resources[p_i];
 		p.as_predicate.remove(&i);
+		if p.as_predicate.is_empty() {
+			self.predicates.remove(&p_i);
+		}
 		if p.is_empty() {
 			self.resources_indexes
 				.remove(resource_index_cmp(&self.resources), &p_i);
@@ -406,6 +546,9 @@ impl<R: Ord> IndexedBTreeDataset<R> {
 
 		let o = &mut self.resources[o_i];
 		o.as_object.remove(&i);
+		if o.as_object.is_empty() {
+			self.objects.remove(&o_i);
+		}
 		if o.is_empty() {
 			self.resources_indexes
 				.remove(resource_index_cmp(&self.resources), &o_i);
@@ -416,11 +559,13 @@ impl<R: Ord> IndexedBTreeDataset<R> {
 			Some(g_i) => {
 				let g = &mut self.resources[g_i];
 				g.as_graph.remove(&i);
+				if g.as_graph.is_empty() {
+					self.named_graphs.remove(&g_i);
+				}
 				if g.is_empty() {
 					self.resources_indexes
 						.remove(resource_index_cmp(&self.resources), &g_i);
 					self.resources.remove(g_i);
-					self.named_graphs.remove(&g_i);
 				}
 			}
 			None => {
@@ -429,17 +574,121 @@ impl<R: Ord> IndexedBTreeDataset<R> {
 		}
 	}
 
+	/// Computes statistics about the content of the dataset, for diagnostics
+	/// or query planning.
+	///
+	/// Counts are read directly from the dataset indexes. Computing the
+	/// per-predicate fan-out requires visiting every distinct predicate once.
+	pub fn statistics(&self) -> DatasetStats {
+		let max_predicate_fan_out = self
+			.predicates
+			.iter()
+			.map(|&p| self.resources[p].as_predicate.len())
+			.max()
+			.unwrap_or(0);
+
+		let average_predicate_fan_out = if self.predicates.is_empty() {
+			0.0
+		} else {
+			self.quads.len() as f64 / self.predicates.len() as f64
+		};
+
+		DatasetStats {
+			quad_count: self.quads.len(),
+			subject_count: self.subjects.len(),
+			predicate_count: self.predicates.len(),
+			object_count: self.objects.len(),
+			named_graph_count: self.named_graphs.len(),
+			max_predicate_fan_out,
+			average_predicate_fan_out,
+		}
+	}
+
+	/// Returns an iterator over the quads whose subject is in the half-open
+	/// range `[start, end)`, ordered by subject, predicate, object and graph.
+	///
+	/// Since resources are ordered lexically, this can be used to list the
+	/// quads whose subject is an IRI under a given namespace.
+	///
+	/// The quad index is first searched for the first quad whose subject is
+	/// not lower than `start`, so the quads preceding the range are never
+	/// visited.
+	pub fn quads_in_subject_range<'a>(
+		&'a self,
+		start: &R,
+		end: &'a R,
+	) -> QuadsInSubjectRange<'a, R> {
+		// Never returns `Equal`, so that the search ends on the insertion
+		// address of `start`, before any quad with that subject.
+		let cmp = |&i: &usize, start: &R| {
+			if self.resources[self.quads[i].0].value < *start {
+				Ordering::Less
+			} else {
+				Ordering::Greater
+			}
+		};
+
+		let addr = match self.quads_indexes.address_of(cmp, start) {
+			Ok(addr) => Some(addr),
+			// SAFETY: `RawBTree::normalize` requires a valid address whose
+			// node is owned by the tree. `addr` is the insertion address
+			// just returned by `address_of` on the same tree, which is not
+			// modified in between.
+			Err(addr) => addr.and_then(|addr| unsafe { self.quads_indexes.normalize(addr) }),
+		};
+
+		QuadsInSubjectRange {
+			resources: &self.resources,
+			quads: &self.quads,
+			indexes: &self.quads_indexes,
+			addr,
+			end,
+		}
+	}
+
 	/// Returns an iterator over all the quads matching the given canonical
 	/// quad pattern.
 	pub fn pattern_matching(&self, pattern: CanonicalQuadPattern<&R>) -> PatternMatching<R> {
+		self.pattern_matching_from(pattern, QuadCursor::default())
+	}
+
+	/// Returns an iterator over the quads matching the given canonical quad
+	/// pattern, starting from the given cursor.
+	///
+	/// The cursor is typically obtained with [`PatternMatching::cursor`]
+	/// after a previous iteration has been interrupted, so that the matching
+	/// quads can be served in pages without visiting the same quads twice.
+	/// A cursor is only valid against the dataset it was obtained from,
+	/// provided it has not been modified since.
+	pub fn pattern_matching_from(
+		&self,
+		pattern: CanonicalQuadPattern<&R>,
+		cursor: QuadCursor,
+	) -> PatternMatching<'_, R> {
+		let start = cursor.0;
 		PatternMatching {
 			resources: &self.resources,
 			quads: &self.quads,
-			subject: SubjectConstraints::new(self, pattern.into_subject()),
-			predicate: PredicateConstraints::new(self, pattern.into_predicate()),
-			object: ObjectConstraints::new(self, pattern.into_object()),
-			graph: GraphConstraints::new(self, pattern.into_graph()),
-			i: 0,
+			subject: SubjectConstraints::new(self, pattern.into_subject(), start),
+			predicate: PredicateConstraints::new(self, pattern.into_predicate(), start),
+			object: ObjectConstraints::new(self, pattern.into_object(), start),
+			graph: GraphConstraints::new(self, pattern.into_graph(), start),
+			i: start,
+		}
+	}
+
+	/// Returns an iterator over all the quads *not* matching the given
+	/// canonical quad pattern.
+	///
+	/// The matching quads are first collected and sorted, then walked in
+	/// tandem with the whole dataset.
+	pub fn quads_not_matching(&self, pattern: CanonicalQuadPattern<&R>) -> QuadsNotMatching<'_, R> {
+		let mut matching: Vec<_> = self.pattern_matching(pattern).collect();
+		matching.sort_unstable();
+
+		QuadsNotMatching {
+			quads: self.iter(),
+			matching: matching.into_iter().peekable(),
 		}
 	}
 
@@ -465,12 +714,323 @@ impl<R: Ord> IndexedBTreeDataset<R> {
 	}
 }
 
+impl<R: Clone + Ord> IndexedBTreeDataset<R> {
+	/// Moves all the quads of `other` into this dataset.
+	///
+	/// Only the smallest of the two datasets is drained and re-inserted into
+	/// the other, whose resources and indexes are kept as is. In particular,
+	/// appending to an empty dataset does not re-insert anything.
+	pub fn append(&mut self, mut other: Self) {
+		if other.len() > self.len() {
+			std::mem::swap(self, &mut other)
+		}
+
+		self.extend(other)
+	}
+
+	/// Renames the named graphs of the dataset.
+	///
+	/// The function `f` is called once on the name of each named graph and
+	/// returns its new name. Quads of graphs mapped to `None` are moved to
+	/// the default graph. Graphs mapped to the same name are merged, in which
+	/// case duplicate quads are collapsed. Quads of graphs whose name does not
+	/// change are left untouched.
+	///
+	/// Graphs are relabeled in place: the graph component of each quad of a
+	/// renamed graph is rewritten, and the quad is moved in the graph
+	/// indexes. Subjects, predicates and objects are not re-indexed. A quad
+	/// is only removed if it collides with a quad already in its new graph.
+	pub fn map_graphs(&mut self, mut f: impl FnMut(&R) -> Option<R>) {
+		let mut renamed = Vec::new();
+		for &g_i in &self.named_graphs {
+			let g = &self.resources[g_i].value;
+			let new_g = f(g);
+			if new_g.as_ref() != Some(g) {
+				renamed.push((g_i, new_g))
+			}
+		}
+
+		// The quad order depends on the graph, so the quads of renamed graphs
+		// are taken out of the quad index before any of them is relabeled.
+		let mut moved = Vec::with_capacity(renamed.len());
+		for (g_i, new_g) in renamed {
+			let quads: Vec<usize> = self.resources[g_i].as_graph.iter().copied().collect();
+			for i in &quads {
+				self.quads_indexes
+					.remove(quad_index_cmp(&self.resources, &self.quads), i);
+			}
+
+			moved.push((quads, new_g))
+		}
+
+		for (quads, new_g) in moved {
+			for i in quads {
+				self.relabel_quad(i, new_g.as_ref())
+			}
+		}
+	}
+
+	/// Moves the quad at index `i` from its named graph to the given graph.
+	///
+	/// The quad must have been removed from the quad index beforehand. It is
+	/// re-inserted in the quad index, unless it collides with a quad already
+	/// in the new graph, in which case it is removed from the dataset.
+	fn relabel_quad(&mut self, i: usize, new_g: Option<&R>) {
+		let Quad(s, p, o, _) = quad_with_resources(&self.resources, self.quads[i]);
+		if self.index_of_quad(Quad(s, p, o, new_g)).is_some() {
+			self.remove_by_index(i, false);
+			return;
+		}
+
+		let g_i = self.quads[i]
+			.3
+			.expect("relabeled quads are in a named graph");
+		let g = &mut self.resources[g_i];
+		g.as_graph.remove(&i);
+		if g.as_graph.is_empty() {
+			self.named_graphs.remove(&g_i);
+		}
+		if g.is_empty() {
+			self.resources_indexes
+				.remove(resource_index_cmp(&self.resources), &g_i);
+			self.resources.remove(g_i);
+		}
+
+		self.quads[i].3 = match new_g {
+			Some(new_g) => {
+				let g_i = match self.index_of_resource(new_g) {
+					Some(g_i) => g_i,
+					None => self.get_or_insert_resource(new_g.clone()),
+				};
+				self.resources[g_i].as_graph.insert(i);
+				self.named_graphs.insert(g_i);
+				Some(g_i)
+			}
+			None => {
+				self.default_graph.insert(i);
+				None
+			}
+		};
+
+		self.quads_indexes
+			.insert(quad_index_cmp(&self.resources, &self.quads), i);
+	}
+}
+
+impl IndexedBTreeDataset {
+	/// Returns the language-tagged string objects of the quads matching
+	/// `subject predicate o graph`, grouped by language tag.
+	///
+	/// Groups are returned in the order their language tag is first
+	/// encountered. Objects that are not language-tagged strings (IRIs,
+	/// blank nodes and other literals) are skipped.
+	pub fn lang_objects<'a>(
+		&'a self,
+		graph: Option<&Term>,
+		subject: &Term,
+		predicate: &Term,
+	) -> std::vec::IntoIter<(&'a LangTag, Vec<LiteralRef<'a>>)> {
+		let mut groups: Vec<(&'a LangTag, Vec<LiteralRef<'a>>)> = Vec::new();
+
+		for object in self.quad_objects(graph, subject, predicate) {
+			let Some(literal) = object.as_literal() else {
+				continue;
+			};
+
+			let Some(tag) = literal.lang_tag() else {
+				continue;
+			};
+
+			match groups.iter_mut().find(|(t, _)| *t == tag) {
+				Some((_, literals)) => literals.push(literal.as_ref()),
+				None => groups.push((tag, vec![literal.as_ref()])),
+			}
+		}
+
+		groups.into_iter()
+	}
+
+	/// Checks that this dataset is equivalent to `other`, up to blank node
+	/// renaming.
+	///
+	/// See [`are_equivalent`].
+	pub fn equivalent(&self, other: &Self) -> bool {
+		are_equivalent(self, other)
+	}
+}
+
+/// Dataset statistics.
+///
+/// Returned by [`IndexedBTreeDataset::statistics`].
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct DatasetStats {
+	/// Number of quads.
+	pub quad_count: usize,
+
+	/// Number of distinct subjects.
+	pub subject_count: usize,
+
+	/// Number of distinct predicates.
+	pub predicate_count: usize,
+
+	/// Number of distinct objects.
+	pub object_count: usize,
+
+	/// Number of distinct named graphs.
+	pub named_graph_count: usize,
+
+	/// Highest number of quads sharing the same predicate.
+	pub max_predicate_fan_out: usize,
+
+	/// Average number of quads per distinct predicate.
+	pub average_predicate_fan_out: f64,
+}
+
 impl From<BTreeDataset> for IndexedBTreeDataset {
 	fn from(value: BTreeDataset) -> Self {
 		Self::from_non_indexed(value)
 	}
 }
 
+#[cfg(feature = "rayon")]
+impl<R: Ord> IndexedBTreeDataset<R> {
+	/// Merges the given dataset into this one.
+	///
+	/// The resources of both datasets are merged in order and renumbered
+	/// following this order, so that comparing two renumbered quads is the
+	/// same as comparing their resources. The renumbered quads of both
+	/// datasets are then merged in order too, and every index is built by
+	/// appending items in order, without comparing any resource again.
+	fn merge(mut self, mut other: Self) -> Self {
+		let mut self_map = vec![usize::MAX; self.resources.capacity()];
+		let mut other_map = vec![usize::MAX; other.resources.capacity()];
+		let mut resources = Slab::with_capacity(self.resources.len() + other.resources.len());
+
+		let self_order: Vec<usize> = self.resources_indexes.iter().copied().collect();
+		let other_order: Vec<usize> = other.resources_indexes.iter().copied().collect();
+		let mut a = self_order.into_iter().peekable();
+		let mut b = other_order.into_iter().peekable();
+		loop {
+			let order = match (a.peek(), b.peek()) {
+				(Some(&i), Some(&j)) => self.resources[i].value.cmp(&other.resources[j].value),
+				(Some(_), None) => Ordering::Less,
+				(None, Some(_)) => Ordering::Greater,
+				(None, None) => break,
+			};
+
+			let k = resources.vacant_key();
+			let value = match order {
+				Ordering::Less => {
+					let i = a.next().unwrap();
+					self_map[i] = k;
+					self.resources.remove(i).value
+				}
+				Ordering::Greater => {
+					let j = b.next().unwrap();
+					other_map[j] = k;
+					other.resources.remove(j).value
+				}
+				Ordering::Equal => {
+					let i = a.next().unwrap();
+					let j = b.next().unwrap();
+					self_map[i] = k;
+					other_map[j] = k;
+					other.resources.remove(j);
+					self.resources.remove(i).value
+				}
+			};
+
+			resources.insert(Resource::new(value));
+		}
+
+		let renumbered = |dataset: &Self, map: &[usize]| -> Vec<Quad<usize>> {
+			dataset
+				.quads_indexes
+				.iter()
+				.map(|&i| {
+					let Quad(s, p, o, g) = dataset.quads[i];
+					Quad(map[s], map[p], map[o], g.map(|g| map[g]))
+				})
+				.collect()
+		};
+
+		let mut a = renumbered(&self, &self_map).into_iter().peekable();
+		let mut b = renumbered(&other, &other_map).into_iter().peekable();
+
+		let mut result = Self {
+			resources_indexes: crate::utils::raw_btree_from_sorted(0..resources.len()),
+			resources,
+			quads: Slab::with_capacity(a.len() + b.len()),
+			..Self::default()
+		};
+
+		loop {
+			let quad = match (a.peek(), b.peek()) {
+				(Some(x), Some(y)) if x > y => b.next(),
+				(Some(x), Some(y)) if x == y => {
+					b.next();
+					a.next()
+				}
+				(Some(_), _) => a.next(),
+				(None, _) => b.next(),
+			};
+
+			match quad {
+				Some(quad) => result.push_greatest(quad),
+				None => break result,
+			}
+		}
+	}
+
+	/// Inserts the given quad of resource indexes, assuming it is greater
+	/// than any quad already in the dataset.
+	fn push_greatest(&mut self, quad: Quad<usize>) {
+		let Quad(s, p, o, g) = quad;
+		let i = self.quads.insert(quad);
+
+		self.resources[s].as_subject.insert(i);
+		self.resources[p].as_predicate.insert(i);
+		self.resources[o].as_object.insert(i);
+		self.subjects.insert(s);
+		self.predicates.insert(p);
+		self.objects.insert(o);
+
+		match g {
+			Some(g) => {
+				self.resources[g].as_graph.insert(i);
+				self.named_graphs.insert(g);
+			}
+			None => {
+				self.default_graph.insert(i);
+			}
+		}
+
+		self.quads_indexes.insert(|_, _| Ordering::Less, i);
+	}
+}
+
+#[cfg(feature = "rayon")]
+impl<R: Clone + Ord + Send> IndexedBTreeDataset<R> {
+	/// Builds a dataset from a parallel iterator of quads.
+	///
+	/// The input is split into chunks, each collected into its own dataset
+	/// on a separate thread. The sub-datasets are then merged pairwise, in
+	/// time linear in their size.
+	///
+	/// This only pays off with several cores available: on a single thread
+	/// the extra merges make it slower than [`FromIterator::from_iter`]. See
+	/// the `par_from_iter` benchmark.
+	pub fn par_from_iter<I: rayon::iter::IntoParallelIterator<Item = Quad<R>>>(iter: I) -> Self {
+		use rayon::iter::ParallelIterator;
+		iter.into_par_iter()
+			.fold(Self::new, |mut dataset, quad| {
+				dataset.insert(quad);
+				dataset
+			})
+			.reduce(Self::new, Self::merge)
+	}
+}
+
 impl<R: Clone + Ord> FromIterator<Quad<R>> for IndexedBTreeDataset<R> {
 	fn from_iter<T: IntoIterator<Item = Quad<R>>>(iter: T) -> Self {
 		let mut result = Self::new();
@@ -564,8 +1124,8 @@ impl<R: Clone + Ord> DatasetMut for IndexedBTreeDataset<R> {
 		self.insert(quad);
 	}
 
-	fn remove(&mut self, quad: Quad<&Self::Resource>) {
-		self.remove(quad);
+	fn remove(&mut self, quad: Quad<&Self::Resource>) -> bool {
+		self.remove(quad)
 	}
 }
 
@@ -582,6 +1142,61 @@ impl<R: Ord> PatternMatchingDataset for IndexedBTreeDataset<R> {
 	fn contains_quad(&self, quad: Quad<&Self::Resource>) -> bool {
 		self.contains(quad)
 	}
+
+	fn predicate_count_of_subject(&self, graph: Option<&R>, subject: &R) -> usize {
+		let Some(s) = self.index_of_resource(subject) else {
+			return 0;
+		};
+
+		let g = match graph {
+			Some(g) => match self.index_of_resource(g) {
+				Some(g) => Some(g),
+				None => return 0,
+			},
+			None => None,
+		};
+
+		self.resources[s]
+			.as_subject
+			.iter()
+			.filter_map(|&i| {
+				let quad = &self.quads[i];
+				(quad.3 == g).then_some(quad.1)
+			})
+			.collect::<BTreeSet<_>>()
+			.len()
+	}
+
+	fn object_frequencies<'a>(
+		&'a self,
+		graph: Option<&R>,
+		predicate: &R,
+	) -> btree_map::IntoIter<&'a R, usize> {
+		let mut frequencies = BTreeMap::new();
+
+		let Some(p) = self.index_of_resource(predicate) else {
+			return frequencies.into_iter();
+		};
+
+		let g = match graph {
+			Some(g) => match self.index_of_resource(g) {
+				Some(g) => Some(g),
+				None => return frequencies.into_iter(),
+			},
+			None => None,
+		};
+
+		for &i in &self.resources[p].as_predicate {
+			let quad = &self.quads[i];
+			if quad.3 == g {
+				*frequencies
+					.entry(&self.resources[quad.2].value)
+					.or_insert(0) += 1
+			}
+		}
+
+		frequencies.into_iter()
+	}
 }
 
 /// Iterator over the quads of a [`BTreeGraph`].
@@ -681,6 +1296,54 @@ impl<'a, R> Iterator for Predicates<'a, R> {
 	}
 }
 
+/// Iterator over the predicates of an [`IndexedBTreeDataset`] along with the
+/// quads using them.
+///
+/// See [`IndexedBTreeDataset::partition_by_predicate`].
+pub struct PredicatePartitions<'a, R> {
+	resources: &'a Slab<Resource<R>>,
+	quads: &'a Slab<Quad<usize>>,
+	indexes: std::collections::btree_set::Iter<'a, usize>,
+}
+
+impl<'a, R> Iterator for PredicatePartitions<'a, R> {
+	type Item = (&'a R, PredicateQuads<'a, R>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.indexes.next().map(|&i| {
+			let p = &self.resources[i];
+			let quads = PredicateQuads {
+				resources: self.resources,
+				quads: self.quads,
+				indexes: p.as_predicate.iter(),
+			};
+
+			(&p.value, quads)
+		})
+	}
+}
+
+/// Iterator over the `(subject, object, graph)` components of the quads using
+/// a given predicate.
+///
+/// See [`IndexedBTreeDataset::partition_by_predicate`].
+pub struct PredicateQuads<'a, R> {
+	resources: &'a Slab<Resource<R>>,
+	quads: &'a Slab<Quad<usize>>,
+	indexes: std::collections::btree_set::Iter<'a, usize>,
+}
+
+impl<'a, R> Iterator for PredicateQuads<'a, R> {
+	type Item = (&'a R, &'a R, Option<&'a R>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.indexes.next().map(|&i| {
+			let Quad(s, _, o, g) = quad_with_resources(self.resources, self.quads[i]);
+			(s, o, g)
+		})
+	}
+}
+
 pub struct Objects<'a, R> {
 	resources: &'a Slab<Resource<R>>,
 	indexes: std::collections::btree_set::Iter<'a, usize>,
@@ -736,6 +1399,66 @@ impl<R: Hash> Hash for IndexedBTreeDataset<R> {
 	}
 }
 
+/// Iterator over the quads of an [`IndexedBTreeDataset`] not matching some
+/// given pattern.
+///
+/// See [`IndexedBTreeDataset::quads_not_matching`].
+pub struct QuadsNotMatching<'a, R> {
+	quads: Quads<'a, R>,
+	matching: std::iter::Peekable<std::vec::IntoIter<Quad<&'a R>>>,
+}
+
+impl<'a, R: Ord> Iterator for QuadsNotMatching<'a, R> {
+	type Item = Quad<&'a R>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.quads
+			.find(|quad| self.matching.next_if_eq(quad).is_none())
+	}
+}
+
+/// Iterator over the quads of an [`IndexedBTreeDataset`] whose subject is in
+/// a given range.
+///
+/// Returned by [`IndexedBTreeDataset::quads_in_subject_range`].
+pub struct QuadsInSubjectRange<'a, R> {
+	resources: &'a Slab<Resource<R>>,
+	quads: &'a Slab<Quad<usize>>,
+	indexes: &'a RawBTree<usize>,
+
+	/// Item address of the next quad index in `indexes`.
+	///
+	/// It is always returned by `RawBTree::normalize` or
+	/// `RawBTree::next_item_address` on `indexes`, which only return item
+	/// addresses. Since `indexes` is borrowed for `'a`, it cannot be modified
+	/// while this iterator exists, so the node of the address stays alive.
+	addr: Option<Address<<BoxStorage as Storage<usize>>::Node>>,
+
+	end: &'a R,
+}
+
+impl<'a, R: Ord> Iterator for QuadsInSubjectRange<'a, R> {
+	type Item = Quad<&'a R>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let addr = self.addr.take()?;
+
+		// SAFETY: `RawBTree::get_at` requires an address whose node is owned
+		// by the tree. `addr` is an item address of `self.indexes` (see the
+		// field documentation).
+		let i = *unsafe { self.indexes.get_at(addr) }?;
+		let quad = quad_with_resources(self.resources, self.quads[i]);
+		if quad.0 >= self.end {
+			return None;
+		}
+
+		// SAFETY: `RawBTree::next_item_address` requires an item address
+		// whose node is owned by the tree, which `addr` still is.
+		self.addr = unsafe { self.indexes.next_item_address(addr) };
+		Some(quad)
+	}
+}
+
 /// Iterator over the quads of a [`BTreeGraph`] matching some given pattern.
 pub struct PatternMatching<'a, R> {
 	resources: &'a Slab<Resource<R>>,
@@ -747,13 +1470,27 @@ pub struct PatternMatching<'a, R> {
 	i: usize,
 }
 
+impl<'a, R> PatternMatching<'a, R> {
+	/// Returns the resume point of this iteration.
+	///
+	/// Passing it to [`IndexedBTreeDataset::pattern_matching_from`] with the
+	/// same pattern returns an iterator over the remaining matching quads.
+	pub fn cursor(&self) -> QuadCursor {
+		QuadCursor(self.i)
+	}
+}
+
 impl<'a, R> Iterator for PatternMatching<'a, R> {
 	type Item = Quad<&'a R>;
 
 	fn next(&mut self) -> Option<Self::Item> {
 		while self.i < self.quads.capacity() {
 			let i = self.subject.next(self.i)?;
-			let quad = *self.quads.get(i)?;
+			let Some(&quad) = self.quads.get(i) else {
+				// Vacant slot left by a removed quad.
+				self.i = i + 1;
+				continue;
+			};
 			match self.predicate.next(i, quad) {
 				Ok(()) => match self.object.next(i, quad) {
 					Ok(()) => match self.graph.next(i, quad) {
@@ -773,6 +1510,27 @@ impl<'a, R> Iterator for PatternMatching<'a, R> {
 	}
 }
 
+/// Resume point of a [`PatternMatching`] iteration.
+///
+/// Returned by [`PatternMatching::cursor`]. It is only valid against an
+/// unmodified dataset.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QuadCursor(usize);
+
+impl QuadCursor {
+	/// Creates a cursor from its raw quad index, as returned by
+	/// [`Self::index`].
+	pub fn from_index(index: usize) -> Self {
+		Self(index)
+	}
+
+	/// Returns the raw quad index of this cursor.
+	pub fn index(&self) -> usize {
+		self.0
+	}
+}
+
 /// Iterator over the quads of a [`BTreeGraph`] matching some given pattern.
 pub struct ExtractPatternMatching<'a, R> {
 	dataset: &'a mut IndexedBTreeDataset<R>,
@@ -789,7 +1547,11 @@ impl<'a, R: Clone + Ord> Iterator for ExtractPatternMatching<'a, R> {
 	fn next(&mut self) -> Option<Self::Item> {
 		while self.i < self.dataset.quads.capacity() {
 			let i = self.subject.next(self.i)?;
-			let quad = *self.dataset.quads.get(i)?;
+			let Some(&quad) = self.dataset.quads.get(i) else {
+				// Vacant slot left by a removed quad.
+				self.i = i + 1;
+				continue;
+			};
 			match self.predicate.next(i, quad) {
 				Ok(()) => match self.object.next(i, quad) {
 					Ok(()) => match self.graph.next(i, quad) {
@@ -811,7 +1573,7 @@ impl<'a, R: Clone + Ord> Iterator for ExtractPatternMatching<'a, R> {
 	}
 }
 
-type TripleIndexes<'a> = std::iter::Copied<std::collections::btree_set::Iter<'a, usize>>;
+type TripleIndexes<'a> = std::iter::Copied<std::collections::btree_set::Range<'a, usize>>;
 type OwnedTripleIndexes = std::vec::IntoIter<usize>;
 
 enum SubjectConstraints<I: Iterator> {
@@ -821,11 +1583,15 @@ enum SubjectConstraints<I: Iterator> {
 }
 
 impl<'a> SubjectConstraints<TripleIndexes<'a>> {
-	fn new<R: Ord>(dataset: &'a IndexedBTreeDataset<R>, s: PatternSubject<&R>) -> Self {
+	fn new<R: Ord>(
+		dataset: &'a IndexedBTreeDataset<R>,
+		s: PatternSubject<&R>,
+		start: usize,
+	) -> Self {
 		match s {
 			PatternSubject::Any => Self::Any,
 			PatternSubject::Given(s) => match dataset.get_resource(s) {
-				Some(subject) => Self::Fixed(subject.as_subject.iter().copied().peekable()),
+				Some(subject) => Self::Fixed(subject.as_subject.range(start..).copied().peekable()),
 				None => Self::None,
 			},
 		}
@@ -880,12 +1646,18 @@ enum PredicateConstraints<I: Iterator> {
 }
 
 impl<'a> PredicateConstraints<TripleIndexes<'a>> {
-	fn new<R: Ord>(dataset: &'a IndexedBTreeDataset<R>, p: PatternPredicate<&R>) -> Self {
+	fn new<R: Ord>(
+		dataset: &'a IndexedBTreeDataset<R>,
+		p: PatternPredicate<&R>,
+		start: usize,
+	) -> Self {
 		match p {
 			PatternPredicate::Any => Self::Any,
 			PatternPredicate::SameAsSubject => Self::SameAsSubject,
 			PatternPredicate::Given(s) => match dataset.get_resource(s) {
-				Some(subject) => Self::Fixed(subject.as_predicate.iter().copied().peekable()),
+				Some(subject) => {
+					Self::Fixed(subject.as_predicate.range(start..).copied().peekable())
+				}
 				None => Self::None,
 			},
 		}
@@ -951,13 +1723,17 @@ enum ObjectConstraints<I: Iterator> {
 }
 
 impl<'a> ObjectConstraints<TripleIndexes<'a>> {
-	fn new<R: Ord>(dataset: &'a IndexedBTreeDataset<R>, p: PatternObject<&R>) -> Self {
+	fn new<R: Ord>(
+		dataset: &'a IndexedBTreeDataset<R>,
+		p: PatternObject<&R>,
+		start: usize,
+	) -> Self {
 		match p {
 			PatternObject::Any => Self::Any,
 			PatternObject::SameAsSubject => Self::SameAsSubject,
 			PatternObject::SameAsPredicate => Self::SameAsPredicate,
 			PatternObject::Given(s) => match dataset.get_resource(s) {
-				Some(subject) => Self::Fixed(subject.as_object.iter().copied().peekable()),
+				Some(subject) => Self::Fixed(subject.as_object.range(start..).copied().peekable()),
 				None => Self::None,
 			},
 		}
@@ -1032,18 +1808,18 @@ enum GraphConstraints<I: Iterator> {
 }
 
 impl<'a> GraphConstraints<TripleIndexes<'a>> {
-	fn new<R: Ord>(dataset: &'a IndexedBTreeDataset<R>, g: PatternGraph<&R>) -> Self {
+	fn new<R: Ord>(dataset: &'a IndexedBTreeDataset<R>, g: PatternGraph<&R>, start: usize) -> Self {
 		match g {
 			PatternGraph::Any => Self::Any,
 			PatternGraph::SameAsSubject => Self::SameAsSubject,
 			PatternGraph::SameAsPredicate => Self::SameAsPredicate,
 			PatternGraph::SameAsObject => Self::SameAsObject,
 			PatternGraph::Given(Some(s)) => match dataset.get_resource(s) {
-				Some(subject) => Self::Fixed(subject.as_graph.iter().copied().peekable()),
+				Some(subject) => Self::Fixed(subject.as_graph.range(start..).copied().peekable()),
 				None => Self::None,
 			},
 			PatternGraph::Given(None) => {
-				Self::Fixed(dataset.default_graph.iter().copied().peekable())
+				Self::Fixed(dataset.default_graph.range(start..).copied().peekable())
 			}
 		}
 	}
@@ -1134,43 +1910,13 @@ struct Resource<R> {
 }
 
 impl<R> Resource<R> {
-	pub fn subject(value: R, i: usize) -> Self {
-		Self {
-			value,
-			as_subject: std::iter::once(i).collect(),
-			as_predicate: BTreeSet::new(),
-			as_object: BTreeSet::new(),
-			as_graph: BTreeSet::new(),
-		}
-	}
-
-	pub fn predicate(value: R, i: usize) -> Self {
-		Self {
-			value,
-			as_subject: BTreeSet::new(),
-			as_predicate: std::iter::once(i).collect(),
-			as_object: BTreeSet::new(),
-			as_graph: BTreeSet::new(),
-		}
-	}
-
-	pub fn object(value: R, i: usize) -> Self {
-		Self {
-			value,
-			as_subject: BTreeSet::new(),
-			as_predicate: BTreeSet::new(),
-			as_object: std::iter::once(i).collect(),
-			as_graph: BTreeSet::new(),
-		}
-	}
-
-	pub fn graph(value: R, i: usize) -> Self {
+	pub fn new(value: R) -> Self {
 		Self {
 			value,
 			as_subject: BTreeSet::new(),
 			as_predicate: BTreeSet::new(),
 			as_object: BTreeSet::new(),
-			as_graph: std::iter::once(i).collect(),
+			as_graph: BTreeSet::new(),
 		}
 	}
 
@@ -1196,6 +1942,15 @@ impl<R: RdfDisplay> RdfDisplay for IndexedBTreeDataset<R> {
 
 		Ok(())
 	}
+
+	fn rdf_write<W: std::io::Write + ?Sized>(&self, writer: &mut W) -> std::io::Result<()> {
+		for t in self {
+			t.rdf_write(writer)?;
+			writer.write_all(b" .\n")?;
+		}
+
+		Ok(())
+	}
 }
 
 #[cfg(feature = "serde")]
@@ -1250,7 +2005,137 @@ mod tests {
 
 	use crate::Quad;
 
-	use super::IndexedBTreeDataset;
+	use super::{IndexedBTreeDataset, QuadCursor};
+
+	#[test]
+	fn partition_by_predicate() {
+		let dataset: IndexedBTreeDataset<u32> = [
+			Quad(0, 1, 2, None),
+			Quad(0, 1, 2, Some(3)),
+			Quad(2, 4, 0, None),
+			Quad(5, 1, 6, None),
+		]
+		.into_iter()
+		.collect();
+
+		let mut partitions: Vec<_> = dataset
+			.partition_by_predicate()
+			.map(|(p, quads)| {
+				let mut quads: Vec<_> = quads.collect();
+				quads.sort_unstable();
+				(p, quads)
+			})
+			.collect();
+		partitions.sort_unstable();
+
+		assert_eq!(
+			partitions,
+			[
+				(&1, vec![(&0, &2, None), (&0, &2, Some(&3)), (&5, &6, None)]),
+				(&4, vec![(&2, &0, None)])
+			]
+		)
+	}
+
+	#[test]
+	fn map_graphs() {
+		let mut dataset: IndexedBTreeDataset<u32> = [
+			Quad(0, 1, 2, None),
+			Quad(0, 1, 2, Some(3)),
+			Quad(0, 1, 4, Some(3)),
+			Quad(0, 1, 2, Some(5)),
+			Quad(0, 1, 2, Some(6)),
+		]
+		.into_iter()
+		.collect();
+
+		dataset.map_graphs(|&g| match g {
+			3 => None,
+			5 => Some(6),
+			6 => Some(7),
+			g => Some(g),
+		});
+
+		assert_eq!(
+			dataset.iter().collect::<Vec<_>>(),
+			[
+				Quad(&0, &1, &2, None),
+				Quad(&0, &1, &2, Some(&6)),
+				Quad(&0, &1, &2, Some(&7)),
+				Quad(&0, &1, &4, None),
+			]
+		);
+		let mut named_graphs: Vec<_> = dataset.named_graphs().copied().collect();
+		named_graphs.sort_unstable();
+		assert_eq!(named_graphs, [6, 7]);
+		assert!(!dataset.contains_resource(&3));
+		assert!(!dataset.contains_resource(&5));
+	}
+
+	#[test]
+	fn map_graphs_swap() {
+		let mut dataset: IndexedBTreeDataset<u32> = [
+			Quad(0, 1, 2, Some(3)),
+			Quad(0, 1, 4, Some(3)),
+			Quad(0, 1, 2, Some(5)),
+		]
+		.into_iter()
+		.collect();
+
+		dataset.map_graphs(|&g| match g {
+			3 => Some(5),
+			5 => Some(3),
+			g => Some(g),
+		});
+
+		assert_eq!(
+			dataset.iter().collect::<Vec<_>>(),
+			[
+				Quad(&0, &1, &2, Some(&3)),
+				Quad(&0, &1, &2, Some(&5)),
+				Quad(&0, &1, &4, Some(&5)),
+			]
+		);
+
+		let graph = dataset.remove_graph(Some(&5)).unwrap();
+		assert_eq!(graph.len(), 2);
+		assert_eq!(
+			dataset.iter().collect::<Vec<_>>(),
+			[Quad(&0, &1, &2, Some(&3))]
+		);
+	}
+
+	#[test]
+	fn with_capacity() {
+		let mut dataset: IndexedBTreeDataset<u32> = IndexedBTreeDataset::with_capacity(16, 8);
+		assert!(dataset.quads.capacity() >= 16);
+		assert!(dataset.resources.capacity() >= 8);
+
+		assert!(dataset.insert(Quad(0, 1, 2, Some(3))));
+		assert!(dataset.contains(Quad(&0, &1, &2, Some(&3))));
+		assert_eq!(dataset.len(), 1)
+	}
+
+	#[test]
+	fn memory_footprint() {
+		let mut dataset: IndexedBTreeDataset<u32> = IndexedBTreeDataset::new();
+		assert_eq!(dataset.memory_footprint(), 0);
+
+		dataset.insert(Quad(0, 1, 2, Some(3)));
+		let small = dataset.memory_footprint();
+		assert!(small >= std::mem::size_of::<Quad<usize>>());
+
+		for i in 0..100 {
+			dataset.insert(Quad(i, 1, i + 1, None));
+		}
+
+		let large = dataset.memory_footprint();
+		assert!(large > small);
+		assert!(large >= dataset.quads.capacity() * std::mem::size_of::<Quad<usize>>());
+
+		let reserved = IndexedBTreeDataset::<u32>::with_capacity(64, 0);
+		assert!(reserved.memory_footprint() >= 64 * std::mem::size_of::<Quad<usize>>())
+	}
 
 	fn rng_graph(rng: &mut SmallRng) -> Option<u32> {
 		let g = rng.next_u32();
@@ -1345,4 +2230,692 @@ mod tests {
 			remove_test(i as usize * 11, [i; 32]);
 		}
 	}
+
+	/// Generates a quad over a small set of resources, so that the same
+	/// resource often appears at different positions of the quad.
+	fn small_quad(rng: &mut SmallRng) -> Quad<u32> {
+		let s = rng.next_u32() % 4;
+		let p = rng.next_u32() % 4;
+		let o = rng.next_u32() % 4;
+		let g = rng.next_u32() % 5;
+		Quad(s, p, o, g.checked_sub(1))
+	}
+
+	fn pattern_matching_test(n: usize, seed: [u8; 32]) {
+		use crate::{
+			dataset::PatternMatchingDataset,
+			pattern::{CanonicalQuadPattern, ResourceOrVar},
+		};
+		use rand::prelude::SliceRandom;
+
+		let mut rng = SmallRng::from_seed(seed);
+		let mut quads = vec![Quad(0, 1, 0, None), Quad(2, 2, 2, Some(2))];
+		quads.extend(std::iter::repeat_with(|| small_quad(&mut rng)).take(n));
+		quads.sort_unstable();
+		quads.dedup();
+
+		let mut dataset = IndexedBTreeDataset::new();
+		for &q in &quads {
+			dataset.insert(q);
+		}
+
+		// Leave some vacant slots in the dataset.
+		quads.shuffle(&mut rng);
+		for _ in 0..(quads.len() / 4) {
+			let q = quads.pop().unwrap();
+			dataset.remove(q.as_ref());
+		}
+
+		quads.sort_unstable();
+
+		let resources = [0u32, 1, 2, 3];
+		let terms = || {
+			resources
+				.iter()
+				.map(ResourceOrVar::Resource)
+				.chain((0..4usize).map(ResourceOrVar::Var))
+		};
+
+		for s in terms() {
+			for p in terms() {
+				for o in terms() {
+					for g in std::iter::once(None).chain(terms().map(Some)) {
+						let pattern = CanonicalQuadPattern::from_pattern(Quad(s, p, o, g));
+
+						let expected: Vec<_> = quads
+							.iter()
+							.filter(|&&q| pattern.matches(q.as_ref().as_ref()))
+							.copied()
+							.collect();
+
+						let mut found: Vec<_> = dataset
+							.pattern_matching(pattern)
+							.map(Quad::into_copied)
+							.collect();
+						found.sort_unstable();
+						assert_eq!(found, expected, "pattern matching {pattern:?}");
+
+						let mut paginated = Vec::new();
+						let mut cursor = QuadCursor::default();
+						loop {
+							let mut page = dataset.pattern_matching_from(pattern, cursor);
+							let len = paginated.len();
+							paginated.extend(page.by_ref().take(2).map(Quad::into_copied));
+							if paginated.len() == len {
+								break;
+							}
+
+							cursor = page.cursor();
+						}
+						paginated.sort_unstable();
+						assert_eq!(paginated, expected, "paginated matching {pattern:?}");
+
+						let mut extracted_dataset = dataset.clone();
+						let mut extracted: Vec<_> = extracted_dataset
+							.extract_pattern_matching(pattern)
+							.collect();
+						extracted.sort_unstable();
+						assert_eq!(extracted, expected, "extracting {pattern:?}");
+						assert_eq!(extracted_dataset.len(), quads.len() - expected.len());
+
+						let not_matching: Vec<_> = quads
+							.iter()
+							.filter(|&&q| !pattern.matches(q.as_ref().as_ref()))
+							.copied()
+							.collect();
+
+						let found: Vec<_> = dataset
+							.quads_not_matching(pattern)
+							.map(Quad::into_copied)
+							.collect();
+						assert_eq!(found, not_matching, "not matching {pattern:?}");
+
+						let found: Vec<_> =
+							PatternMatchingDataset::quads_not_matching(&dataset, pattern)
+								.map(Quad::into_copied)
+								.collect();
+						assert_eq!(found, not_matching, "generic not matching {pattern:?}");
+					}
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn statistics() {
+		use rand::prelude::SliceRandom;
+		use std::collections::{BTreeMap, BTreeSet};
+
+		for i in 0u8..8 {
+			let mut rng = SmallRng::from_seed([i; 32]);
+			let mut quads: Vec<_> = std::iter::repeat_with(|| small_quad(&mut rng))
+				.take(i as usize * 5)
+				.collect();
+			quads.sort_unstable();
+			quads.dedup();
+
+			let mut dataset = IndexedBTreeDataset::new();
+			for &q in &quads {
+				dataset.insert(q);
+			}
+
+			quads.shuffle(&mut rng);
+			for _ in 0..(quads.len() / 2) {
+				let q = quads.pop().unwrap();
+				dataset.remove(q.as_ref());
+			}
+
+			let mut fan_out: BTreeMap<u32, usize> = BTreeMap::new();
+			for q in &quads {
+				*fan_out.entry(q.1).or_default() += 1
+			}
+
+			let stats = dataset.statistics();
+			assert_eq!(stats.quad_count, quads.len());
+			assert_eq!(
+				stats.subject_count,
+				quads.iter().map(|q| q.0).collect::<BTreeSet<_>>().len()
+			);
+			assert_eq!(stats.predicate_count, fan_out.len());
+			assert_eq!(
+				stats.object_count,
+				quads.iter().map(|q| q.2).collect::<BTreeSet<_>>().len()
+			);
+			assert_eq!(
+				stats.named_graph_count,
+				quads
+					.iter()
+					.filter_map(|q| q.3)
+					.collect::<BTreeSet<_>>()
+					.len()
+			);
+			assert_eq!(
+				stats.max_predicate_fan_out,
+				fan_out.values().copied().max().unwrap_or(0)
+			);
+		}
+	}
+
+	#[test]
+	fn pattern_matching() {
+		for i in 0u8..8 {
+			pattern_matching_test(i as usize * 5, [i; 32]);
+		}
+	}
+
+	#[test]
+	fn quads_in_subject_range() {
+		use crate::{Id, Term};
+
+		let term =
+			|iri: &str| -> Term { Term::Id(Id::Iri(iref::IriBuf::new(iri.to_owned()).unwrap())) };
+
+		let subjects = [
+			"http://example.org/n",
+			"http://example.org/ns/a",
+			"http://example.org/ns/b",
+			"http://example.org/ns/b/c",
+			"http://example.org/nt",
+			"http://example.com/ns/a",
+		];
+
+		let p = term("http://example.org/p");
+		let dataset: IndexedBTreeDataset = subjects
+			.iter()
+			.flat_map(|s| {
+				[
+					Quad(term(s), p.clone(), term("http://example.org/o1"), None),
+					Quad(term(s), p.clone(), term("http://example.org/o2"), None),
+				]
+			})
+			.collect();
+
+		let start = term("http://example.org/ns/");
+		let end = term("http://example.org/ns0");
+		let found: Vec<_> = dataset
+			.quads_in_subject_range(&start, &end)
+			.map(Quad::into_cloned)
+			.collect();
+
+		let mut expected: Vec<_> = dataset
+			.iter()
+			.filter(|q| {
+				q.0.as_iri()
+					.is_some_and(|iri| iri.as_str().starts_with("http://example.org/ns/"))
+			})
+			.map(Quad::into_cloned)
+			.collect();
+		expected.sort_unstable();
+
+		assert_eq!(found.len(), 6);
+		assert_eq!(found, expected);
+		assert_eq!(dataset.quads_in_subject_range(&end, &start).count(), 0);
+
+		// Large enough for the range to span several B-tree nodes.
+		let dataset: IndexedBTreeDataset<u32> =
+			(0..500).map(|i| Quad(i % 100, 1000, i, None)).collect();
+		let found: Vec<_> = dataset
+			.quads_in_subject_range(&17, &63)
+			.map(Quad::into_copied)
+			.collect();
+		let expected: Vec<_> = dataset
+			.iter()
+			.map(Quad::into_copied)
+			.filter(|q| (17..63).contains(&q.0))
+			.collect();
+		assert_eq!(found.len(), 46 * 5);
+		assert_eq!(found, expected)
+	}
+
+	#[test]
+	fn lang_objects() {
+		use crate::{Id, Literal, LiteralType, Term};
+		use langtag::LangTagBuf;
+		use static_iref::iri;
+
+		let lang_string = |value: &str, tag: &str| {
+			Term::Literal(Literal::new(
+				value.to_owned(),
+				LiteralType::LangString(LangTagBuf::new(tag.to_owned()).unwrap()),
+			))
+		};
+
+		let subject = Term::Id(Id::Iri(iri!("http://example.org/#subject").to_owned()));
+		let label = Term::Id(Id::Iri(iri!("http://example.org/#label").to_owned()));
+		let other = Term::Id(Id::Iri(iri!("http://example.org/#other").to_owned()));
+
+		let dataset: IndexedBTreeDataset = [
+			Quad(
+				subject.clone(),
+				label.clone(),
+				lang_string("chat", "fr"),
+				None,
+			),
+			Quad(
+				subject.clone(),
+				label.clone(),
+				lang_string("cat", "en"),
+				None,
+			),
+			Quad(
+				subject.clone(),
+				label.clone(),
+				lang_string("matou", "fr"),
+				None,
+			),
+			Quad(subject.clone(), label.clone(), other.clone(), None),
+			Quad(
+				subject.clone(),
+				other.clone(),
+				lang_string("ignored", "en"),
+				None,
+			),
+			Quad(
+				subject.clone(),
+				label.clone(),
+				lang_string("ignored", "de"),
+				Some(other.clone()),
+			),
+		]
+		.into_iter()
+		.collect();
+
+		let mut groups: Vec<(String, Vec<&str>)> = dataset
+			.lang_objects(None, &subject, &label)
+			.map(|(tag, literals)| {
+				let mut values: Vec<_> = literals.iter().map(|l| l.as_str()).collect();
+				values.sort_unstable();
+				(tag.as_str().to_owned(), values)
+			})
+			.collect();
+		groups.sort_unstable();
+
+		assert_eq!(
+			groups,
+			[
+				("en".to_owned(), vec!["cat"]),
+				("fr".to_owned(), vec!["chat", "matou"])
+			]
+		)
+	}
+
+	#[test]
+	fn append() {
+		for i in 0u8..8 {
+			let mut rng = SmallRng::from_seed([i; 32]);
+			let a: Vec<_> = std::iter::repeat_with(|| small_quad(&mut rng))
+				.take(i as usize * 3)
+				.collect();
+			let b: Vec<_> = std::iter::repeat_with(|| small_quad(&mut rng))
+				.take(i as usize * 5)
+				.collect();
+
+			let expected: IndexedBTreeDataset<u32> = a.iter().chain(&b).copied().collect();
+
+			let mut dataset: IndexedBTreeDataset<u32> = a.iter().copied().collect();
+			dataset.append(b.iter().copied().collect());
+			assert_eq!(dataset, expected);
+
+			let mut dataset: IndexedBTreeDataset<u32> = b.iter().copied().collect();
+			dataset.append(a.iter().copied().collect());
+			assert_eq!(dataset, expected);
+		}
+	}
+
+	#[test]
+	fn quad_pattern_matching_bindings() {
+		use crate::{dataset::PatternMatchingDataset, pattern::ResourceOrVar};
+		use std::collections::HashMap;
+
+		let dataset: IndexedBTreeDataset<u32> = [
+			Quad(1, 2, 1, None),
+			Quad(1, 2, 3, Some(4)),
+			Quad(3, 2, 3, Some(4)),
+			Quad(5, 6, 5, Some(7)),
+		]
+		.into_iter()
+		.collect();
+
+		let mut results: Vec<_> = dataset
+			.quad_pattern_matching_bindings(Quad(
+				ResourceOrVar::Var("x"),
+				ResourceOrVar::Resource(&2),
+				ResourceOrVar::Var("x"),
+				Some(ResourceOrVar::Var("g")),
+			))
+			.collect();
+		results.sort_by_key(|(quad, _)| *quad);
+
+		assert_eq!(
+			results,
+			[
+				(Quad(&1, &2, &1, None), HashMap::from([("x", &1)])),
+				(
+					Quad(&3, &2, &3, Some(&4)),
+					HashMap::from([("x", &3), ("g", &4)])
+				)
+			]
+		)
+	}
+
+	#[test]
+	fn iter_sorted_by_key() {
+		let dataset: IndexedBTreeDataset<u32> = [
+			Quad(3, 1, 2, Some(1)),
+			Quad(2, 2, 2, None),
+			Quad(1, 2, 3, Some(1)),
+			Quad(2, 1, 1, None),
+		]
+		.into_iter()
+		.collect();
+
+		let quads: Vec<_> = dataset.iter_sorted_by_key(|r| *r).collect();
+		assert_eq!(
+			quads,
+			[
+				Quad(&2, &1, &1, None),
+				Quad(&2, &2, &2, None),
+				Quad(&1, &2, &3, Some(&1)),
+				Quad(&3, &1, &2, Some(&1))
+			]
+		);
+
+		let quads: Vec<_> = dataset
+			.iter_sorted_by_key(|r| std::cmp::Reverse(*r))
+			.map(Quad::into_subject)
+			.collect();
+		assert_eq!(quads, [&2, &2, &3, &1])
+	}
+
+	#[test]
+	fn predicate_count_of_subject() {
+		use super::PatternMatching;
+		use crate::{
+			dataset::{Dataset, PatternMatchingDataset},
+			pattern::CanonicalQuadPattern,
+		};
+
+		/// Only forwards pattern matching, so that the provided methods of
+		/// `PatternMatchingDataset` are used.
+		struct ProvidedMethods(IndexedBTreeDataset<u32>);
+
+		impl Dataset for ProvidedMethods {
+			type Resource = u32;
+		}
+
+		impl PatternMatchingDataset for ProvidedMethods {
+			type QuadPatternMatching<'a, 'p> = PatternMatching<'a, u32>;
+
+			fn quad_pattern_matching<'p>(
+				&self,
+				pattern: CanonicalQuadPattern<&'p u32>,
+			) -> Self::QuadPatternMatching<'_, 'p> {
+				self.0.pattern_matching(pattern)
+			}
+		}
+
+		for i in 0u8..8 {
+			let mut rng = SmallRng::from_seed([i; 32]);
+			let quads: Vec<_> = std::iter::repeat_with(|| small_quad(&mut rng))
+				.take(i as usize * 10)
+				.collect();
+			let dataset: IndexedBTreeDataset<u32> = quads.iter().copied().collect();
+			let reference = ProvidedMethods(dataset.clone());
+
+			for graph in [None, Some(&0), Some(&1)] {
+				for subject in 0..8 {
+					let expected = reference.predicate_count_of_subject(graph, &subject);
+					assert_eq!(
+						dataset.predicate_count_of_subject(graph, &subject),
+						expected
+					)
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn first_last_nth_quad() {
+		let mut rng = SmallRng::from_seed([1; 32]);
+		let dataset: IndexedBTreeDataset<u32> = std::iter::repeat_with(|| small_quad(&mut rng))
+			.take(32)
+			.collect();
+
+		let quads: Vec<_> = dataset.iter().collect();
+		assert_eq!(dataset.first_quad(), quads.first().copied());
+		assert_eq!(dataset.last_quad(), quads.last().copied());
+		for (n, quad) in quads.iter().enumerate() {
+			assert_eq!(dataset.nth_quad(n), Some(*quad))
+		}
+		assert_eq!(dataset.nth_quad(quads.len()), None);
+
+		let empty = IndexedBTreeDataset::<u32>::new();
+		assert_eq!(empty.first_quad(), None);
+		assert_eq!(empty.last_quad(), None)
+	}
+
+	#[test]
+	fn collect_rdf_list() {
+		use crate::{
+			dataset::{ListError, PatternMatchingDataset},
+			BlankIdBuf, Term, RDF_FIRST, RDF_NIL, RDF_REST,
+		};
+		use iref::IriBuf;
+
+		let blank = |b: &str| Term::blank(BlankIdBuf::from_suffix(b).unwrap());
+		let first = Term::iri(RDF_FIRST.to_owned());
+		let rest = Term::iri(RDF_REST.to_owned());
+		let nil = Term::iri(RDF_NIL.to_owned());
+		let item = |i: u32| Term::iri(IriBuf::new(format!("http://example.org/{i}")).unwrap());
+
+		let mut dataset: IndexedBTreeDataset = [
+			Quad(blank("l0"), first.clone(), item(0), None),
+			Quad(blank("l0"), rest.clone(), blank("l1"), None),
+			Quad(blank("l1"), first.clone(), item(1), None),
+			Quad(blank("l1"), rest.clone(), nil.clone(), None),
+			Quad(blank("c"), first.clone(), item(2), None),
+			Quad(blank("c"), rest.clone(), blank("c"), None),
+			Quad(blank("m"), rest.clone(), nil.clone(), None),
+		]
+		.into_iter()
+		.collect();
+
+		assert_eq!(
+			dataset.collect_rdf_list(None, &blank("l0")).unwrap(),
+			[&item(0), &item(1)]
+		);
+		assert!(dataset.collect_rdf_list(None, &nil).unwrap().is_empty());
+		assert!(dataset
+			.collect_rdf_list(Some(&item(0)), &blank("l0"))
+			.is_err());
+		assert!(matches!(
+			dataset.collect_rdf_list(None, &blank("c")),
+			Err(ListError::Cycle(_))
+		));
+		assert!(matches!(
+			dataset.collect_rdf_list(None, &blank("m")),
+			Err(ListError::MissingFirst(_))
+		));
+
+		dataset.insert(Quad(blank("l1"), rest, blank("l0"), None));
+		assert!(matches!(
+			dataset.collect_rdf_list(None, &blank("l0")),
+			Err(ListError::MultipleRests(_))
+		));
+	}
+
+	#[test]
+	fn concise_bounded_description() {
+		use crate::{dataset::PatternMatchingDataset, BlankIdBuf, Term, Triple};
+		use iref::IriBuf;
+
+		let blank = |b: &str| Term::blank(BlankIdBuf::from_suffix(b).unwrap());
+		let iri = |i: &str| Term::iri(IriBuf::new(format!("http://example.org/{i}")).unwrap());
+
+		let dataset: IndexedBTreeDataset = [
+			Quad(iri("s"), iri("p"), blank("a"), None),
+			Quad(iri("s"), iri("p"), iri("o"), None),
+			Quad(blank("a"), iri("p"), blank("b"), None),
+			Quad(blank("b"), iri("p"), blank("a"), None),
+			Quad(blank("b"), iri("p"), blank("b"), None),
+			Quad(iri("o"), iri("p"), iri("ignored"), None),
+			Quad(blank("c"), iri("p"), iri("ignored"), None),
+			Quad(iri("s"), iri("p"), iri("ignored"), Some(iri("g"))),
+		]
+		.into_iter()
+		.collect();
+
+		let description = dataset.concise_bounded_description(None, &iri("s"));
+		assert_eq!(description.len(), 5);
+		for (s, o) in [
+			(iri("s"), blank("a")),
+			(iri("s"), iri("o")),
+			(blank("a"), blank("b")),
+			(blank("b"), blank("a")),
+			(blank("b"), blank("b")),
+		] {
+			assert!(description.contains(Triple(&s, &iri("p"), &o)))
+		}
+
+		let description = dataset.concise_bounded_description(Some(&iri("g")), &iri("s"));
+		assert_eq!(description.len(), 1)
+	}
+
+	#[test]
+	fn object_frequencies() {
+		use crate::{
+			dataset::{IndexedBTreeGraph, PatternMatchingDataset},
+			Term, Triple, RDF_TYPE,
+		};
+		use iref::IriBuf;
+
+		let iri = |i: &str| Term::iri(IriBuf::new(format!("http://example.org/{i}")).unwrap());
+
+		let rdf_type = Term::iri(RDF_TYPE.to_owned());
+
+		let triples = [
+			Triple(iri("a"), rdf_type.clone(), iri("A")),
+			Triple(iri("b"), rdf_type.clone(), iri("A")),
+			Triple(iri("b"), rdf_type.clone(), iri("B")),
+			Triple(iri("c"), rdf_type.clone(), iri("A")),
+			Triple(iri("c"), iri("p"), iri("B")),
+		];
+
+		let mut dataset: IndexedBTreeDataset = triples
+			.iter()
+			.cloned()
+			.map(Triple::in_default_graph)
+			.collect();
+		dataset.insert(Quad(iri("d"), rdf_type.clone(), iri("B"), Some(iri("g"))));
+
+		let (a, b) = (iri("A"), iri("B"));
+		let expected = vec![(&a, 3), (&b, 1)];
+		assert_eq!(
+			dataset
+				.object_frequencies(None, &rdf_type)
+				.collect::<Vec<_>>(),
+			expected
+		);
+		assert_eq!(
+			dataset
+				.object_frequencies(Some(&iri("g")), &rdf_type)
+				.collect::<Vec<_>>(),
+			vec![(&b, 1)]
+		);
+		assert_eq!(dataset.object_frequencies(None, &iri("q")).count(), 0);
+
+		let graph: IndexedBTreeGraph = triples.into_iter().collect();
+		assert_eq!(
+			graph
+				.object_frequencies(None, &rdf_type)
+				.collect::<Vec<_>>(),
+			expected
+		)
+	}
+
+	#[test]
+	fn graph_metadata() {
+		use crate::{dataset::PatternMatchingDataset, Term};
+		use iref::IriBuf;
+
+		let iri = |i: &str| Term::iri(IriBuf::new(format!("http://example.org/{i}")).unwrap());
+
+		let dataset: IndexedBTreeDataset = [
+			Quad(iri("g"), iri("generatedBy"), iri("agent"), None),
+			Quad(iri("g"), iri("source"), iri("doc"), None),
+			Quad(iri("g"), iri("p"), iri("o"), Some(iri("g"))),
+			Quad(iri("s"), iri("p"), iri("o"), Some(iri("g"))),
+		]
+		.into_iter()
+		.collect();
+
+		let metadata: Vec<_> = dataset.graph_metadata(&iri("g")).collect();
+		assert_eq!(
+			metadata,
+			[
+				(&iri("generatedBy"), &iri("agent")),
+				(&iri("source"), &iri("doc"))
+			]
+		);
+		assert_eq!(dataset.graph_metadata(&iri("s")).count(), 0)
+	}
+
+	#[test]
+	fn to_indexed() {
+		use crate::{
+			dataset::{BTreeGraph, TraversableDataset},
+			Triple,
+		};
+
+		let graph: BTreeGraph<u32> = [Triple(0, 1, 2), Triple(2, 1, 0)].into_iter().collect();
+		let dataset = graph.to_indexed();
+		assert_eq!(dataset.len(), 2);
+		assert!(dataset.contains(Quad(&0, &1, &2, None)));
+		assert!(dataset.contains(Quad(&2, &1, &0, None)))
+	}
+
+	#[cfg(feature = "rayon")]
+	#[test]
+	fn par_from_iter() {
+		use crate::pattern::{CanonicalQuadPattern, ResourceOrVar};
+
+		for i in 0u8..8 {
+			let mut rng = SmallRng::from_seed([i; 32]);
+			let quads: Vec<_> = std::iter::repeat_with(|| {
+				let Quad(s, p, o, g) = small_quad(&mut rng);
+				Quad(s + rng.next_u32() % 16 * 4, p, o, g)
+			})
+			.take(i as usize * 1000)
+			.collect();
+
+			let expected: IndexedBTreeDataset<u32> = quads.iter().copied().collect();
+
+			let (left, right) = quads.split_at(quads.len() / 3);
+			let left: IndexedBTreeDataset<u32> = left.iter().copied().collect();
+			let right: IndexedBTreeDataset<u32> = right.iter().copied().collect();
+			assert_eq!(left.merge(right), expected);
+
+			let pool = rayon::ThreadPoolBuilder::new()
+				.num_threads(4)
+				.build()
+				.unwrap();
+			let dataset = pool.install(|| IndexedBTreeDataset::par_from_iter(quads));
+			assert_eq!(dataset, expected);
+			assert_eq!(dataset.statistics(), expected.statistics());
+
+			for s in 0..64 {
+				let pattern = CanonicalQuadPattern::from_pattern(Quad(
+					ResourceOrVar::Resource(&s),
+					ResourceOrVar::Var(0),
+					ResourceOrVar::Var(1),
+					Some(ResourceOrVar::Var(2)),
+				));
+				let mut found: Vec<_> = dataset.pattern_matching(pattern).collect();
+				let mut expected: Vec<_> = expected.pattern_matching(pattern).collect();
+				found.sort_unstable();
+				expected.sort_unstable();
+				assert_eq!(found, expected)
+			}
+		}
+	}
 }
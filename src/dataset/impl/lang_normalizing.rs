@@ -0,0 +1,121 @@
+use std::borrow::Cow;
+
+use langtag::LangTagBuf;
+
+use crate::{
+	dataset::{Dataset, DatasetMut, TraversableDataset},
+	Literal, LiteralType, Quad, Term,
+};
+
+/// Dataset wrapper normalizing language tags.
+///
+/// Language tags are case-insensitive, so `"x"@EN` and `"x"@en` denote the
+/// same literal. This wrapper lowercases the language tag of every language
+/// string of the quads inserted in (or removed from) the inner dataset, so
+/// that such literals are not stored twice.
+#[derive(Debug, Default, Clone)]
+pub struct LangNormalizing<D>(pub D);
+
+impl<D> LangNormalizing<D> {
+	/// Wraps the given dataset.
+	pub fn new(dataset: D) -> Self {
+		Self(dataset)
+	}
+
+	/// Returns a reference to the inner dataset.
+	pub fn inner(&self) -> &D {
+		&self.0
+	}
+
+	/// Returns the inner dataset.
+	pub fn into_inner(self) -> D {
+		self.0
+	}
+}
+
+impl<D: Dataset> Dataset for LangNormalizing<D> {
+	type Resource = D::Resource;
+}
+
+impl<D: TraversableDataset> TraversableDataset for LangNormalizing<D> {
+	type Quads<'a> = D::Quads<'a> where Self: 'a;
+
+	fn quads(&self) -> Self::Quads<'_> {
+		self.0.quads()
+	}
+
+	fn quads_count(&self) -> usize {
+		self.0.quads_count()
+	}
+}
+
+impl<I: Clone, T: Clone, D> DatasetMut for LangNormalizing<D>
+where
+	D: DatasetMut<Resource = Term<I, Literal<T>>>,
+{
+	fn insert(&mut self, quad: Quad<Self::Resource>) {
+		self.0
+			.insert(quad.map(|t| normalized(Cow::Owned(t)).into_owned()))
+	}
+
+	fn remove(&mut self, quad: Quad<&Self::Resource>) -> bool {
+		let quad = quad.map(|t| normalized(Cow::Borrowed(t)));
+		self.0.remove(quad.as_ref().map(|t| &**t))
+	}
+}
+
+/// Lowercases the language tag of the given term, if it is a language
+/// string.
+fn normalized<I: Clone, T: Clone>(term: Cow<Term<I, Literal<T>>>) -> Cow<Term<I, Literal<T>>> {
+	match term.as_literal().and_then(Literal::lang_tag) {
+		Some(tag) if tag.as_str().bytes().any(|c| c.is_ascii_uppercase()) => {
+			let tag = LangTagBuf::new(tag.as_str().to_ascii_lowercase()).unwrap();
+			let mut term = term.into_owned();
+			if let Term::Literal(literal) = &mut term {
+				let type_ = match literal.direction() {
+					Some(direction) => LiteralType::DirLangString(tag, direction),
+					None => LiteralType::LangString(tag),
+				};
+
+				*literal.as_type_mut() = type_
+			}
+
+			Cow::Owned(term)
+		}
+		_ => term,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::LangNormalizing;
+	use crate::{
+		dataset::{BTreeDataset, DatasetMut, TraversableDataset},
+		Literal, LiteralType, Quad, Term,
+	};
+	use static_iref::iri;
+
+	#[test]
+	fn insert() {
+		let iri: Term = Term::iri(iri!("http://example.org/").to_owned());
+		let lang_string = |tag: &str| -> Term {
+			Term::Literal(Literal::new(
+				"x",
+				LiteralType::LangString(tag.parse().unwrap()),
+			))
+		};
+
+		let mut dataset = LangNormalizing::new(BTreeDataset::new());
+		dataset.insert(Quad(iri.clone(), iri.clone(), lang_string("EN"), None));
+		dataset.insert(Quad(iri.clone(), iri.clone(), lang_string("en"), None));
+		dataset.insert(Quad(iri.clone(), iri.clone(), lang_string("en-US"), None));
+		assert_eq!(dataset.quads_count(), 2);
+
+		let object = lang_string("en");
+		assert!(dataset.inner().contains(Quad(&iri, &iri, &object, None)));
+
+		assert!(dataset.remove(Quad(&iri, &iri, &lang_string("En-us"), None)));
+		assert!(!dataset.remove(Quad(&iri, &iri, &lang_string("en-US"), None)));
+		assert_eq!(dataset.quads_count(), 1)
+	}
+}
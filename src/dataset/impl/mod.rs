@@ -1,5 +1,9 @@
 pub mod btree_dataset;
+mod builder;
 pub mod indexed_btree_dataset;
+mod lang_normalizing;
 
 pub use btree_dataset::BTreeDataset;
+pub use builder::DatasetBuilder;
 pub use indexed_btree_dataset::IndexedBTreeDataset;
+pub use lang_normalizing::LangNormalizing;
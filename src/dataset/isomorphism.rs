@@ -1,13 +1,31 @@
-use std::collections::{btree_map::Entry, BTreeMap, BTreeSet};
+use std::{
+	collections::{btree_map::Entry, BTreeMap, BTreeSet},
+	hash::{Hash, Hasher},
+};
 
 use educe::Educe;
 
 use crate::{
-	dataset::TraversableDataset,
+	dataset::{ResourceTraversableDataset, TraversableDataset},
 	interpretation::{ReverseIriInterpretation, ReverseLiteralInterpretation},
 	Quad, Term,
 };
 
+/// Checks that the datasets `a` and `b` are equal up to blank node renaming.
+///
+/// The isomorphism check is only run if one of the datasets contains blank
+/// nodes. Otherwise the datasets are simply compared with `==`.
+pub fn are_equivalent<D>(a: &D, b: &D) -> bool
+where
+	D: TraversableDataset<Resource = Term> + ResourceTraversableDataset + PartialEq,
+{
+	if a.resources().any(Term::is_blank) || b.resources().any(Term::is_blank) {
+		are_isomorphic(a, b)
+	} else {
+		a == b
+	}
+}
+
 /// Checks that there is an isomorphism between the datasets `a` and `b`.
 ///
 /// There is an isomorphism if there exists a blank node identifier bijection
@@ -131,6 +149,157 @@ where
 	)
 }
 
+/// Explains why the datasets `a` and `b` are, or are not, isomorphic.
+///
+/// See [`IsomorphismReport`].
+pub fn diff_isomorphism<'a, 'b, A, B>(a: &'a A, b: &'b B) -> IsomorphismReport<'a, 'b>
+where
+	A: TraversableDataset<Resource = Term>,
+	B: TraversableDataset<Resource = Term>,
+{
+	diff_isomorphism_with(&(), a, b)
+}
+
+/// Explains why the datasets `a` and `b` are, or are not, isomorphic.
+///
+/// Blank resources are partitioned using the same color refinement as
+/// [`dataset_signature_with`]. Each dataset is colored separately, but both
+/// colorings are refined in lockstep for the same number of rounds. Since
+/// colors are hashes of the neighborhood of each resource, this makes them
+/// comparable across the two datasets.
+///
+/// See [`IsomorphismReport`].
+pub fn diff_isomorphism_with<'a, 'b, I, A, B>(
+	interpretation: &I,
+	a: &'a A,
+	b: &'b B,
+) -> IsomorphismReport<'a, 'b, I::Resource>
+where
+	I: ReverseIriInterpretation + ReverseLiteralInterpretation,
+	I::Resource: Ord,
+	I::Iri: PartialEq + Hash,
+	I::Literal: PartialEq + Hash,
+	A: TraversableDataset<Resource = I::Resource>,
+	B: TraversableDataset<Resource = I::Resource>,
+{
+	let bijection = find_bijection_with(interpretation, a, b);
+
+	let mut a_coloring = Coloring::new(interpretation, a);
+	let mut b_coloring = Coloring::new(interpretation, b);
+
+	let mut distinct_colors = (1, 1);
+	for _ in 0..a_coloring.colors.len().max(b_coloring.colors.len()) {
+		let refined_distinct_colors = (a_coloring.refine(), b_coloring.refine());
+
+		if refined_distinct_colors == distinct_colors {
+			break;
+		}
+
+		distinct_colors = refined_distinct_colors
+	}
+
+	// Ground quads are bucketed by the hash of their resources, then matched
+	// one by one.
+	let mut b_ground: BTreeMap<_, Vec<_>> = BTreeMap::new();
+	for quad in b_coloring.quads.iter().filter(|q| b_coloring.is_ground(q)) {
+		b_ground
+			.entry(b_coloring.quad_colors(quad))
+			.or_default()
+			.push(*quad)
+	}
+
+	let mut a_only = Vec::new();
+	for quad in a_coloring.quads.iter().filter(|q| a_coloring.is_ground(q)) {
+		let candidates = b_ground.entry(a_coloring.quad_colors(quad)).or_default();
+		match candidates
+			.iter()
+			.position(|b_quad| quad_matches(interpretation, *quad, *b_quad))
+		{
+			Some(i) => {
+				candidates.swap_remove(i);
+			}
+			None => a_only.push(*quad),
+		}
+	}
+
+	let b_only = b_ground.into_values().flatten().collect();
+
+	let mut classes: BTreeMap<u64, BlankClass<I::Resource>> = BTreeMap::new();
+	for (&r, color) in &a_coloring.colors {
+		classes.entry(*color).or_default().a.push(r)
+	}
+
+	for (&r, color) in &b_coloring.colors {
+		classes.entry(*color).or_default().b.push(r)
+	}
+
+	IsomorphismReport {
+		bijection,
+		a_only,
+		b_only,
+		unmatched_blanks: classes
+			.into_values()
+			.filter(|class| class.a.len() != class.b.len())
+			.collect(),
+	}
+}
+
+/// Isomorphism diagnostic between two datasets `a` and `b`.
+///
+/// Built by [`diff_isomorphism`].
+///
+/// When the datasets are not isomorphic, the report lists the ground quads
+/// (without blank resources) that appear in only one of the datasets, and the
+/// classes of structurally similar blank resources that do not have the
+/// same size in both datasets.
+/// Both lists may be empty even if the datasets are not isomorphic, when
+/// the difference is not captured by the color refinement (for instance
+/// between two cycles of blank nodes with different lengths but the same
+/// labels).
+pub struct IsomorphismReport<'a, 'b, R = Term> {
+	/// Blank node identifier bijection, if the datasets are isomorphic.
+	pub bijection: Option<BTreeBijection<'a, 'b, R>>,
+
+	/// Ground quads of `a` not in `b`.
+	pub a_only: Vec<Quad<&'a R>>,
+
+	/// Ground quads of `b` not in `a`.
+	pub b_only: Vec<Quad<&'b R>>,
+
+	/// Classes of blank resources that could not be matched.
+	pub unmatched_blanks: Vec<BlankClass<'a, 'b, R>>,
+}
+
+impl<'a, 'b, R> IsomorphismReport<'a, 'b, R> {
+	/// Checks if the datasets are isomorphic.
+	pub fn is_isomorphic(&self) -> bool {
+		self.bijection.is_some()
+	}
+}
+
+/// Class of structurally similar blank resources in the datasets `a` and
+/// `b`.
+///
+/// Blank resources in the same class are used the same way, up to the
+/// color refinement precision. Only the classes with a different number of
+/// resources in `a` and `b` are reported by [`diff_isomorphism`].
+pub struct BlankClass<'a, 'b, R = Term> {
+	/// Blank resources of `a` in this class.
+	pub a: Vec<&'a R>,
+
+	/// Blank resources of `b` in this class.
+	pub b: Vec<&'b R>,
+}
+
+impl<'a, 'b, R> Default for BlankClass<'a, 'b, R> {
+	fn default() -> Self {
+		Self {
+			a: Vec::new(),
+			b: Vec::new(),
+		}
+	}
+}
+
 fn resource_matches<I>(interpretation: &I, a: &I::Resource, b: &I::Resource) -> bool
 where
 	I: ReverseIriInterpretation + ReverseLiteralInterpretation,
@@ -266,6 +435,241 @@ where
 	result
 }
 
+/// Computes a signature of the given dataset, invariant under blank node
+/// renaming and quad order.
+///
+/// Isomorphic datasets have the same signature. The converse is not true:
+/// equal signatures are necessary but not sufficient for datasets to be
+/// isomorphic. This makes it a cheap pre-filter to bucket datasets before
+/// running [`find_bijection`] within each bucket.
+///
+/// The signature is deterministic across runs and platforms.
+pub fn dataset_signature<D>(dataset: &D) -> u64
+where
+	D: TraversableDataset<Resource = Term>,
+{
+	dataset_signature_with(&(), dataset)
+}
+
+/// Computes a signature of the given dataset, invariant under blank node
+/// renaming and quad order.
+///
+/// See [`dataset_signature`].
+pub fn dataset_signature_with<I, D>(interpretation: &I, dataset: &D) -> u64
+where
+	I: ReverseIriInterpretation + ReverseLiteralInterpretation,
+	I::Resource: Ord,
+	I::Iri: Hash,
+	I::Literal: Hash,
+	D: TraversableDataset<Resource = I::Resource>,
+{
+	let mut coloring = Coloring::new(interpretation, dataset);
+
+	let mut distinct_colors = 1;
+	for _ in 0..coloring.colors.len() {
+		let refined_distinct_colors = coloring.refine();
+
+		if refined_distinct_colors == distinct_colors {
+			break;
+		}
+
+		distinct_colors = refined_distinct_colors
+	}
+
+	let mut hashes: Vec<_> = coloring
+		.quads
+		.iter()
+		.map(|quad| stable_hash(&coloring.quad_colors(quad)))
+		.collect();
+	hashes.sort_unstable();
+	stable_hash(&StableSlice(&hashes))
+}
+
+/// Color refinement of the blank resources of a dataset.
+///
+/// Ground resources are hashed once, blank resources get a color refined
+/// from the colors of their neighbors.
+struct Coloring<'d, R> {
+	quads: Vec<Quad<&'d R>>,
+	ground: BTreeMap<&'d R, Option<u64>>,
+	colors: BTreeMap<&'d R, u64>,
+}
+
+impl<'d, R: Ord> Coloring<'d, R> {
+	fn new<I, D>(interpretation: &I, dataset: &'d D) -> Self
+	where
+		I: ReverseIriInterpretation<Resource = R> + ReverseLiteralInterpretation,
+		I::Iri: Hash,
+		I::Literal: Hash,
+		D: TraversableDataset<Resource = R>,
+	{
+		let quads: Vec<_> = dataset.quads().collect();
+
+		let mut ground = BTreeMap::new();
+		let mut colors = BTreeMap::new();
+		for quad in &quads {
+			for r in [Some(quad.0), Some(quad.1), Some(quad.2), quad.3]
+				.into_iter()
+				.flatten()
+			{
+				let hash = ground
+					.entry(r)
+					.or_insert_with(|| ground_hash(interpretation, r));
+
+				if hash.is_none() {
+					colors.insert(r, 0u64);
+				}
+			}
+		}
+
+		Self {
+			quads,
+			ground,
+			colors,
+		}
+	}
+
+	fn color(&self, r: &R) -> u64 {
+		self.ground[r].unwrap_or_else(|| self.colors[r])
+	}
+
+	fn quad_colors(&self, quad: &Quad<&R>) -> [Option<u64>; 4] {
+		[
+			Some(self.color(quad.0)),
+			Some(self.color(quad.1)),
+			Some(self.color(quad.2)),
+			quad.3.map(|g| self.color(g)),
+		]
+	}
+
+	fn is_ground(&self, quad: &Quad<&R>) -> bool {
+		[Some(quad.0), Some(quad.1), Some(quad.2), quad.3]
+			.into_iter()
+			.flatten()
+			.all(|r| self.ground[r].is_some())
+	}
+
+	/// Refines the colors of the blank resources once, and returns the
+	/// number of distinct colors.
+	fn refine(&mut self) -> usize {
+		let mut neighbors: BTreeMap<&R, Vec<u64>> = BTreeMap::new();
+		for quad in &self.quads {
+			let quad_colors = self.quad_colors(quad);
+			for (i, r) in [Some(quad.0), Some(quad.1), Some(quad.2), quad.3]
+				.into_iter()
+				.enumerate()
+			{
+				if let Some(r) = r.filter(|r| self.ground[r].is_none()) {
+					neighbors
+						.entry(r)
+						.or_default()
+						.push(stable_hash(&(i, quad_colors)))
+				}
+			}
+		}
+
+		let refined: BTreeMap<_, _> = neighbors
+			.into_iter()
+			.map(|(r, mut hashes)| {
+				hashes.sort_unstable();
+				(r, stable_hash(&(1u8, self.colors[r], StableSlice(&hashes))))
+			})
+			.collect();
+
+		self.colors = refined;
+		self.colors.values().collect::<BTreeSet<_>>().len()
+	}
+}
+
+/// Hashes the lexical representations of a non-blank resource.
+fn ground_hash<I>(interpretation: &I, r: &I::Resource) -> Option<u64>
+where
+	I: ReverseIriInterpretation + ReverseLiteralInterpretation,
+	I::Iri: Hash,
+	I::Literal: Hash,
+{
+	let mut iris: Vec<_> = interpretation.iris_of(r).map(stable_hash).collect();
+	let mut literals: Vec<_> = interpretation.literals_of(r).map(stable_hash).collect();
+
+	if iris.is_empty() && literals.is_empty() {
+		None
+	} else {
+		iris.sort_unstable();
+		literals.sort_unstable();
+		Some(stable_hash(&(
+			0u8,
+			StableSlice(&iris),
+			StableSlice(&literals),
+		)))
+	}
+}
+
+fn stable_hash(value: &(impl ?Sized + Hash)) -> u64 {
+	let mut hasher = StableHasher::default();
+	value.hash(&mut hasher);
+	hasher.finish()
+}
+
+/// Slice of hashes, hashed element by element.
+///
+/// The standard `Hash` implementation of integer slices writes their raw
+/// memory at once, which depends on the platform endianness.
+struct StableSlice<'a>(&'a [u64]);
+
+impl<'a> Hash for StableSlice<'a> {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		state.write_usize(self.0.len());
+		for &h in self.0 {
+			state.write_u64(h)
+		}
+	}
+}
+
+/// FNV-1a hasher.
+///
+/// Integers are always written in little-endian over 64 bits (or more), so
+/// that hashes do not depend on the platform.
+struct StableHasher(u64);
+
+impl Default for StableHasher {
+	fn default() -> Self {
+		Self(0xcbf29ce484222325)
+	}
+}
+
+impl Hasher for StableHasher {
+	fn finish(&self) -> u64 {
+		self.0
+	}
+
+	fn write(&mut self, bytes: &[u8]) {
+		for &b in bytes {
+			self.0 ^= b as u64;
+			self.0 = self.0.wrapping_mul(0x100000001b3);
+		}
+	}
+
+	fn write_u16(&mut self, i: u16) {
+		self.write_u64(i as u64)
+	}
+
+	fn write_u32(&mut self, i: u32) {
+		self.write_u64(i as u64)
+	}
+
+	fn write_u64(&mut self, i: u64) {
+		self.write(&i.to_le_bytes())
+	}
+
+	fn write_u128(&mut self, i: u128) {
+		self.write(&i.to_le_bytes())
+	}
+
+	fn write_usize(&mut self, i: usize) {
+		self.write_u64(i as u64)
+	}
+}
+
 /// Blank node identifier bijection
 /// between two (isomorphic) datasets.
 #[derive(Educe)]
@@ -464,3 +868,91 @@ impl<'a, R> BlankSignature<'a, R> {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use iref::IriBuf;
+
+	use super::{dataset_signature, diff_isomorphism};
+	use crate::{dataset::BTreeDataset, BlankIdBuf, Quad, Term};
+
+	fn iri(suffix: &str) -> Term {
+		Term::iri(IriBuf::new(format!("http://example.org/{suffix}")).unwrap())
+	}
+
+	fn blank(id: &str) -> Term {
+		Term::blank(BlankIdBuf::new(format!("_:{id}")).unwrap())
+	}
+
+	#[test]
+	fn diff() {
+		let a: BTreeDataset = [
+			Quad(iri("a"), iri("p"), iri("b"), None),
+			Quad(iri("a"), iri("p"), blank("x"), None),
+			Quad(blank("x"), iri("q"), blank("y"), None),
+		]
+		.into_iter()
+		.collect();
+
+		let b: BTreeDataset = [
+			Quad(iri("a"), iri("p"), iri("b"), None),
+			Quad(iri("a"), iri("p"), blank("0"), None),
+			Quad(blank("0"), iri("q"), blank("1"), None),
+		]
+		.into_iter()
+		.collect();
+
+		let report = diff_isomorphism(&a, &b);
+		assert!(report.is_isomorphic());
+		assert!(report.a_only.is_empty());
+		assert!(report.b_only.is_empty());
+		assert!(report.unmatched_blanks.is_empty());
+
+		let c: BTreeDataset = [
+			Quad(iri("a"), iri("p"), iri("c"), None),
+			Quad(iri("a"), iri("p"), blank("0"), None),
+			Quad(blank("0"), iri("q"), blank("0"), None),
+		]
+		.into_iter()
+		.collect();
+
+		let report = diff_isomorphism(&a, &c);
+		assert!(!report.is_isomorphic());
+
+		let a_only: Vec<Quad<Term>> = report.a_only.iter().map(|q| q.cloned()).collect();
+		let expected: [Quad; 1] = [Quad(iri("a"), iri("p"), iri("b"), None)];
+		assert_eq!(a_only, expected);
+
+		let b_only: Vec<Quad<Term>> = report.b_only.iter().map(|q| q.cloned()).collect();
+		let expected: [Quad; 1] = [Quad(iri("a"), iri("p"), iri("c"), None)];
+		assert_eq!(b_only, expected);
+
+		let mut a_blanks: Vec<_> = report
+			.unmatched_blanks
+			.iter()
+			.flat_map(|class| class.a.iter().copied())
+			.collect();
+		a_blanks.sort_unstable();
+		assert_eq!(a_blanks, [&blank("x"), &blank("y")]);
+
+		let b_blanks: Vec<_> = report
+			.unmatched_blanks
+			.iter()
+			.flat_map(|class| class.b.iter().copied())
+			.collect();
+		assert_eq!(b_blanks, [&blank("0")])
+	}
+
+	#[test]
+	fn signature_golden_value() {
+		// The signature is deterministic across runs and platforms.
+		let dataset: BTreeDataset = [
+			Quad(iri("a"), iri("p"), blank("x"), None),
+			Quad(blank("x"), iri("q"), iri("b"), Some(iri("g"))),
+		]
+		.into_iter()
+		.collect();
+
+		assert_eq!(dataset_signature(&dataset), 0x3de267752eac39e0)
+	}
+}
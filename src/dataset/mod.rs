@@ -1,8 +1,13 @@
 //! Dataset traits and implementations.
+use std::{
+	collections::{btree_map, BTreeMap, BTreeSet, HashMap},
+	hash::Hash,
+};
+
 use crate::{
-	pattern::{quad::canonical::PatternGraph, CanonicalQuadPattern},
+	pattern::{quad::canonical::PatternGraph, CanonicalQuadPattern, QuadPattern},
 	utils::{OptionIterator, TripleToQuadIterator},
-	Quad,
+	Quad, Term, Triple, TryAsBlankId, RDF_FIRST, RDF_NIL, RDF_REST,
 };
 
 pub mod fallible;
@@ -16,6 +21,9 @@ pub use r#impl::*;
 
 pub mod isomorphism;
 
+mod serialize;
+pub use serialize::SerializeRdf;
+
 /// RDF dataset.
 pub trait Dataset {
 	/// Resource type.
@@ -39,6 +47,17 @@ pub trait TraversableDataset: Dataset {
 	fn quads_count(&self) -> usize {
 		self.quads().count()
 	}
+
+	/// Collects the quads of this dataset into an [`IndexedBTreeDataset`].
+	///
+	/// This can be used to materialize a view or adapter before running
+	/// many pattern matching queries on it.
+	fn to_indexed(&self) -> IndexedBTreeDataset<Self::Resource>
+	where
+		Self::Resource: Clone + Ord,
+	{
+		self.quads().map(|quad| quad.cloned()).collect()
+	}
 }
 
 impl<G: TraversableGraph> TraversableDataset for G {
@@ -188,6 +207,48 @@ pub trait PatternMatchingDataset: Dataset {
 		pattern: CanonicalQuadPattern<&'p Self::Resource>,
 	) -> Self::QuadPatternMatching<'_, 'p>;
 
+	/// Returns an iterator over all the quads of the dataset matching the given
+	/// pattern, along with the resources bound to each variable of the
+	/// pattern.
+	///
+	/// A variable in graph position also matches quads of the default graph,
+	/// in which case it is left unbound.
+	fn quad_pattern_matching_bindings<'p, V: Clone + Eq + Hash>(
+		&self,
+		pattern: QuadPattern<&'p Self::Resource, V>,
+	) -> QuadPatternMatchingBindings<'_, 'p, Self, V> {
+		let vars = [
+			pattern.0.as_ref().into_var().cloned(),
+			pattern.1.as_ref().into_var().cloned(),
+			pattern.2.as_ref().into_var().cloned(),
+			pattern
+				.3
+				.as_ref()
+				.and_then(|g| g.as_ref().into_var().cloned()),
+		];
+
+		QuadPatternMatchingBindings {
+			vars,
+			inner: self.quad_pattern_matching(CanonicalQuadPattern::from_pattern(pattern)),
+		}
+	}
+
+	/// Returns an iterator over all the quads of the dataset *not* matching
+	/// the given pattern.
+	fn quads_not_matching<'p>(
+		&self,
+		pattern: CanonicalQuadPattern<&'p Self::Resource>,
+	) -> QuadsNotMatching<'_, 'p, Self>
+	where
+		Self: TraversableDataset,
+		Self::Resource: PartialEq,
+	{
+		QuadsNotMatching {
+			pattern,
+			quads: self.quads(),
+		}
+	}
+
 	/// Checks if the dataset contains the given quad.
 	fn contains_quad(&self, quad: Quad<&Self::Resource>) -> bool {
 		self.quad_pattern_matching(quad.into()).next().is_some()
@@ -270,6 +331,69 @@ pub trait PatternMatchingDataset: Dataset {
 		}
 	}
 
+	/// Returns an iterator over the predicate/object pairs describing the
+	/// named graph `graph` itself, the quads `graph p o` of the default graph.
+	///
+	/// This is where graph-level metadata such as provenance information is
+	/// usually stated.
+	fn graph_metadata<'p>(&self, graph: &'p Self::Resource) -> GraphMetadata<'_, 'p, Self> {
+		GraphMetadata {
+			inner: self.quad_pattern_matching(CanonicalQuadPattern::from_option_quad(Quad(
+				Some(graph),
+				None,
+				None,
+				Some(None),
+			))),
+		}
+	}
+
+	/// Returns the number of distinct predicates `p` such that a quad
+	/// `subject p o graph` is present in the dataset, for any object `o`.
+	fn predicate_count_of_subject(
+		&self,
+		graph: Option<&Self::Resource>,
+		subject: &Self::Resource,
+	) -> usize
+	where
+		Self::Resource: Ord,
+	{
+		use crate::pattern::quad::canonical::{
+			GivenSubject, GivenSubjectAnyPredicate, GivenSubjectAnyPredicateAnyObject,
+		};
+		self.quad_pattern_matching(CanonicalQuadPattern::GivenSubject(
+			subject,
+			GivenSubject::AnyPredicate(GivenSubjectAnyPredicate::AnyObject(
+				GivenSubjectAnyPredicateAnyObject::GivenGraph(graph),
+			)),
+		))
+		.map(Quad::into_predicate)
+		.collect::<BTreeSet<_>>()
+		.len()
+	}
+
+	/// Returns each distinct object `o` of the quads `s predicate o graph`,
+	/// for any subject `s`, along with the number of such quads.
+	///
+	/// Objects are returned once each, in ascending order.
+	fn object_frequencies<'a>(
+		&'a self,
+		graph: Option<&Self::Resource>,
+		predicate: &Self::Resource,
+	) -> btree_map::IntoIter<&'a Self::Resource, usize>
+	where
+		Self::Resource: Ord,
+	{
+		let mut frequencies = BTreeMap::new();
+
+		for Quad(_, _, o, _) in self.quad_pattern_matching(CanonicalQuadPattern::from_option_quad(
+			Quad(None, Some(predicate), None, Some(graph)),
+		)) {
+			*frequencies.entry(o).or_insert(0) += 1
+		}
+
+		frequencies.into_iter()
+	}
+
 	/// Returns an iterator over all the objects `o` matching the quad `subject predicate o graph`.
 	fn quad_objects<'p>(
 		&self,
@@ -287,6 +411,152 @@ pub trait PatternMatchingDataset: Dataset {
 			))),
 		}
 	}
+
+	/// Collects the items of the list starting at `head` in the given graph,
+	/// using the given resources as `rdf:first`, `rdf:rest` and `rdf:nil`.
+	///
+	/// Fails if the list is cyclic or if a node of the list does not have
+	/// exactly one `first` and one `rest`.
+	fn collect_list<'a>(
+		&'a self,
+		graph: Option<&Self::Resource>,
+		head: &'a Self::Resource,
+		first: &Self::Resource,
+		rest: &Self::Resource,
+		nil: &Self::Resource,
+	) -> Result<Vec<&'a Self::Resource>, ListError<&'a Self::Resource>>
+	where
+		Self::Resource: Ord,
+	{
+		let mut items = Vec::new();
+		let mut visited = BTreeSet::new();
+		let mut node = head;
+
+		while node != nil {
+			if !visited.insert(node) {
+				return Err(ListError::Cycle(node));
+			}
+
+			let mut firsts = self.quad_objects(graph, node, first);
+			let item = firsts.next().ok_or(ListError::MissingFirst(node))?;
+			if firsts.next().is_some() {
+				return Err(ListError::MultipleFirsts(node));
+			}
+
+			let mut rests = self.quad_objects(graph, node, rest);
+			let next = rests.next().ok_or(ListError::MissingRest(node))?;
+			if rests.next().is_some() {
+				return Err(ListError::MultipleRests(node));
+			}
+
+			items.push(item);
+			node = next
+		}
+
+		Ok(items)
+	}
+
+	/// Collects the items of the RDF list starting at `head` in the given
+	/// graph.
+	///
+	/// See [`Self::collect_list`].
+	fn collect_rdf_list<'a>(
+		&'a self,
+		graph: Option<&Term>,
+		head: &'a Term,
+	) -> Result<Vec<&'a Term>, ListError<&'a Term>>
+	where
+		Self: Dataset<Resource = Term>,
+	{
+		self.collect_list(
+			graph,
+			head,
+			&Term::iri(RDF_FIRST.to_owned()),
+			&Term::iri(RDF_REST.to_owned()),
+			&Term::iri(RDF_NIL.to_owned()),
+		)
+	}
+
+	/// Returns the concise bounded description of `start` in the given graph.
+	///
+	/// This is the graph of all the triples whose subject is `start`, along
+	/// with the concise bounded description of every blank node object of
+	/// those triples, recursively. Each blank node is described at most once,
+	/// so cycles of blank nodes are supported. Reifications of the included
+	/// triples are not part of the description.
+	fn concise_bounded_description(
+		&self,
+		graph: Option<&Self::Resource>,
+		start: &Self::Resource,
+	) -> BTreeGraph<Self::Resource>
+	where
+		Self::Resource: Clone + Ord + TryAsBlankId,
+	{
+		let mut result = BTreeGraph::new();
+		let mut visited = BTreeSet::new();
+		let mut stack = vec![start];
+		visited.insert(start);
+
+		while let Some(subject) = stack.pop() {
+			for Quad(s, p, o, _) in
+				self.quad_pattern_matching(CanonicalQuadPattern::from_option_quad(Quad(
+					Some(subject),
+					None,
+					None,
+					Some(graph),
+				))) {
+				if o.is_blank() && visited.insert(o) {
+					stack.push(o)
+				}
+
+				result.insert(Triple(s.clone(), p.clone(), o.clone()));
+			}
+		}
+
+		result
+	}
+}
+
+/// Iterator over the predicate/object pairs describing a named graph.
+///
+/// See [`PatternMatchingDataset::graph_metadata`].
+pub struct GraphMetadata<'a, 'p, D: 'a + ?Sized + PatternMatchingDataset>
+where
+	D::Resource: 'p,
+{
+	inner: D::QuadPatternMatching<'a, 'p>,
+}
+
+impl<'a, 'p, D: 'a + ?Sized + PatternMatchingDataset> Iterator for GraphMetadata<'a, 'p, D>
+where
+	D::Resource: 'p,
+{
+	type Item = (&'a D::Resource, &'a D::Resource);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.inner.next().map(|Quad(_, p, o, _)| (p, o))
+	}
+}
+
+/// Malformed list error.
+///
+/// Returned by [`PatternMatchingDataset::collect_list`].
+#[derive(Debug, thiserror::Error)]
+pub enum ListError<R> {
+	#[error("cyclic list at node {0}")]
+	Cycle(R),
+
+	#[error("missing first item of list node {0}")]
+	MissingFirst(R),
+
+	#[error("multiple first items of list node {0}")]
+	MultipleFirsts(R),
+
+	#[error("missing rest of list node {0}")]
+	MissingRest(R),
+
+	#[error("multiple rests of list node {0}")]
+	MultipleRests(R),
 }
 
 impl<G: PatternMatchingGraph> PatternMatchingDataset for G {
@@ -376,13 +646,72 @@ where
 	}
 }
 
+/// Iterator over the quads matching a pattern, along with the variable
+/// bindings.
+///
+/// See [`PatternMatchingDataset::quad_pattern_matching_bindings`].
+pub struct QuadPatternMatchingBindings<'a, 'p, D: 'a + ?Sized + PatternMatchingDataset, V>
+where
+	D::Resource: 'p,
+{
+	vars: [Option<V>; 4],
+	inner: D::QuadPatternMatching<'a, 'p>,
+}
+
+impl<'a, 'p, D: 'a + ?Sized + PatternMatchingDataset, V: Clone + Eq + Hash> Iterator
+	for QuadPatternMatchingBindings<'a, 'p, D, V>
+where
+	D::Resource: 'p,
+{
+	type Item = (Quad<&'a D::Resource>, HashMap<V, &'a D::Resource>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let quad = self.inner.next()?;
+		let mut bindings = HashMap::new();
+
+		let values = [Some(quad.0), Some(quad.1), Some(quad.2), quad.3];
+		for (x, value) in self.vars.iter().zip(values) {
+			if let (Some(x), Some(value)) = (x, value) {
+				bindings.insert(x.clone(), value);
+			}
+		}
+
+		Some((quad, bindings))
+	}
+}
+
+/// Iterator over the quads not matching a pattern.
+///
+/// See [`PatternMatchingDataset::quads_not_matching`].
+pub struct QuadsNotMatching<'a, 'p, D: 'a + ?Sized + TraversableDataset>
+where
+	D::Resource: 'p,
+{
+	pattern: CanonicalQuadPattern<&'p D::Resource>,
+	quads: D::Quads<'a>,
+}
+
+impl<'a, 'p, D: 'a + ?Sized + TraversableDataset> Iterator for QuadsNotMatching<'a, 'p, D>
+where
+	D::Resource: 'p + PartialEq,
+{
+	type Item = Quad<&'a D::Resource>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let pattern = &self.pattern;
+		self.quads.find(|quad| !pattern.matches(quad.as_ref()))
+	}
+}
+
 /// Mutable dataset.
 pub trait DatasetMut: Dataset {
 	/// Inserts the given quad in the dataset.
 	fn insert(&mut self, quad: Quad<Self::Resource>);
 
 	/// Removes the given quad from the dataset.
-	fn remove(&mut self, quad: Quad<&Self::Resource>);
+	///
+	/// Returns `true` if the quad was in the dataset.
+	fn remove(&mut self, quad: Quad<&Self::Resource>) -> bool;
 }
 
 /// Dataset view focusing on a given graph.
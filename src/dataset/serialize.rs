@@ -0,0 +1,50 @@
+//! Dataset serialization.
+use std::io;
+
+use super::TraversableDataset;
+use crate::RdfDisplay;
+
+/// Dataset that can be serialized into an RDF document.
+pub trait SerializeRdf {
+	/// Writes the dataset as an [N-Quads] document.
+	///
+	/// Each quad is written on its own line, followed by ` .`. Quads in the
+	/// default graph are written without graph term.
+	///
+	/// [N-Quads]: <https://www.w3.org/TR/n-quads/>
+	///
+	/// ```
+	/// use rdf_types::{dataset::{BTreeDataset, SerializeRdf}, Quad, Term};
+	/// use static_iref::iri;
+	///
+	/// let mut dataset = BTreeDataset::<Term>::new();
+	/// dataset.insert(Quad(
+	///   Term::iri(iri!("http://example.org/s").to_owned()),
+	///   Term::iri(iri!("http://example.org/p").to_owned()),
+	///   Term::iri(iri!("http://example.org/o").to_owned()),
+	///   Some(Term::iri(iri!("http://example.org/g").to_owned()))
+	/// ));
+	///
+	/// let mut buffer = Vec::new();
+	/// dataset.write_nquads(&mut buffer).unwrap();
+	/// assert_eq!(
+	///   buffer,
+	///   b"<http://example.org/s> <http://example.org/p> <http://example.org/o> <http://example.org/g> .\n"
+	/// );
+	/// ```
+	fn write_nquads(&self, w: &mut impl io::Write) -> io::Result<()>;
+}
+
+impl<D: TraversableDataset> SerializeRdf for D
+where
+	D::Resource: RdfDisplay,
+{
+	fn write_nquads(&self, w: &mut impl io::Write) -> io::Result<()> {
+		for quad in self.quads() {
+			quad.rdf_write(w)?;
+			w.write_all(b" .\n")?
+		}
+
+		Ok(())
+	}
+}
@@ -1,4 +1,4 @@
-use std::fmt;
+use std::{fmt, io};
 
 use langtag::{LangTag, LangTagBuf};
 
@@ -12,24 +12,116 @@ pub trait RdfDisplay {
 	fn rdf_display(&self) -> RdfDisplayed<&Self> {
 		RdfDisplayed(self)
 	}
+
+	/// Writes the value as an RDF syntax element into the given byte
+	/// writer.
+	///
+	/// The default implementation goes through [`Self::rdf_fmt`]. Strings,
+	/// IRIs, terms, literals, triples, quads and the dataset types override
+	/// it to write bytes directly, sharing the escaping rules of
+	/// [`Self::rdf_fmt`].
+	///
+	/// ```
+	/// use rdf_types::{Literal, LiteralType, RdfDisplay, XSD_STRING};
+	///
+	/// let literal = Literal::new("a \"quoted\" value".to_owned(), LiteralType::Any(XSD_STRING.to_owned()));
+	///
+	/// let mut buffer = Vec::new();
+	/// literal.rdf_write(&mut buffer).unwrap();
+	/// assert_eq!(buffer, br#""a \"quoted\" value""#);
+	/// ```
+	fn rdf_write<W: io::Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+		write!(writer, "{}", self.rdf_display())
+	}
+
+	/// Appends the value as an RDF syntax element to the given string
+	/// buffer.
+	///
+	/// Unlike `rdf_display().to_string()`, this does not allocate a new
+	/// `String` for each value: the same buffer can be cleared and reused
+	/// across calls.
+	///
+	/// ```
+	/// use rdf_types::{RdfDisplay, Term};
+	///
+	/// let terms: Vec<Term> = vec!["<http://example.org/a>".parse().unwrap(), "_:b".parse().unwrap()];
+	///
+	/// let mut buffer = String::new();
+	/// for term in &terms {
+	///     buffer.clear();
+	///     term.rdf_fmt_into(&mut buffer).unwrap();
+	///     assert_eq!(buffer, term.rdf_display().to_string());
+	/// }
+	/// ```
+	fn rdf_fmt_into(&self, buffer: &mut String) -> fmt::Result {
+		use fmt::Write;
+		write!(buffer, "{}", self.rdf_display())
+	}
+}
+
+/// Escapes the given string as an RDF string literal, passing each chunk of
+/// the output to `write`.
+fn escape_str<E>(value: &str, mut write: impl FnMut(&str) -> Result<(), E>) -> Result<(), E> {
+	write("\"")?;
+
+	let mut start = 0;
+	for (i, c) in value.char_indices() {
+		let escaped = match c {
+			'"' => "\\\"",
+			'\\' => "\\\\",
+			'\n' => "\\n",
+			'\r' => "\\r",
+			_ => continue,
+		};
+
+		write(&value[start..i])?;
+		write(escaped)?;
+		start = i + 1
+	}
+
+	write(&value[start..])?;
+	write("\"")
+}
+
+/// Escapes the given IRI reference as an RDF IRI, passing each chunk of the
+/// output to `write`.
+///
+/// Characters not allowed in an N-Triples `IRIREF` are written as a `\u00XX`
+/// `UCHAR` escape sequence.
+fn escape_iri<E>(value: &str, mut write: impl FnMut(&str) -> Result<(), E>) -> Result<(), E> {
+	const HEX: &[u8; 16] = b"0123456789ABCDEF";
+	write("<")?;
+
+	let mut start = 0;
+	for (i, c) in value.char_indices() {
+		if let '\x00'..='\x20' | '<' | '>' | '"' | '{' | '}' | '|' | '^' | '`' | '\\' = c {
+			let byte = c as u8;
+			let escaped = [
+				b'\\',
+				b'u',
+				b'0',
+				b'0',
+				HEX[(byte >> 4) as usize],
+				HEX[(byte & 0xf) as usize],
+			];
+
+			write(&value[start..i])?;
+			write(std::str::from_utf8(&escaped).unwrap())?;
+			start = i + 1
+		}
+	}
+
+	write(&value[start..])?;
+	write(">")
 }
 
 impl RdfDisplay for str {
 	fn rdf_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		use fmt::Display;
-		write!(f, "\"")?;
-
-		for c in self.chars() {
-			match c {
-				'"' => write!(f, "\\\""),
-				'\\' => write!(f, "\\\\"),
-				'\n' => write!(f, "\\n"),
-				'\r' => write!(f, "\\r"),
-				c => c.fmt(f),
-			}?
-		}
+		escape_str(self, |s| f.write_str(s))
+	}
 
-		write!(f, "\"")
+	fn rdf_write<W: io::Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+		escape_str(self, |s| writer.write_all(s.as_bytes()))
 	}
 }
 
@@ -37,23 +129,30 @@ impl RdfDisplay for String {
 	fn rdf_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 		self.as_str().rdf_fmt(f)
 	}
+
+	fn rdf_write<W: io::Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+		self.as_str().rdf_write(writer)
+	}
+}
+
+#[cfg(feature = "smol_str")]
+impl RdfDisplay for smol_str::SmolStr {
+	fn rdf_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.as_str().rdf_fmt(f)
+	}
+
+	fn rdf_write<W: io::Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+		self.as_str().rdf_write(writer)
+	}
 }
 
 impl RdfDisplay for iref::IriRef {
 	fn rdf_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		write!(f, "<")?;
-
-		for c in self.as_str().chars() {
-			match c {
-				'\x00'..='\x20' | '<' | '>' | '"' | '{' | '}' | '|' | '^' | '`' | '\\' => {
-					let bytes: u32 = c.into();
-					write!(f, "\\u{bytes:#04x}")
-				}
-				_ => fmt::Display::fmt(&c, f),
-			}?;
-		}
+		escape_iri(self.as_str(), |s| f.write_str(s))
+	}
 
-		write!(f, ">")
+	fn rdf_write<W: io::Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+		escape_iri(self.as_str(), |s| writer.write_all(s.as_bytes()))
 	}
 }
 
@@ -62,6 +161,11 @@ impl RdfDisplay for iref::Iri {
 	fn rdf_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 		self.as_iri_ref().rdf_fmt(f)
 	}
+
+	#[inline(always)]
+	fn rdf_write<W: io::Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+		self.as_iri_ref().rdf_write(writer)
+	}
 }
 
 #[cfg(feature = "contextual")]
@@ -76,6 +180,11 @@ impl RdfDisplay for iref::IriBuf {
 	fn rdf_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 		self.as_iri_ref().rdf_fmt(f)
 	}
+
+	#[inline(always)]
+	fn rdf_write<W: io::Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+		self.as_iri_ref().rdf_write(writer)
+	}
 }
 
 #[cfg(feature = "contextual")]
@@ -90,6 +199,11 @@ impl RdfDisplay for iref::IriRefBuf {
 	fn rdf_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 		self.as_iri_ref().rdf_fmt(f)
 	}
+
+	#[inline(always)]
+	fn rdf_write<W: io::Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+		self.as_iri_ref().rdf_write(writer)
+	}
 }
 
 #[cfg(feature = "contextual")]
@@ -104,6 +218,10 @@ impl RdfDisplay for LangTag {
 		use fmt::Display;
 		self.as_str().fmt(f)
 	}
+
+	fn rdf_write<W: io::Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+		writer.write_all(self.as_str().as_bytes())
+	}
 }
 
 #[cfg(feature = "contextual")]
@@ -118,6 +236,10 @@ impl RdfDisplay for LangTagBuf {
 		use fmt::Display;
 		self.as_str().fmt(f)
 	}
+
+	fn rdf_write<W: io::Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+		writer.write_all(self.as_str().as_bytes())
+	}
 }
 
 #[cfg(feature = "contextual")]
@@ -132,6 +254,23 @@ impl<'a, T: RdfDisplay + ?Sized> RdfDisplay for &'a T {
 	fn rdf_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 		T::rdf_fmt(*self, f)
 	}
+
+	#[inline(always)]
+	fn rdf_write<W: io::Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+		T::rdf_write(*self, writer)
+	}
+}
+
+impl<T: RdfDisplay + ?Sized> RdfDisplay for std::sync::Arc<T> {
+	#[inline(always)]
+	fn rdf_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		T::rdf_fmt(self, f)
+	}
+
+	#[inline(always)]
+	fn rdf_write<W: io::Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+		T::rdf_write(self, writer)
+	}
 }
 
 /// Value ready to be formatted as an RDF syntax element.
@@ -174,3 +313,73 @@ impl<'c, T: RdfDisplayWithContext<C>, C: ?Sized> RdfDisplay
 		self.0.rdf_fmt_with(self.1, f)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use crate::{dataset::BTreeDataset, Literal, LiteralType, Quad, RdfDisplay, Term, XSD_STRING};
+
+	fn assert_same_output<T: RdfDisplay + ?Sized>(value: &T) {
+		let mut buffer = Vec::new();
+		value.rdf_write(&mut buffer).unwrap();
+		assert_eq!(
+			String::from_utf8(buffer).unwrap(),
+			value.rdf_display().to_string()
+		)
+	}
+
+	#[test]
+	fn rdf_write() {
+		assert_same_output("plain");
+		assert_same_output("\"quoted\" \\ line\nfeed\r, café");
+
+		let terms: Vec<Term> = vec![
+			"<http://example.org/a>".parse().unwrap(),
+			"_:b".parse().unwrap(),
+			Term::Literal(Literal::new(
+				"a \"value\"\n".to_owned(),
+				LiteralType::Any(XSD_STRING.to_owned()),
+			)),
+			"\"chat\"@fr".parse().unwrap(),
+			"\"1\"^^<http://www.w3.org/2001/XMLSchema#integer>"
+				.parse()
+				.unwrap(),
+		];
+
+		for term in &terms {
+			if let Term::Id(id) = term {
+				assert_same_output(id)
+			}
+
+			assert_same_output(term)
+		}
+
+		let dataset: BTreeDataset = [
+			Quad(terms[0].clone(), terms[0].clone(), terms[2].clone(), None),
+			Quad(
+				terms[1].clone(),
+				terms[0].clone(),
+				terms[3].clone(),
+				Some(terms[0].clone()),
+			),
+		]
+		.into_iter()
+		.collect();
+
+		for quad in &dataset {
+			assert_same_output(&quad)
+		}
+
+		assert_same_output(&dataset)
+	}
+
+	#[test]
+	fn escape_iri() {
+		let mut output = String::new();
+		super::escape_iri("http://example.org/a b<c>", |s| {
+			output.push_str(s);
+			Ok::<_, std::convert::Infallible>(())
+		})
+		.unwrap();
+		assert_eq!(output, "<http://example.org/a\\u0020b\\u003Cc\\u003E>")
+	}
+}
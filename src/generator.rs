@@ -1,4 +1,7 @@
 //! Resource identifier generators.
+#[cfg(feature = "uuid-generator-v4")]
+use std::sync::Mutex;
+
 use crate::{
 	vocabulary::{BlankIdVocabulary, BlankIdVocabularyMut, IriVocabulary},
 	BlankIdBuf, Id, Vocabulary,
@@ -21,7 +24,10 @@ impl<'a, V: IriVocabulary + BlankIdVocabulary, G: Generator<V>> Generator<V> for
 ///
 /// This generator can create `usize::MAX` unique blank node identifiers.
 /// If [`Generator::next`] is called `usize::MAX + 1` times, it will panic.
-#[derive(Default)]
+///
+/// Cloning the generator snapshots its counter: the clone then generates the
+/// same sequence of identifiers as the original, independently.
+#[derive(Debug, Default, Clone)]
 pub struct Blank {
 	/// Prefix string.
 	prefix: String,
@@ -120,6 +126,7 @@ impl<V: Vocabulary + BlankIdVocabularyMut> Generator<V> for Blank {
 /// use this type.
 /// You also need to enable the features of each version you need
 /// in the `uuid` crate.
+#[derive(Debug)]
 pub enum Uuid {
 	/// UUIDv3.
 	///
@@ -134,6 +141,15 @@ pub enum Uuid {
 	#[cfg(feature = "uuid-generator-v4")]
 	V4,
 
+	/// Deterministic UUIDv4.
+	///
+	/// Random bytes are generated from the given 256-bit state using the
+	/// xoshiro256** algorithm instead of the system random number generator,
+	/// so that the same sequence of UUIDs is generated from the same seed.
+	/// See [`Uuid::from_rng`].
+	#[cfg(feature = "uuid-generator-v4")]
+	SeededV4(Mutex<[u64; 4]>),
+
 	/// UUIDv5.
 	///
 	/// You must provide a vocabulary UUID and a name.
@@ -148,12 +164,46 @@ pub enum Uuid {
 	feature = "uuid-generator-v5"
 ))]
 impl Uuid {
+	/// Creates a deterministic UUIDv4 generator seeded from the given random
+	/// number generator.
+	///
+	/// The whole generator state is filled from `rng`, so that each UUID
+	/// gets its 122 bits of entropy. This is useful to get reproducible
+	/// identifiers, for instance in tests.
+	#[cfg(feature = "uuid-generator-v4")]
+	pub fn from_rng(mut rng: impl rand_core::RngCore) -> Self {
+		let mut state = [0; 4];
+		for word in &mut state {
+			*word = rng.next_u64()
+		}
+
+		// The all-zero state would only ever generate zeros.
+		if state == [0; 4] {
+			state[0] = 1
+		}
+
+		Self::SeededV4(Mutex::new(state))
+	}
+
 	pub fn next_uuid(&self) -> uuid::Uuid {
 		match self {
 			#[cfg(feature = "uuid-generator-v3")]
 			Self::V3(vocabulary, name) => uuid::Uuid::new_v3(vocabulary, name.as_bytes()),
 			#[cfg(feature = "uuid-generator-v4")]
 			Self::V4 => uuid::Uuid::new_v4(),
+			#[cfg(feature = "uuid-generator-v4")]
+			Self::SeededV4(state) => {
+				let mut state = state.lock().unwrap();
+				let mut bytes = [0; 16];
+				bytes[..8].copy_from_slice(&xoshiro_256_star_star(&mut state).to_le_bytes());
+				bytes[8..].copy_from_slice(&xoshiro_256_star_star(&mut state).to_le_bytes());
+
+				// Set the version (4) and variant (RFC 4122) bits.
+				bytes[6] = (bytes[6] & 0x0f) | 0x40;
+				bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+				uuid::Uuid::from_bytes(bytes)
+			}
 			#[cfg(feature = "uuid-generator-v5")]
 			Self::V5(vocabulary, name) => uuid::Uuid::new_v5(vocabulary, name.as_bytes()),
 		}
@@ -184,6 +234,41 @@ impl Uuid {
 	}
 }
 
+#[cfg(any(
+	feature = "uuid-generator-v3",
+	feature = "uuid-generator-v4",
+	feature = "uuid-generator-v5"
+))]
+impl Clone for Uuid {
+	fn clone(&self) -> Self {
+		match self {
+			#[cfg(feature = "uuid-generator-v3")]
+			Self::V3(vocabulary, name) => Self::V3(*vocabulary, name.clone()),
+			#[cfg(feature = "uuid-generator-v4")]
+			Self::V4 => Self::V4,
+			#[cfg(feature = "uuid-generator-v4")]
+			Self::SeededV4(state) => Self::SeededV4(Mutex::new(*state.lock().unwrap())),
+			#[cfg(feature = "uuid-generator-v5")]
+			Self::V5(vocabulary, name) => Self::V5(*vocabulary, name.clone()),
+		}
+	}
+}
+
+/// Returns the next output of the xoshiro256** pseudo-random number
+/// generator.
+#[cfg(feature = "uuid-generator-v4")]
+fn xoshiro_256_star_star(s: &mut [u64; 4]) -> u64 {
+	let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+	let t = s[1] << 17;
+	s[2] ^= s[0];
+	s[3] ^= s[1];
+	s[1] ^= s[2];
+	s[0] ^= s[3];
+	s[2] ^= t;
+	s[3] = s[3].rotate_left(45);
+	result
+}
+
 #[cfg(any(
 	feature = "uuid-generator-v3",
 	feature = "uuid-generator-v4",
@@ -202,15 +287,24 @@ impl<V: crate::Vocabulary + crate::vocabulary::IriVocabularyMut> Generator<V> fo
 	}
 }
 
-#[cfg(any(
-	feature = "uuid-generator-v3",
-	feature = "uuid-generator-v4",
-	feature = "uuid-generator-v5"
-))]
 #[cfg(test)]
 mod tests {
 	use super::*;
 
+	#[test]
+	fn blank_clone() {
+		let mut generator = Blank::new_with_prefix("b".to_owned());
+		generator.next_blank_id();
+
+		let mut snapshot = generator.clone();
+		let a: Vec<_> = (0..3).map(|_| generator.next_blank_id()).collect();
+		let b: Vec<_> = (0..3).map(|_| snapshot.next_blank_id()).collect();
+		assert_eq!(a, b);
+		assert_eq!(a[0].as_str(), "_:b1");
+		assert_eq!(generator.count(), 4);
+		assert_eq!(snapshot.count(), 4)
+	}
+
 	#[cfg(feature = "uuid-generator-v3")]
 	#[test]
 	fn uuidv3_iri() {
@@ -234,6 +328,29 @@ mod tests {
 		}
 	}
 
+	#[cfg(feature = "uuid-generator-v4")]
+	#[test]
+	fn seeded_uuidv4() {
+		use rand::{rngs::SmallRng, SeedableRng};
+
+		let uuid_gen = Uuid::from_rng(SmallRng::seed_from_u64(42));
+		let first = uuid_gen.next_uuid();
+		assert_eq!(first.get_version(), Some(uuid::Version::Random));
+		assert_eq!(first.get_variant(), Some(uuid::Variant::RFC4122));
+
+		let snapshot = uuid_gen.clone();
+		for _ in 0..100 {
+			assert_eq!(uuid_gen.next_uuid(), snapshot.next_uuid())
+		}
+
+		let other = Uuid::from_rng(SmallRng::seed_from_u64(42));
+		assert_eq!(other.next_uuid(), first);
+		assert_ne!(
+			Uuid::from_rng(SmallRng::seed_from_u64(43)).next_uuid(),
+			first
+		)
+	}
+
 	#[cfg(feature = "uuid-generator-v5")]
 	#[test]
 	fn uuidv5_iri() {
@@ -1,13 +1,20 @@
-use std::collections::{HashMap, HashSet};
+use std::{
+	collections::{HashMap, HashSet},
+	fmt,
+};
 
 use crate::interpretation::{
 	BlankIdInterpretation, BlankIdInterpretationMut, IriInterpretation, IriInterpretationMut,
-	LiteralInterpretation, LiteralInterpretationMut, ReverseBlankIdInterpretation,
-	ReverseBlankIdInterpretationMut, ReverseIriInterpretation, ReverseIriInterpretationMut,
-	ReverseLiteralInterpretation, ReverseLiteralInterpretationMut, TraversableInterpretation,
+	LiteralInterpretation, LiteralInterpretationMut, MergeableInterpretation,
+	ReverseBlankIdInterpretation, ReverseBlankIdInterpretationMut, ReverseIriInterpretation,
+	ReverseIriInterpretationMut, ReverseLiteralInterpretation, ReverseLiteralInterpretationMut,
+	ReverseTermInterpretation, TraversableInterpretation,
+};
+use crate::vocabulary::{
+	BlankIdIndex, BlankIdVocabulary, ExtractedFromVocabulary, IriIndex, LiteralIndex,
+	LiteralVocabulary,
 };
-use crate::vocabulary::{BlankIdIndex, IriIndex, LiteralIndex};
-use crate::{Interpretation, InterpretationMut};
+use crate::{Interpretation, InterpretationMut, RdfDisplay, Term};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct ResourceIndex(usize);
@@ -27,6 +34,10 @@ impl From<ResourceIndex> for usize {
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct Resource {
 	index: ResourceIndex,
+
+	/// Resource this resource has been merged into, if any.
+	parent: Option<ResourceIndex>,
+
 	iris: HashSet<IriIndex>,
 	blank_ids: HashSet<BlankIdIndex>,
 	literals: HashSet<LiteralIndex>,
@@ -36,6 +47,7 @@ impl Resource {
 	fn new(index: ResourceIndex) -> Self {
 		Self {
 			index,
+			parent: None,
 			iris: HashSet::new(),
 			blank_ids: HashSet::new(),
 			literals: HashSet::new(),
@@ -55,11 +67,21 @@ impl Resources {
 		self.0.is_empty()
 	}
 
+	/// Finds the resource the given resource has been merged into, if any.
+	fn find(&self, mut i: ResourceIndex) -> Option<ResourceIndex> {
+		while let Some(parent) = self.0.get(i.0)?.parent {
+			i = parent
+		}
+
+		Some(i)
+	}
+
 	fn get(&self, i: ResourceIndex) -> Option<&Resource> {
-		self.0.get(i.0)
+		self.0.get(self.find(i)?.0)
 	}
 
 	fn get_mut(&mut self, i: ResourceIndex) -> Option<&mut Resource> {
+		let i = self.find(i)?;
 		self.0.get_mut(i.0)
 	}
 
@@ -74,6 +96,7 @@ impl Resources {
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct Indexed {
 	resources: Resources,
+	merged_count: usize,
 	by_iri: HashMap<IriIndex, ResourceIndex>,
 	by_blank_id: HashMap<BlankIdIndex, ResourceIndex>,
 	by_literal: HashMap<LiteralIndex, ResourceIndex>,
@@ -84,13 +107,137 @@ impl Indexed {
 		Self::default()
 	}
 
+	/// Returns the number of distinct resources.
+	///
+	/// Merged resources count as one.
 	pub fn len(&self) -> usize {
-		self.resources.len()
+		self.resources.len() - self.merged_count
 	}
 
 	pub fn is_empty(&self) -> bool {
 		self.resources.is_empty()
 	}
+
+	/// Returns the resource the given resource has been merged into, or the
+	/// resource itself if it has not been merged.
+	///
+	/// # Panics
+	///
+	/// Panics if the resource is unknown.
+	pub fn representative(&self, resource: ResourceIndex) -> ResourceIndex {
+		self.resources.find(resource).unwrap()
+	}
+
+	/// Starts a transaction on this interpretation.
+	///
+	/// Every resource and term interpretation created through the returned
+	/// transaction can be reverted using
+	/// [`TransactionalInterpretation::rollback`].
+	pub fn transaction(&mut self) -> TransactionalInterpretation<'_> {
+		TransactionalInterpretation {
+			resource_count: self.resources.len(),
+			interpretation: self,
+			log: Vec::new(),
+		}
+	}
+
+	/// Writes every resource along with the terms interpreted as it, for
+	/// debugging purposes.
+	///
+	/// Resources are written one per line, in the form
+	/// `r0 -> [<http://example.org/a>, _:b1]`, with their terms sorted
+	/// lexically. Merged resources only appear once, under the index of their
+	/// representative.
+	pub fn debug_dump<V>(&self, vocabulary: &V, w: &mut impl fmt::Write) -> fmt::Result
+	where
+		V: BlankIdVocabulary<BlankId = BlankIdIndex>
+			+ LiteralVocabulary<Iri = IriIndex, Literal = LiteralIndex>,
+	{
+		for r in self.resources() {
+			let mut terms: Vec<_> = self
+				.terms_of(r)
+				.map(|term| {
+					let term: Term = match term {
+						Term::Id(id) => Term::Id(id.cloned().extracted_from_vocabulary(vocabulary)),
+						Term::Literal(l) => Term::Literal(l.extracted_from_vocabulary(vocabulary)),
+					};
+
+					term.rdf_display().to_string()
+				})
+				.collect();
+
+			terms.sort_unstable();
+			writeln!(w, "r{} -> [{}]", r.0, terms.join(", "))?
+		}
+
+		Ok(())
+	}
+}
+
+impl MergeableInterpretation for Indexed {
+	/// Merges the two given resources using union-find.
+	///
+	/// The terms of the resource with the fewest terms are moved to the
+	/// other, which is returned. Both resource indexes remain valid and
+	/// refer to the merged resource from now on.
+	///
+	/// # Panics
+	///
+	/// Panics if one of the resources is unknown.
+	fn merge(&mut self, a: Self::Resource, b: Self::Resource) -> Self::Resource {
+		let ra = self.representative(a);
+		let rb = self.representative(b);
+
+		if ra == rb {
+			return ra;
+		}
+
+		let size = |r: &Resource| r.iris.len() + r.blank_ids.len() + r.literals.len();
+		let (root, child) = if size(&self.resources.0[ra.0]) >= size(&self.resources.0[rb.0]) {
+			(ra, rb)
+		} else {
+			(rb, ra)
+		};
+
+		let child_resource = &mut self.resources.0[child.0];
+		child_resource.parent = Some(root);
+		let iris = std::mem::take(&mut child_resource.iris);
+		let blank_ids = std::mem::take(&mut child_resource.blank_ids);
+		let literals = std::mem::take(&mut child_resource.literals);
+
+		for iri in &iris {
+			if let Some(r) = self.by_iri.get_mut(iri) {
+				*r = root
+			}
+		}
+
+		for blank_id in &blank_ids {
+			if let Some(r) = self.by_blank_id.get_mut(blank_id) {
+				*r = root
+			}
+		}
+
+		for literal in &literals {
+			if let Some(r) = self.by_literal.get_mut(literal) {
+				*r = root
+			}
+		}
+
+		let root_resource = &mut self.resources.0[root.0];
+		root_resource.iris.extend(iris);
+		root_resource.blank_ids.extend(blank_ids);
+		root_resource.literals.extend(literals);
+
+		// Path compression.
+		for r in [a, b] {
+			if r != root {
+				self.resources.0[r.0].parent = Some(root)
+			}
+		}
+
+		self.merged_count += 1;
+		root
+	}
 }
 
 impl Interpretation for Indexed {
@@ -117,7 +264,7 @@ impl<'a> Iterator for ResourceIndexIter<'a> {
 	type Item = &'a ResourceIndex;
 
 	fn next(&mut self) -> Option<Self::Item> {
-		self.0.next().map(|r| &r.index)
+		self.0.find(|r| r.parent.is_none()).map(|r| &r.index)
 	}
 }
 
@@ -239,3 +386,372 @@ impl ReverseLiteralInterpretationMut for Indexed {
 			.insert(literal)
 	}
 }
+
+/// Change made to an [`Indexed`] interpretation during a transaction.
+enum Change {
+	InterpretedIri(IriIndex),
+	InterpretedBlankId(BlankIdIndex),
+	InterpretedLiteral(LiteralIndex),
+	AssignedIri(ResourceIndex, IriIndex),
+	AssignedBlankId(ResourceIndex, BlankIdIndex),
+	AssignedLiteral(ResourceIndex, LiteralIndex),
+}
+
+/// Transaction on an [`Indexed`] interpretation.
+///
+/// Records the changes made to the interpretation so they can be reverted
+/// with [`Self::rollback`], for instance when a parser fails in the middle of
+/// a statement. Resources cannot be merged during a transaction.
+///
+/// Dropping the transaction keeps the changes, like [`Self::commit`].
+pub struct TransactionalInterpretation<'a> {
+	interpretation: &'a mut Indexed,
+
+	/// Number of resources when the transaction began.
+	resource_count: usize,
+
+	log: Vec<Change>,
+}
+
+impl<'a> TransactionalInterpretation<'a> {
+	/// Returns the underlying interpretation.
+	pub fn interpretation(&self) -> &Indexed {
+		self.interpretation
+	}
+
+	/// Keeps all the changes made during the transaction.
+	pub fn commit(self) {}
+
+	/// Reverts all the changes made during the transaction.
+	///
+	/// Resources created since the transaction began are removed, and terms
+	/// interpreted or assigned since then are forgotten.
+	pub fn rollback(self) {
+		let interpretation = self.interpretation;
+
+		for change in self.log.into_iter().rev() {
+			match change {
+				Change::InterpretedIri(iri) => {
+					interpretation.by_iri.remove(&iri);
+				}
+				Change::InterpretedBlankId(blank_id) => {
+					interpretation.by_blank_id.remove(&blank_id);
+				}
+				Change::InterpretedLiteral(literal) => {
+					interpretation.by_literal.remove(&literal);
+				}
+				Change::AssignedIri(r, iri) => {
+					if let Some(r) = interpretation.resources.0.get_mut(r.0) {
+						r.iris.remove(&iri);
+					}
+				}
+				Change::AssignedBlankId(r, blank_id) => {
+					if let Some(r) = interpretation.resources.0.get_mut(r.0) {
+						r.blank_ids.remove(&blank_id);
+					}
+				}
+				Change::AssignedLiteral(r, literal) => {
+					if let Some(r) = interpretation.resources.0.get_mut(r.0) {
+						r.literals.remove(&literal);
+					}
+				}
+			}
+		}
+
+		interpretation.resources.0.truncate(self.resource_count)
+	}
+}
+
+impl<'a> Interpretation for TransactionalInterpretation<'a> {
+	type Resource = ResourceIndex;
+}
+
+impl<'a> TraversableInterpretation for TransactionalInterpretation<'a> {
+	type Resources<'r> = ResourceIndexIter<'r> where Self: 'r;
+
+	fn resources(&self) -> Self::Resources<'_> {
+		self.interpretation.resources()
+	}
+}
+
+impl<'a, V> InterpretationMut<V> for TransactionalInterpretation<'a> {
+	fn new_resource(&mut self, vocabulary: &mut V) -> Self::Resource {
+		self.interpretation.new_resource(vocabulary)
+	}
+}
+
+impl<'a> IriInterpretation<IriIndex> for TransactionalInterpretation<'a> {
+	fn iri_interpretation(&self, iri: &IriIndex) -> Option<Self::Resource> {
+		self.interpretation.iri_interpretation(iri)
+	}
+}
+
+impl<'a> IriInterpretationMut<IriIndex> for TransactionalInterpretation<'a> {
+	fn interpret_iri(&mut self, iri: IriIndex) -> Self::Resource {
+		if !self.interpretation.by_iri.contains_key(&iri) {
+			self.log.push(Change::InterpretedIri(iri))
+		}
+
+		self.interpretation.interpret_iri(iri)
+	}
+}
+
+impl<'a> BlankIdInterpretation<BlankIdIndex> for TransactionalInterpretation<'a> {
+	fn blank_id_interpretation(&self, blank_id: &BlankIdIndex) -> Option<Self::Resource> {
+		self.interpretation.blank_id_interpretation(blank_id)
+	}
+}
+
+impl<'a> BlankIdInterpretationMut<BlankIdIndex> for TransactionalInterpretation<'a> {
+	fn interpret_blank_id(&mut self, blank_id: BlankIdIndex) -> Self::Resource {
+		if !self.interpretation.by_blank_id.contains_key(&blank_id) {
+			self.log.push(Change::InterpretedBlankId(blank_id))
+		}
+
+		self.interpretation.interpret_blank_id(blank_id)
+	}
+}
+
+impl<'a> LiteralInterpretation<LiteralIndex> for TransactionalInterpretation<'a> {
+	fn literal_interpretation(&self, literal: &LiteralIndex) -> Option<Self::Resource> {
+		self.interpretation.literal_interpretation(literal)
+	}
+}
+
+impl<'a> LiteralInterpretationMut<LiteralIndex> for TransactionalInterpretation<'a> {
+	fn interpret_literal(&mut self, literal: LiteralIndex) -> Self::Resource {
+		if !self.interpretation.by_literal.contains_key(&literal) {
+			self.log.push(Change::InterpretedLiteral(literal))
+		}
+
+		self.interpretation.interpret_literal(literal)
+	}
+}
+
+impl<'a> ReverseIriInterpretation for TransactionalInterpretation<'a> {
+	type Iri = IriIndex;
+	type Iris<'r> = <Indexed as ReverseIriInterpretation>::Iris<'r> where Self: 'r;
+
+	fn iris_of<'r>(&'r self, id: &'r Self::Resource) -> Self::Iris<'r> {
+		self.interpretation.iris_of(id)
+	}
+}
+
+impl<'a> ReverseBlankIdInterpretation for TransactionalInterpretation<'a> {
+	type BlankId = BlankIdIndex;
+	type BlankIds<'r> = <Indexed as ReverseBlankIdInterpretation>::BlankIds<'r> where Self: 'r;
+
+	fn blank_ids_of<'r>(&'r self, id: &'r Self::Resource) -> Self::BlankIds<'r> {
+		self.interpretation.blank_ids_of(id)
+	}
+}
+
+impl<'a> ReverseLiteralInterpretation for TransactionalInterpretation<'a> {
+	type Literal = LiteralIndex;
+	type Literals<'r> = <Indexed as ReverseLiteralInterpretation>::Literals<'r> where Self: 'r;
+
+	fn literals_of<'r>(&'r self, id: &'r Self::Resource) -> Self::Literals<'r> {
+		self.interpretation.literals_of(id)
+	}
+}
+
+impl<'a> ReverseIriInterpretationMut for TransactionalInterpretation<'a> {
+	fn assign_iri(&mut self, resource: &Self::Resource, iri: Self::Iri) -> bool {
+		let r = self.interpretation.representative(*resource);
+		let assigned = self.interpretation.assign_iri(&r, iri);
+		if assigned {
+			self.log.push(Change::AssignedIri(r, iri))
+		}
+
+		assigned
+	}
+}
+
+impl<'a> ReverseBlankIdInterpretationMut for TransactionalInterpretation<'a> {
+	fn assign_blank_id(&mut self, resource: &Self::Resource, blank_id: Self::BlankId) -> bool {
+		let r = self.interpretation.representative(*resource);
+		let assigned = self.interpretation.assign_blank_id(&r, blank_id);
+		if assigned {
+			self.log.push(Change::AssignedBlankId(r, blank_id))
+		}
+
+		assigned
+	}
+}
+
+impl<'a> ReverseLiteralInterpretationMut for TransactionalInterpretation<'a> {
+	fn assign_literal(&mut self, resource: &Self::Resource, literal: Self::Literal) -> bool {
+		let r = self.interpretation.representative(*resource);
+		let assigned = self.interpretation.assign_literal(&r, literal);
+		if assigned {
+			self.log.push(Change::AssignedLiteral(r, literal))
+		}
+
+		assigned
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Indexed;
+	use crate::{
+		interpretation::{
+			IriInterpretation, IriInterpretationMut, LiteralInterpretationMut,
+			MergeableInterpretation, ReverseIriInterpretation, ReverseIriInterpretationMut,
+			ReverseLiteralInterpretation, ReverseTermInterpretation, ReverseTermInterpretationMut,
+			TraversableInterpretation,
+		},
+		vocabulary::{IndexVocabulary, IriIndex, IriVocabularyMut, LiteralIndex},
+		Id, InterpretationMut, Quad, Term,
+	};
+	use static_iref::iri;
+
+	#[test]
+	fn debug_dump() {
+		let mut vocabulary: IndexVocabulary = IndexVocabulary::new();
+		let a = vocabulary.insert(iri!("http://example.org/a"));
+		let b = vocabulary.insert(iri!("http://example.org/b"));
+		let c = vocabulary.insert(iri!("http://example.org/c"));
+
+		let mut interpretation = Indexed::new();
+		let r0 = interpretation.interpret_iri(b);
+		let r1 = interpretation.interpret_iri(c);
+		interpretation.assign_iri(&r0, a);
+
+		let mut dump = String::new();
+		interpretation.debug_dump(&vocabulary, &mut dump).unwrap();
+		assert_eq!(
+			dump,
+			"r0 -> [<http://example.org/a>, <http://example.org/b>]\nr1 -> [<http://example.org/c>]\n"
+		);
+
+		interpretation.merge(r1, r0);
+		let mut dump = String::new();
+		interpretation.debug_dump(&vocabulary, &mut dump).unwrap();
+		assert_eq!(
+			dump,
+			"r0 -> [<http://example.org/a>, <http://example.org/b>, <http://example.org/c>]\n"
+		)
+	}
+
+	#[test]
+	fn assign_from_quads() {
+		let mut interpretation = Indexed::new();
+		let s = interpretation.new_resource(&mut ());
+		let p = interpretation.new_resource(&mut ());
+		let o = interpretation.interpret_iri(IriIndex::from(2));
+
+		let iri = |i: usize| Term::Id(Id::Iri(IriIndex::from(i)));
+		let assigned = interpretation.assign_from_quads([
+			Quad((s, iri(0)), (p, iri(1)), (o, iri(2)), None),
+			Quad((s, iri(3)), (p, iri(1)), (o, iri(2)), Some((s, iri(0)))),
+			Quad(
+				(o, Term::Literal(LiteralIndex::from(0))),
+				(p, iri(1)),
+				(s, iri(0)),
+				None,
+			),
+		]);
+		assert_eq!(assigned, 4);
+
+		let iris_of = |r| {
+			let mut iris: Vec<_> = interpretation.iris_of(&r).copied().collect();
+			iris.sort_unstable();
+			iris
+		};
+		assert_eq!(iris_of(s), [IriIndex::from(0), IriIndex::from(3)]);
+		assert_eq!(iris_of(p), [IriIndex::from(1)]);
+		assert_eq!(iris_of(o), [IriIndex::from(2)]);
+		assert_eq!(interpretation.literals_of(&o).count(), 1)
+	}
+
+	#[test]
+	fn quads_of_lossy() {
+		let mut interpretation = Indexed::new();
+		let s = interpretation.interpret_iri(IriIndex::from(0));
+		let p = interpretation.new_resource(&mut ());
+		let o = interpretation.interpret_literal(LiteralIndex::from(0));
+
+		let quad = Quad(&s, &p, &o, None);
+		assert_eq!(interpretation.quads_of(quad).count(), 0);
+
+		let quads: Vec<_> = interpretation.quads_of_lossy(quad).collect();
+		assert_eq!(quads.len(), 1);
+		let Quad(qs, qp, qo, qg) = quads[0];
+		assert_eq!(qs, Some(Id::Iri(&IriIndex::from(0))));
+		assert_eq!(qp, None);
+		assert_eq!(qo, Some(Term::Literal(&LiteralIndex::from(0))));
+		assert_eq!(qg, None);
+
+		let quad = Quad(&s, &s, &o, Some(&p));
+		let quads: Vec<_> = interpretation.quads_of_lossy(quad).collect();
+		assert_eq!(quads.len(), 1);
+		let Quad(_, qp, _, qg) = quads[0];
+		assert_eq!(qp, Some(&IriIndex::from(0)));
+		assert_eq!(qg, Some(None))
+	}
+
+	#[test]
+	fn merge() {
+		let mut interpretation = Indexed::new();
+		let a = interpretation.interpret_iri(IriIndex::from(0));
+		let b = interpretation.interpret_iri(IriIndex::from(1));
+		let c = interpretation.interpret_literal(LiteralIndex::from(0));
+		assert_eq!(interpretation.len(), 3);
+
+		let ab = interpretation.merge(a, b);
+		let abc = interpretation.merge(c, b);
+		assert_eq!(ab, abc);
+		assert_eq!(interpretation.merge(a, c), abc);
+		assert_eq!(interpretation.len(), 1);
+		assert_eq!(interpretation.resources().count(), 1);
+
+		for iri in [0, 1] {
+			assert_eq!(
+				interpretation.iri_interpretation(&IriIndex::from(iri)),
+				Some(abc)
+			)
+		}
+
+		for r in [a, b, c] {
+			assert_eq!(interpretation.representative(r), abc);
+
+			let mut iris: Vec<_> = interpretation.iris_of(&r).copied().collect();
+			iris.sort_unstable();
+			assert_eq!(iris, [IriIndex::from(0), IriIndex::from(1)]);
+
+			let literals: Vec<_> = interpretation.literals_of(&r).copied().collect();
+			assert_eq!(literals, [LiteralIndex::from(0)])
+		}
+	}
+
+	#[test]
+	fn transaction_rollback() {
+		let mut interpretation = Indexed::new();
+		let a = interpretation.interpret_iri(IriIndex::from(0));
+		let before = interpretation.clone();
+
+		let mut transaction = interpretation.transaction();
+		assert_eq!(transaction.interpret_iri(IriIndex::from(0)), a);
+		let b = transaction.interpret_iri(IriIndex::from(1));
+		transaction.interpret_literal(LiteralIndex::from(0));
+		assert!(transaction.assign_iri(&a, IriIndex::from(2)));
+		assert!(transaction.assign_iri(&b, IriIndex::from(3)));
+		assert_eq!(transaction.interpretation().len(), 3);
+		transaction.rollback();
+
+		assert_eq!(interpretation, before);
+		assert_eq!(interpretation.iri_interpretation(&IriIndex::from(1)), None);
+		assert_eq!(interpretation.iris_of(&a).count(), 1);
+
+		let mut transaction = interpretation.transaction();
+		let b = transaction.interpret_iri(IriIndex::from(1));
+		transaction.commit();
+
+		assert_eq!(interpretation.len(), 2);
+		assert_eq!(
+			interpretation.iri_interpretation(&IriIndex::from(1)),
+			Some(b)
+		)
+	}
+}
@@ -1,9 +1,12 @@
 mod indexed;
 mod none;
+mod prefix_scoped;
 mod vocabulary;
 mod with_generator;
 
 pub use indexed::*;
+pub use none::*;
+pub use prefix_scoped::*;
 pub use vocabulary::*;
 pub use with_generator::*;
 
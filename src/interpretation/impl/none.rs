@@ -1,3 +1,7 @@
+//! Interpretations where terms are their own interpretation.
+use std::marker::PhantomData;
+
+use educe::Educe;
 use iref::{Iri, IriBuf};
 
 use crate::{
@@ -9,6 +13,105 @@ use crate::{
 	BlankId, BlankIdBuf, Id, Interpretation, Literal, Term,
 };
 
+/// Interpretation where every term is its own interpretation.
+///
+/// The interpreted resource of an IRI, blank node identifier or literal is
+/// the term itself, of type `Term<Id<I, B>, L>`. No interning takes place,
+/// which makes this interpretation suitable to run interpretation-generic
+/// algorithms directly on terms, whether lexical or vocabulary-embedded.
+///
+/// The `()` interpretation behaves like the default
+/// `SelfInterpretation<IriBuf, BlankIdBuf, Literal>`.
+#[derive(Educe)]
+#[educe(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SelfInterpretation<I = IriBuf, B = BlankIdBuf, L = Literal>(PhantomData<(I, B, L)>);
+
+impl<I, B, L> SelfInterpretation<I, B, L> {
+	/// Creates a new self interpretation.
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<I, B, L> Interpretation for SelfInterpretation<I, B, L> {
+	type Resource = Term<Id<I, B>, L>;
+}
+
+impl<I: Clone, B, L> IriInterpretation<I> for SelfInterpretation<I, B, L> {
+	fn iri_interpretation(&self, iri: &I) -> Option<Self::Resource> {
+		Some(Term::Id(Id::Iri(iri.clone())))
+	}
+}
+
+impl<I, B, L> IriInterpretationMut<I> for SelfInterpretation<I, B, L> {
+	fn interpret_iri(&mut self, iri: I) -> Self::Resource {
+		Term::Id(Id::Iri(iri))
+	}
+}
+
+impl<I, B, L> ReverseIriInterpretation for SelfInterpretation<I, B, L> {
+	type Iri = I;
+
+	type Iris<'a> = std::option::IntoIter<&'a I> where Self: 'a;
+
+	fn iris_of<'a>(&'a self, id: &'a Self::Resource) -> Self::Iris<'a> {
+		match id {
+			Term::Id(Id::Iri(iri)) => Some(iri).into_iter(),
+			_ => None.into_iter(),
+		}
+	}
+}
+
+impl<I, B: Clone, L> BlankIdInterpretation<B> for SelfInterpretation<I, B, L> {
+	fn blank_id_interpretation(&self, blank_id: &B) -> Option<Self::Resource> {
+		Some(Term::Id(Id::Blank(blank_id.clone())))
+	}
+}
+
+impl<I, B, L> BlankIdInterpretationMut<B> for SelfInterpretation<I, B, L> {
+	fn interpret_blank_id(&mut self, blank_id: B) -> Self::Resource {
+		Term::Id(Id::Blank(blank_id))
+	}
+}
+
+impl<I, B, L> ReverseBlankIdInterpretation for SelfInterpretation<I, B, L> {
+	type BlankId = B;
+
+	type BlankIds<'a> = std::option::IntoIter<&'a B> where Self: 'a;
+
+	fn blank_ids_of<'a>(&'a self, id: &'a Self::Resource) -> Self::BlankIds<'a> {
+		match id {
+			Term::Id(Id::Blank(b)) => Some(b).into_iter(),
+			_ => None.into_iter(),
+		}
+	}
+}
+
+impl<I, B, L: Clone> LiteralInterpretation<L> for SelfInterpretation<I, B, L> {
+	fn literal_interpretation(&self, literal: &L) -> Option<Self::Resource> {
+		Some(Term::Literal(literal.clone()))
+	}
+}
+
+impl<I, B, L> LiteralInterpretationMut<L> for SelfInterpretation<I, B, L> {
+	fn interpret_literal(&mut self, literal: L) -> Self::Resource {
+		Term::Literal(literal)
+	}
+}
+
+impl<I, B, L> ReverseLiteralInterpretation for SelfInterpretation<I, B, L> {
+	type Literal = L;
+
+	type Literals<'a> = std::option::IntoIter<&'a L> where Self: 'a;
+
+	fn literals_of<'a>(&'a self, id: &'a Self::Resource) -> Self::Literals<'a> {
+		match id {
+			Term::Literal(l) => Some(l).into_iter(),
+			_ => None.into_iter(),
+		}
+	}
+}
+
 impl Interpretation for () {
 	type Resource = Term;
 }
@@ -99,3 +202,44 @@ impl ReverseLiteralInterpretation for () {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use static_iref::iri;
+
+	use super::SelfInterpretation;
+	use crate::{
+		interpretation::{IriInterpretationMut, ReverseIriInterpretation, TermInterpretation},
+		vocabulary::{BlankIdIndex, IndexVocabulary, IriIndex, IriVocabularyMut, LiteralIndex},
+		Id, Term,
+	};
+
+	#[test]
+	fn lexical_terms() {
+		let mut interpretation: SelfInterpretation = SelfInterpretation::new();
+		let iri = iri!("http://example.org/").to_owned();
+		let resource = interpretation.interpret_iri(iri.clone());
+
+		let expected: Term = Term::Id(Id::Iri(iri.clone()));
+		assert_eq!(resource, expected);
+		assert_eq!(
+			interpretation.term_interpretation(&resource),
+			Some(expected)
+		);
+		assert_eq!(interpretation.iris_of(&resource).next(), Some(&iri));
+	}
+
+	#[test]
+	fn vocabulary_terms() {
+		let mut vocabulary: IndexVocabulary = IndexVocabulary::new();
+		let iri = vocabulary.insert(iri!("http://example.org/"));
+
+		let mut interpretation: SelfInterpretation<IriIndex, BlankIdIndex, LiteralIndex> =
+			SelfInterpretation::new();
+		let resource =
+			interpretation.interpret_lexical_iri(&mut vocabulary, iri!("http://example.org/"));
+
+		assert_eq!(resource.as_id().and_then(Id::as_iri), Some(&iri));
+		assert_eq!(interpretation.iris_of(&resource).next(), Some(&iri));
+	}
+}
@@ -0,0 +1,195 @@
+use std::borrow::Borrow;
+
+use iref::{Iri, IriBuf};
+
+use crate::{
+	interpretation::{
+		BlankIdInterpretation, BlankIdInterpretationMut, IriInterpretation, IriInterpretationMut,
+		LiteralInterpretation, LiteralInterpretationMut,
+	},
+	Interpretation,
+};
+
+/// Interpretation restricted to the IRIs under a given prefix.
+///
+/// IRIs under the prefix are interpreted by the inner interpretation, while
+/// every other IRI is left uninterpreted. Blank node identifiers and literals
+/// are always interpreted by the inner interpretation.
+///
+/// An IRI is under the prefix if it has the same scheme and authority, and
+/// if the path of the prefix is made of the first segments of its path. For
+/// instance `http://example.org/a/b` is under the prefix `http://example.org/a`
+/// while `http://example.org/abc` is not. If the prefix has a query or
+/// fragment, the IRI must have the same path, query and fragment.
+pub struct PrefixScopedInterpretation<I> {
+	interpretation: I,
+	prefix: IriBuf,
+}
+
+impl<I> PrefixScopedInterpretation<I> {
+	/// Restricts the given interpretation to the IRIs under `prefix`.
+	pub fn new(interpretation: I, prefix: IriBuf) -> Self {
+		Self {
+			interpretation,
+			prefix,
+		}
+	}
+
+	/// Returns the prefix of the interpreted IRIs.
+	pub fn prefix(&self) -> &Iri {
+		&self.prefix
+	}
+
+	/// Returns a reference to the inner interpretation.
+	pub fn inner_interpretation(&self) -> &I {
+		&self.interpretation
+	}
+
+	/// Returns a mutable reference to the inner interpretation.
+	///
+	/// Anything interpreted directly through this reference bypasses the
+	/// prefix restriction.
+	pub fn inner_interpretation_mut(&mut self) -> &mut I {
+		&mut self.interpretation
+	}
+
+	/// Removes the prefix restriction and returns the inner interpretation.
+	pub fn into_inner_interpretation(self) -> I {
+		self.interpretation
+	}
+
+	/// Checks if the given IRI is under the prefix.
+	pub fn in_scope(&self, iri: &Iri) -> bool {
+		let prefix = self.prefix.as_iri();
+
+		if prefix.scheme().as_str() != iri.scheme().as_str()
+			|| prefix.authority() != iri.authority()
+		{
+			return false;
+		}
+
+		let (prefix_path, path) = (prefix.path().as_str(), iri.path().as_str());
+
+		if prefix.query().is_some() || prefix.fragment().is_some() {
+			prefix_path == path
+				&& prefix.query().map(|q| q.as_str()) == iri.query().map(|q| q.as_str())
+				&& (prefix.fragment().is_none()
+					|| prefix.fragment().map(|f| f.as_str()) == iri.fragment().map(|f| f.as_str()))
+		} else {
+			match path.strip_prefix(prefix_path) {
+				Some(rest) => {
+					prefix_path.is_empty()
+						|| prefix_path.ends_with('/')
+						|| rest.is_empty() || rest.starts_with('/')
+				}
+				None => false,
+			}
+		}
+	}
+
+	/// Interprets the given IRI if it is under the prefix.
+	///
+	/// Returns the IRI back, uninterpreted, if it is not under the prefix.
+	/// This is why this interpretation does not implement
+	/// [`IriInterpretationMut`], which must interpret every IRI.
+	pub fn try_interpret_iri<J: Borrow<Iri>>(&mut self, iri: J) -> Result<I::Resource, J>
+	where
+		I: IriInterpretationMut<J>,
+	{
+		if self.in_scope(iri.borrow()) {
+			Ok(self.interpretation.interpret_iri(iri))
+		} else {
+			Err(iri)
+		}
+	}
+}
+
+impl<I: Interpretation> Interpretation for PrefixScopedInterpretation<I> {
+	type Resource = I::Resource;
+}
+
+impl<J: Borrow<Iri>, I: IriInterpretation<J>> IriInterpretation<J>
+	for PrefixScopedInterpretation<I>
+{
+	fn iri_interpretation(&self, iri: &J) -> Option<Self::Resource> {
+		if self.in_scope(iri.borrow()) {
+			self.interpretation.iri_interpretation(iri)
+		} else {
+			None
+		}
+	}
+}
+
+impl<B, I: BlankIdInterpretation<B>> BlankIdInterpretation<B> for PrefixScopedInterpretation<I> {
+	fn blank_id_interpretation(&self, blank_id: &B) -> Option<Self::Resource> {
+		self.interpretation.blank_id_interpretation(blank_id)
+	}
+}
+
+impl<B, I: BlankIdInterpretationMut<B>> BlankIdInterpretationMut<B>
+	for PrefixScopedInterpretation<I>
+{
+	fn interpret_blank_id(&mut self, blank_id: B) -> Self::Resource {
+		self.interpretation.interpret_blank_id(blank_id)
+	}
+}
+
+impl<L, I: LiteralInterpretation<L>> LiteralInterpretation<L> for PrefixScopedInterpretation<I> {
+	fn literal_interpretation(&self, literal: &L) -> Option<Self::Resource> {
+		self.interpretation.literal_interpretation(literal)
+	}
+}
+
+impl<L, I: LiteralInterpretationMut<L>> LiteralInterpretationMut<L>
+	for PrefixScopedInterpretation<I>
+{
+	fn interpret_literal(&mut self, literal: L) -> Self::Resource {
+		self.interpretation.interpret_literal(literal)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use iref::IriBuf;
+	use static_iref::iri;
+
+	use super::PrefixScopedInterpretation;
+	use crate::{
+		interpretation::{IriInterpretation, SelfInterpretation},
+		Id, Term,
+	};
+
+	#[test]
+	fn scope() {
+		let mut interpretation = PrefixScopedInterpretation::new(
+			SelfInterpretation::<IriBuf>::new(),
+			iri!("http://example.org/a").to_owned(),
+		);
+
+		for iri in [
+			"http://example.org/a",
+			"http://example.org/a/b",
+			"http://example.org/a?q",
+			"http://example.org/a#f",
+		] {
+			let iri = IriBuf::new(iri.to_owned()).unwrap();
+			let expected: Term = Term::Id(Id::Iri(iri.clone()));
+			assert_eq!(
+				interpretation.iri_interpretation(&iri),
+				Some(expected.clone())
+			);
+			assert_eq!(interpretation.try_interpret_iri(iri), Ok(expected))
+		}
+
+		for iri in [
+			"http://example.org/abc",
+			"http://example.org/",
+			"https://example.org/a",
+			"http://example.com/a",
+		] {
+			let iri = IriBuf::new(iri.to_owned()).unwrap();
+			assert_eq!(interpretation.iri_interpretation(&iri), None);
+			assert_eq!(interpretation.try_interpret_iri(iri.clone()), Err(iri))
+		}
+	}
+}
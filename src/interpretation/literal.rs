@@ -74,13 +74,18 @@ pub trait LiteralInterpretationMut<L = Literal>: Interpretation {
 		vocabulary: &mut (impl IriVocabularyMut + LiteralVocabularyMut<Literal = L>),
 		literal: Literal,
 	) -> Self::Resource {
-		let (value, type_) = literal.into_parts();
+		let (value, type_) = literal.into_literal_parts();
 		let type_ = match type_ {
 			literal::LiteralType::Any(ty) => literal::LiteralType::Any(vocabulary.insert_owned(ty)),
 			literal::LiteralType::LangString(tag) => literal::LiteralType::LangString(tag),
+			literal::LiteralType::DirLangString(tag, d) => {
+				literal::LiteralType::DirLangString(tag, d)
+			}
 		};
 
-		self.interpret_literal(vocabulary.insert_owned_literal(Literal::new(value, type_)))
+		self.interpret_literal(
+			vocabulary.insert_owned_literal(Literal::from_literal_value(value, type_)),
+		)
 	}
 }
 
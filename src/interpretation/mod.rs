@@ -74,6 +74,25 @@ impl<'t, V, T: InterpretationMut<V>> InterpretationMut<V> for &'t mut T {
 	}
 }
 
+/// Interpretation in which resources can be merged.
+///
+/// Merging is typically used when two resources are discovered to be the
+/// same (e.g. through `owl:sameAs`).
+pub trait MergeableInterpretation: Interpretation {
+	/// Merges the two given resources into one, and returns it.
+	///
+	/// Every term interpreted as either `a` or `b` is then interpreted as the
+	/// returned resource, and reverse interpretation functions of either `a`
+	/// or `b` return the terms of both.
+	fn merge(&mut self, a: Self::Resource, b: Self::Resource) -> Self::Resource;
+}
+
+impl<T: MergeableInterpretation> MergeableInterpretation for &mut T {
+	fn merge(&mut self, a: Self::Resource, b: Self::Resource) -> Self::Resource {
+		T::merge(*self, a, b)
+	}
+}
+
 pub type UninterpretedIdRef<'a, I> =
 	Id<&'a <I as ReverseIriInterpretation>::Iri, &'a <I as ReverseBlankIdInterpretation>::BlankId>;
 
@@ -1,8 +1,11 @@
+use std::cmp::Ordering;
+
 use iref::Iri;
 
 use crate::{
-	vocabulary::{BlankIdVocabulary, IriVocabulary},
-	BlankId, Generator, Id, Literal, LiteralRef, Quad, Term, Vocabulary, VocabularyMut,
+	vocabulary::{BlankIdVocabulary, IriVocabulary, LiteralVocabulary},
+	BlankId, Generator, Id, Literal, LiteralRef, LiteralTypeRef, Quad, Term, Vocabulary,
+	VocabularyMut,
 };
 
 use super::{
@@ -34,6 +37,66 @@ pub trait TermInterpretation<I, B, L = Literal>:
 			Term::Literal(l) => self.lexical_literal_interpretation(vocabulary, l),
 		}
 	}
+
+	/// Returns the interpretation of each given lexical term, if any.
+	///
+	/// The returned iterator yields one item per input term, in order, so
+	/// that a whole set of terms (such as the constants of a query) can be
+	/// resolved in a single call. The terms are first looked up in the
+	/// vocabulary all at once, using [`IriVocabulary::get_iris`],
+	/// [`BlankIdVocabulary::get_blank_ids`] and
+	/// [`LiteralVocabulary::get_literals`], so that vocabularies can batch
+	/// these lookups.
+	fn lexical_terms_interpretation<'t, V, J>(
+		&self,
+		vocabulary: &V,
+		terms: J,
+	) -> LexicalTermsInterpretation<'_, Self, I, B, L>
+	where
+		Self: Sized,
+		I: 't,
+		V: Vocabulary<Iri = I, BlankId = B, Literal = L>,
+		J: IntoIterator<Item = Term<Id<&'t Iri, &'t BlankId>, LiteralRef<'t, I>>>,
+	{
+		let terms: Vec<_> = terms.into_iter().collect();
+
+		let mut iris = vocabulary
+			.get_iris(terms.iter().filter_map(|term| match term {
+				Term::Id(Id::Iri(iri)) => Some(*iri),
+				_ => None,
+			}))
+			.into_iter();
+
+		let mut blank_ids = vocabulary
+			.get_blank_ids(terms.iter().filter_map(|term| match term {
+				Term::Id(Id::Blank(blank_id)) => Some(*blank_id),
+				_ => None,
+			}))
+			.into_iter();
+
+		let mut literals = vocabulary
+			.get_literals(terms.iter().filter_map(|term| match term {
+				Term::Literal(literal) => Some(*literal),
+				_ => None,
+			}))
+			.into_iter();
+
+		let terms: Vec<_> = terms
+			.iter()
+			.map(|term| match term {
+				Term::Id(Id::Iri(_)) => iris.next().flatten().map(|i| Term::Id(Id::Iri(i))),
+				Term::Id(Id::Blank(_)) => {
+					blank_ids.next().flatten().map(|b| Term::Id(Id::Blank(b)))
+				}
+				Term::Literal(_) => literals.next().flatten().map(Term::Literal),
+			})
+			.collect();
+
+		LexicalTermsInterpretation {
+			interpretation: self,
+			terms: terms.into_iter(),
+		}
+	}
 }
 
 impl<I, B, L, T: IdInterpretation<I, B> + LiteralInterpretation<L>> TermInterpretation<I, B, L>
@@ -41,6 +104,35 @@ impl<I, B, L, T: IdInterpretation<I, B> + LiteralInterpretation<L>> TermInterpre
 {
 }
 
+/// Iterator over the interpretations of lexical terms.
+///
+/// See [`TermInterpretation::lexical_terms_interpretation`].
+pub struct LexicalTermsInterpretation<'a, T, I, B, L> {
+	interpretation: &'a T,
+	terms: std::vec::IntoIter<Option<Term<Id<I, B>, L>>>,
+}
+
+impl<'a, I, B, L, T: TermInterpretation<I, B, L>> Iterator
+	for LexicalTermsInterpretation<'a, T, I, B, L>
+{
+	type Item = Option<T::Resource>;
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.terms.size_hint()
+	}
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.terms
+			.next()
+			.map(|term| term.and_then(|term| self.interpretation.term_interpretation(&term)))
+	}
+}
+
+impl<'a, I, B, L, T: TermInterpretation<I, B, L>> ExactSizeIterator
+	for LexicalTermsInterpretation<'a, T, I, B, L>
+{
+}
+
 pub trait TermInterpretationMut<I, B, L = Literal>:
 	IdInterpretationMut<I, B> + LiteralInterpretationMut<L>
 {
@@ -113,6 +205,36 @@ pub trait ReverseTermInterpretation:
 		self.term_of(id).is_some()
 	}
 
+	/// Returns the preferred term of the given resource, if any.
+	///
+	/// Contrarily to [`Self::term_of`], the result does not depend on the
+	/// order in which the interpretation stores terms, nor on the order in
+	/// which the vocabulary interned them. Terms are first ranked by kind
+	/// according to the given `preference`, then ties are broken by comparing
+	/// their lexical representation resolved using the given `vocabulary`:
+	/// the shortest representation wins, then the lexicographically smallest.
+	/// Terms unknown to the vocabulary come last.
+	fn canonical_term<'a, V>(
+		&'a self,
+		vocabulary: &V,
+		id: &'a Self::Resource,
+		preference: TermPreference,
+	) -> Option<TermOf<'a, Self>>
+	where
+		V: LiteralVocabulary<Iri = Self::Iri, Literal = Self::Literal>
+			+ BlankIdVocabulary<BlankId = Self::BlankId>,
+		Self::Iri: Ord,
+		Self::BlankId: Ord,
+		Self::Literal: Ord,
+	{
+		self.terms_of(id).min_by(|a, b| {
+			preference
+				.rank(a)
+				.cmp(&preference.rank(b))
+				.then_with(|| cmp_lexical_terms(vocabulary, a, b))
+		})
+	}
+
 	fn quads_of<'a>(
 		&'a self,
 		quad: Quad<&'a Self::Resource, &'a Self::Resource, &'a Self::Resource, &'a Self::Resource>,
@@ -126,6 +248,30 @@ pub trait ReverseTermInterpretation:
 		}
 	}
 
+	/// Returns an iterator over all the quads of terms interpreted as the
+	/// given resources, tolerating positions without valid term.
+	///
+	/// Contrarily to [`Self::quads_of`], which yields nothing if any
+	/// position has no valid term (for instance if the predicate resource
+	/// has no IRI), a position without valid term is filled with `None`
+	/// while the other positions still range over all their terms. The graph
+	/// component is `None` for the default graph, and `Some(None)` for a
+	/// named graph resource without identifier.
+	///
+	/// This is mostly useful for debugging.
+	fn quads_of_lossy<'a>(
+		&'a self,
+		quad: Quad<&'a Self::Resource, &'a Self::Resource, &'a Self::Resource, &'a Self::Resource>,
+	) -> QuadsOfLossy<'a, Self> {
+		QuadsOfLossy {
+			s: or_none(self.ids_of(quad.0)),
+			p: or_none(self.iris_of(quad.1)),
+			o: or_none(self.terms_of(quad.2)),
+			g: quad.3.map(|g| or_none(self.ids_of(g))),
+			i: 0,
+		}
+	}
+
 	fn grdf_quads_of<'a>(
 		&'a self,
 		quad: Quad<&'a Self::Resource, &'a Self::Resource, &'a Self::Resource, &'a Self::Resource>,
@@ -140,6 +286,107 @@ pub trait ReverseTermInterpretation:
 	}
 }
 
+/// Term kind preference.
+///
+/// Used by [`ReverseTermInterpretation::canonical_term`] to select a single
+/// term among all the terms of a resource.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TermPreference {
+	/// Prefer IRIs, then literals, then blank node identifiers.
+	#[default]
+	Iri,
+
+	/// Prefer literals, then IRIs, then blank node identifiers.
+	Literal,
+
+	/// Prefer blank node identifiers, then IRIs, then literals.
+	BlankId,
+}
+
+impl TermPreference {
+	/// Returns the rank of the given term according to this preference.
+	///
+	/// Preferred terms have a lower rank.
+	pub fn rank<I, B, L>(&self, term: &Term<Id<I, B>, L>) -> u8 {
+		match (self, term) {
+			(Self::Iri, Term::Id(Id::Iri(_))) => 0,
+			(Self::Iri, Term::Literal(_)) => 1,
+			(Self::Iri, Term::Id(Id::Blank(_))) => 2,
+			(Self::Literal, Term::Literal(_)) => 0,
+			(Self::Literal, Term::Id(Id::Iri(_))) => 1,
+			(Self::Literal, Term::Id(Id::Blank(_))) => 2,
+			(Self::BlankId, Term::Id(Id::Blank(_))) => 0,
+			(Self::BlankId, Term::Id(Id::Iri(_))) => 1,
+			(Self::BlankId, Term::Literal(_)) => 2,
+		}
+	}
+}
+
+/// Compares two terms by their lexical representation, resolved using the
+/// given vocabulary.
+///
+/// Shorter representations come first, and representations of the same
+/// length are compared lexicographically. Terms unknown to the vocabulary are
+/// ordered after all the known terms, and compared using their own order.
+fn cmp_lexical_terms<V>(
+	vocabulary: &V,
+	a: &Term<Id<&V::Iri, &V::BlankId>, &V::Literal>,
+	b: &Term<Id<&V::Iri, &V::BlankId>, &V::Literal>,
+) -> Ordering
+where
+	V: LiteralVocabulary + BlankIdVocabulary,
+	V::Iri: Ord,
+	V::BlankId: Ord,
+	V::Literal: Ord,
+{
+	match (a, b) {
+		(Term::Id(Id::Iri(a)), Term::Id(Id::Iri(b))) => {
+			cmp_resolved(*a, *b, |i| vocabulary.iri(i).map(Iri::as_str))
+		}
+		(Term::Id(Id::Blank(a)), Term::Id(Id::Blank(b))) => {
+			cmp_resolved(*a, *b, |b| vocabulary.blank_id(b).map(BlankId::as_str))
+		}
+		(Term::Literal(a), Term::Literal(b)) => {
+			match (vocabulary.literal(*a), vocabulary.literal(*b)) {
+				(Some(la), Some(lb)) => {
+					cmp_shortlex(la.value, lb.value).then_with(|| match (la.type_, lb.type_) {
+						(LiteralTypeRef::Any(a), LiteralTypeRef::Any(b)) => {
+							cmp_resolved(a, b, |i| vocabulary.iri(i).map(Iri::as_str))
+						}
+						(LiteralTypeRef::Any(_), _) => Ordering::Less,
+						(_, LiteralTypeRef::Any(_)) => Ordering::Greater,
+						(a, b) => (a.lang_tag(), a.direction()).cmp(&(b.lang_tag(), b.direction())),
+					})
+				}
+				(Some(_), None) => Ordering::Less,
+				(None, Some(_)) => Ordering::Greater,
+				(None, None) => a.cmp(b),
+			}
+		}
+		(a, b) => a.cmp(b),
+	}
+}
+
+/// Compares two identifiers by their resolved lexical representation,
+/// putting unresolved identifiers last.
+fn cmp_resolved<'a, T: Ord>(
+	a: &'a T,
+	b: &'a T,
+	resolve: impl Fn(&'a T) -> Option<&'a str>,
+) -> Ordering {
+	match (resolve(a), resolve(b)) {
+		(Some(a), Some(b)) => cmp_shortlex(a, b),
+		(Some(_), None) => Ordering::Less,
+		(None, Some(_)) => Ordering::Greater,
+		(None, None) => a.cmp(b),
+	}
+}
+
+/// Compares two strings by length, then lexicographically.
+fn cmp_shortlex(a: &str, b: &str) -> Ordering {
+	a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
 pub struct QuadsOf<'a, I: ?Sized + ReverseTermInterpretation> {
 	s: IdsOf<'a, I>,
 	p: I::Iris<'a>,
@@ -282,6 +529,53 @@ impl<'a, I: ?Sized + ReverseTermInterpretation> Iterator for GsOf<'a, I> {
 	}
 }
 
+/// Collects the given iterator items, or a single `None` if it is empty.
+fn or_none<T>(items: impl Iterator<Item = T>) -> Vec<Option<T>> {
+	let mut result: Vec<_> = items.map(Some).collect();
+	if result.is_empty() {
+		result.push(None)
+	}
+
+	result
+}
+
+type IdOf<'a, I> =
+	Id<&'a <I as ReverseIriInterpretation>::Iri, &'a <I as ReverseBlankIdInterpretation>::BlankId>;
+
+/// Iterator returned by [`ReverseTermInterpretation::quads_of_lossy`].
+pub struct QuadsOfLossy<'a, I: ?Sized + ReverseTermInterpretation> {
+	s: Vec<Option<IdOf<'a, I>>>,
+	p: Vec<Option<&'a I::Iri>>,
+	o: Vec<Option<TermOf<'a, I>>>,
+	g: Option<Vec<Option<IdOf<'a, I>>>>,
+	i: usize,
+}
+
+impl<'a, I: ?Sized + ReverseTermInterpretation> Iterator for QuadsOfLossy<'a, I> {
+	type Item =
+		Quad<Option<IdOf<'a, I>>, Option<&'a I::Iri>, Option<TermOf<'a, I>>, Option<IdOf<'a, I>>>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let g_len = self.g.as_ref().map(Vec::len).unwrap_or(1);
+		if self.i >= self.s.len() * self.p.len() * self.o.len() * g_len {
+			return None;
+		}
+
+		let mut i = self.i;
+		self.i += 1;
+
+		let g = self.g.as_ref().map(|g| g[i % g_len]);
+		i /= g_len;
+		let o = self.o[i % self.o.len()];
+		i /= self.o.len();
+		let p = self.p[i % self.p.len()];
+		i /= self.p.len();
+		let s = self.s[i];
+
+		Some(Quad(s, p, o, g))
+	}
+}
+
 pub struct GrdfQuadsOf<'a, I: ?Sized + ReverseTermInterpretation> {
 	s: TermsOf<'a, I>,
 	p: TermsOf<'a, I>,
@@ -455,6 +749,37 @@ pub trait ReverseTermInterpretationMut:
 		}
 	}
 
+	/// Assigns terms in bulk, from quads pairing each resource with one of
+	/// its terms.
+	///
+	/// Assignments add to the terms already assigned to a resource, they
+	/// never replace them. The same resource may appear multiple times, with
+	/// the same or different terms.
+	///
+	/// Returns the number of terms that were not already assigned.
+	#[allow(clippy::type_complexity)]
+	fn assign_from_quads(
+		&mut self,
+		quads: impl IntoIterator<
+			Item = Quad<(
+				Self::Resource,
+				Term<Id<Self::Iri, Self::BlankId>, Self::Literal>,
+			)>,
+		>,
+	) -> usize {
+		let mut count = 0;
+
+		for Quad(s, p, o, g) in quads {
+			for (resource, term) in [Some(s), Some(p), Some(o), g].into_iter().flatten() {
+				if self.assign_term(&resource, term) {
+					count += 1
+				}
+			}
+		}
+
+		count
+	}
+
 	/// Generates and assign a node identifier for all the resources that don't
 	/// have any term, using the given generator.
 	fn generate_ids<V: IriVocabulary + BlankIdVocabulary>(
@@ -508,3 +833,128 @@ impl<'a, I: 'a + ?Sized + ReverseTermInterpretation> Iterator for TermsOf<'a, I>
 			.or_else(|| self.literals.next().map(Term::Literal))
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use static_iref::iri;
+
+	use super::{ReverseTermInterpretation, TermInterpretation, TermPreference};
+	use crate::{
+		interpretation::{
+			Indexed, IriInterpretationMut, ReverseBlankIdInterpretationMut,
+			ReverseIriInterpretationMut, ReverseLiteralInterpretationMut, SelfInterpretation,
+		},
+		vocabulary::{
+			BlankIdIndex, BlankIdVocabularyMut, IndexVocabulary, IriIndex, IriVocabularyMut,
+			LiteralIndex, LiteralVocabularyMut,
+		},
+		BlankId, Id, Literal, LiteralRef, LiteralType, LiteralTypeRef, Term,
+	};
+
+	#[test]
+	fn lexical_terms_interpretation() {
+		let mut vocabulary: IndexVocabulary = IndexVocabulary::new();
+		let a = vocabulary.insert(iri!("http://example.org/a"));
+		let b = vocabulary.insert_blank_id(BlankId::new("_:b").unwrap());
+		let ty = vocabulary.insert(iri!("http://example.org/datatype"));
+		let l = vocabulary.insert_owned_literal(Literal::new("value", LiteralType::Any(ty)));
+
+		let interpretation = SelfInterpretation::<IriIndex, BlankIdIndex, LiteralIndex>::new();
+		let terms = [
+			Term::Id(Id::Iri(iri!("http://example.org/a"))),
+			Term::Literal(LiteralRef::new("value", LiteralTypeRef::Any(&ty))),
+			Term::Id(Id::Iri(iri!("http://example.org/missing"))),
+			Term::Id(Id::Blank(BlankId::new("_:b").unwrap())),
+			Term::Literal(LiteralRef::new("other", LiteralTypeRef::Any(&ty))),
+			Term::Id(Id::Blank(BlankId::new("_:missing").unwrap())),
+		];
+
+		let resources: Vec<_> = interpretation
+			.lexical_terms_interpretation(&vocabulary, terms)
+			.collect();
+		assert_eq!(
+			resources,
+			[
+				Some(Term::Id(Id::Iri(a))),
+				Some(Term::Literal(l)),
+				None,
+				Some(Term::Id(Id::Blank(b))),
+				None,
+				None
+			]
+		);
+
+		for (term, resource) in terms.into_iter().zip(resources) {
+			assert_eq!(
+				interpretation.lexical_term_interpretation(&vocabulary, term),
+				resource
+			)
+		}
+	}
+
+	#[test]
+	fn canonical_term_preference() {
+		let mut vocabulary: IndexVocabulary = IndexVocabulary::new();
+		let b = vocabulary.insert_blank_id(BlankId::new("_:b").unwrap());
+		let ty = vocabulary.insert(iri!("http://example.org/datatype"));
+		let l = vocabulary.insert_owned_literal(Literal::new("value", LiteralType::Any(ty)));
+		let a = vocabulary.insert(iri!("http://example.org/a"));
+
+		let mut interpretation = Indexed::new();
+		let r = interpretation.interpret_iri(a);
+		interpretation.assign_blank_id(&r, b);
+		interpretation.assign_literal(&r, l);
+
+		assert_eq!(
+			interpretation.canonical_term(&vocabulary, &r, TermPreference::Iri),
+			Some(Term::Id(Id::Iri(&a)))
+		);
+		assert_eq!(
+			interpretation.canonical_term(&vocabulary, &r, TermPreference::Literal),
+			Some(Term::Literal(&l))
+		);
+		assert_eq!(
+			interpretation.canonical_term(&vocabulary, &r, TermPreference::BlankId),
+			Some(Term::Id(Id::Blank(&b)))
+		)
+	}
+
+	#[test]
+	fn canonical_term_tie_breaking() {
+		let mut vocabulary: IndexVocabulary = IndexVocabulary::new();
+		let long = vocabulary.insert(iri!("http://example.org/aa"));
+		let c = vocabulary.insert(iri!("http://example.org/c"));
+		let b = vocabulary.insert(iri!("http://example.org/b"));
+		let long_blank = vocabulary.insert_blank_id(BlankId::new("_:aa").unwrap());
+		let c_blank = vocabulary.insert_blank_id(BlankId::new("_:c").unwrap());
+		let b_blank = vocabulary.insert_blank_id(BlankId::new("_:b").unwrap());
+		let ty = vocabulary.insert(iri!("http://example.org/datatype"));
+		let ten = vocabulary.insert_owned_literal(Literal::new("10", LiteralType::Any(ty)));
+		let nine = vocabulary.insert_owned_literal(Literal::new("9", LiteralType::Any(ty)));
+		let eight = vocabulary.insert_owned_literal(Literal::new("8", LiteralType::Any(ty)));
+
+		let mut interpretation = Indexed::new();
+		let r = interpretation.interpret_iri(long);
+		interpretation.assign_iri(&r, c);
+		interpretation.assign_iri(&r, b);
+		interpretation.assign_blank_id(&r, long_blank);
+		interpretation.assign_blank_id(&r, c_blank);
+		interpretation.assign_blank_id(&r, b_blank);
+		interpretation.assign_literal(&r, ten);
+		interpretation.assign_literal(&r, nine);
+		interpretation.assign_literal(&r, eight);
+
+		assert_eq!(
+			interpretation.canonical_term(&vocabulary, &r, TermPreference::Iri),
+			Some(Term::Id(Id::Iri(&b)))
+		);
+		assert_eq!(
+			interpretation.canonical_term(&vocabulary, &r, TermPreference::BlankId),
+			Some(Term::Id(Id::Blank(&b_blank)))
+		);
+		assert_eq!(
+			interpretation.canonical_term(&vocabulary, &r, TermPreference::Literal),
+			Some(Term::Literal(&eight))
+		)
+	}
+}
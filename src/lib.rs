@@ -23,11 +23,16 @@ pub use iref;
 #[doc(hidden)]
 pub use static_iref;
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
 mod blankid;
 mod display;
 mod grdf;
 mod literal;
 mod r#macro;
+#[cfg(feature = "serde")]
+mod named_fields;
+mod pretty;
 mod quad;
 mod schema;
 mod term;
@@ -37,6 +42,9 @@ pub use blankid::*;
 pub use display::*;
 pub use grdf::*;
 pub use literal::*;
+#[cfg(feature = "serde")]
+pub use named_fields::*;
+pub use pretty::*;
 pub use quad::*;
 pub use schema::*;
 pub use term::*;
@@ -56,6 +64,9 @@ pub use iref::{Iri, IriBuf};
 pub use vocabulary::{Vocabulary, VocabularyMut};
 
 pub const XSD_STRING: &Iri = static_iref::iri!("http://www.w3.org/2001/XMLSchema#string");
+pub const XSD_BOOLEAN: &Iri = static_iref::iri!("http://www.w3.org/2001/XMLSchema#boolean");
+pub const XSD_INTEGER: &Iri = static_iref::iri!("http://www.w3.org/2001/XMLSchema#integer");
+pub const XSD_DOUBLE: &Iri = static_iref::iri!("http://www.w3.org/2001/XMLSchema#double");
 
 /// IRI type that may be <http://www.w3.org/2001/XMLSchema#string>.
 ///
@@ -77,3 +88,9 @@ impl IsXsdStringIri for Iri {
 		self == XSD_STRING
 	}
 }
+
+impl<T: IsXsdStringIri + ?Sized> IsXsdStringIri for std::sync::Arc<T> {
+	fn is_xsd_string_iri(&self) -> bool {
+		T::is_xsd_string_iri(self)
+	}
+}
@@ -0,0 +1,312 @@
+use std::{
+	borrow::{Borrow, Cow},
+	fmt::UpperExp,
+	str::FromStr,
+};
+
+use iref::{Iri, IriBuf};
+
+use crate::{Literal, LiteralType};
+
+use super::numeric::{Decimal, XSD};
+
+impl<I: Borrow<Iri>> Literal<I> {
+	/// Returns the canonical lexical form of this literal value, as defined
+	/// by XSD 1.1.
+	///
+	/// The following datatypes are recognized:
+	///   - `xsd:boolean`, whose canonical values are `true` and `false`;
+	///   - `xsd:integer` and its derived types (`xsd:long`,
+	///     `xsd:nonNegativeInteger`, etc.), without sign (unless negative)
+	///     nor leading zeros, such as `-12`;
+	///   - `xsd:decimal`, without leading or trailing zeros, and without
+	///     decimal point if the value is an integer, such as `1` or `-0.5`;
+	///   - `xsd:float` and `xsd:double`, in scientific notation with a single
+	///     non-zero digit before the decimal point, such as `1.0E1` or
+	///     `-2.5E-3`, or `INF`, `-INF` and `NaN`.
+	///
+	/// Leading and trailing whitespaces are ignored for these datatypes.
+	/// The value is returned unchanged if the datatype is not recognized
+	/// (including language strings), or if it is not a valid lexical form of
+	/// the datatype (for instance an `xsd:byte` greater than 127).
+	///
+	/// ```
+	/// use rdf_types::{Literal, LiteralType};
+	/// use static_iref::iri;
+	///
+	/// let integer = Literal::new("+01", LiteralType::Any(iri!("http://www.w3.org/2001/XMLSchema#integer").to_owned()));
+	/// assert_eq!(integer.canonical_lexical(), "1");
+	///
+	/// let double = Literal::new("1.0e1", LiteralType::Any(iri!("http://www.w3.org/2001/XMLSchema#double").to_owned()));
+	/// assert_eq!(double.canonical_lexical(), "1.0E1");
+	/// ```
+	pub fn canonical_lexical(&self) -> Cow<'_, str> {
+		let value: &str = &self.value;
+
+		let canonical = match &self.type_ {
+			LiteralType::Any(ty) => ty
+				.borrow()
+				.as_str()
+				.strip_prefix(XSD)
+				.and_then(|ty| canonical_lexical(ty, value).flatten()),
+			LiteralType::LangString(_) | LiteralType::DirLangString(..) => None,
+		};
+
+		match canonical {
+			Some(canonical) if canonical != value => Cow::Owned(canonical),
+			_ => Cow::Borrowed(value),
+		}
+	}
+}
+
+impl Literal {
+	/// Parses a typed literal, validating its lexical form.
+	///
+	/// The `value` is checked against the lexical space of the `datatype`,
+	/// for the datatypes supported by [`Literal::canonical_lexical`]. Any
+	/// value is accepted for other datatypes. The value is stored as is,
+	/// without being canonicalized.
+	///
+	/// ```
+	/// use rdf_types::Literal;
+	/// use static_iref::iri;
+	///
+	/// let integer = iri!("http://www.w3.org/2001/XMLSchema#integer");
+	/// assert!(Literal::parse_lexical("+12", integer).is_ok());
+	/// assert!(Literal::parse_lexical("abc", integer).is_err());
+	/// ```
+	pub fn parse_lexical(value: &str, datatype: &Iri) -> Result<Self, LexicalError> {
+		let valid = datatype
+			.as_str()
+			.strip_prefix(XSD)
+			.and_then(|ty| canonical_lexical(ty, value))
+			.map_or(true, |canonical| canonical.is_some());
+
+		if valid {
+			Ok(Self::new(
+				value.to_owned(),
+				LiteralType::Any(datatype.to_owned()),
+			))
+		} else {
+			Err(LexicalError {
+				value: value.to_owned(),
+				datatype: datatype.to_owned(),
+			})
+		}
+	}
+}
+
+/// Invalid lexical form.
+///
+/// Error returned by [`Literal::parse_lexical`].
+#[derive(Debug, thiserror::Error)]
+#[error("invalid lexical form `{value}` for datatype <{datatype}>")]
+pub struct LexicalError {
+	/// Invalid value.
+	pub value: String,
+
+	/// Datatype.
+	pub datatype: IriBuf,
+}
+
+/// Returns the canonical lexical form of `value` for the given XSD datatype
+/// name, or `None` if the datatype is not recognized.
+///
+/// The inner value is `None` if `value` is not a valid lexical form of the
+/// datatype.
+fn canonical_lexical(ty: &str, value: &str) -> Option<Option<String>> {
+	let value = value.trim_matches(|c: char| matches!(c, ' ' | '\t' | '\n' | '\r'));
+	let canonical = match ty {
+		"boolean" => match value {
+			"true" | "1" => Some("true".to_owned()),
+			"false" | "0" => Some("false".to_owned()),
+			_ => None,
+		},
+		"decimal" => Decimal::parse(value).map(|d| d.canonical()),
+		"integer" | "nonPositiveInteger" | "negativeInteger" | "long" | "int" | "short"
+		| "byte" | "nonNegativeInteger" | "unsignedLong" | "unsignedInt" | "unsignedShort"
+		| "unsignedByte" | "positiveInteger" => canonical_integer(ty, value),
+		"float" => canonical_floating_point::<f32>(value),
+		"double" => canonical_floating_point::<f64>(value),
+		_ => return None,
+	};
+
+	Some(canonical)
+}
+
+/// Returns the canonical lexical form of an `xsd:integer` or of one of its
+/// derived types.
+fn canonical_integer(ty: &str, value: &str) -> Option<String> {
+	if value.contains('.') {
+		return None;
+	}
+
+	let canonical = Decimal::parse(value)?.canonical();
+	integer_in_range(ty, &canonical).then_some(canonical)
+}
+
+/// Checks that the given canonical integer is in the value space of the
+/// given integer datatype.
+fn integer_in_range(ty: &str, canonical: &str) -> bool {
+	let negative = canonical.starts_with('-');
+	let zero = canonical == "0";
+
+	let bounds = match ty {
+		"nonPositiveInteger" => return negative || zero,
+		"negativeInteger" => return negative,
+		"nonNegativeInteger" | "unsignedLong" | "unsignedInt" | "unsignedShort"
+		| "unsignedByte"
+			if negative =>
+		{
+			return false
+		}
+		"nonNegativeInteger" => return true,
+		"positiveInteger" => return !negative && !zero,
+		"long" => (i64::MIN as i128, i64::MAX as i128),
+		"int" => (i32::MIN as i128, i32::MAX as i128),
+		"short" => (i16::MIN as i128, i16::MAX as i128),
+		"byte" => (i8::MIN as i128, i8::MAX as i128),
+		"unsignedLong" => (0, u64::MAX as i128),
+		"unsignedInt" => (0, u32::MAX as i128),
+		"unsignedShort" => (0, u16::MAX as i128),
+		"unsignedByte" => (0, u8::MAX as i128),
+		_ => return true,
+	};
+
+	canonical
+		.parse::<i128>()
+		.is_ok_and(|i| bounds.0 <= i && i <= bounds.1)
+}
+
+/// Returns the canonical lexical form of an `xsd:float` or `xsd:double`.
+fn canonical_floating_point<F>(value: &str) -> Option<String>
+where
+	F: FromStr + UpperExp + Into<f64> + Copy,
+{
+	match value {
+		"INF" | "+INF" => Some("INF".to_owned()),
+		"-INF" => Some("-INF".to_owned()),
+		"NaN" => Some("NaN".to_owned()),
+		_ if value
+			.bytes()
+			.all(|c| c.is_ascii_digit() || matches!(c, b'+' | b'-' | b'.' | b'e' | b'E')) =>
+		{
+			let f: F = value.parse().ok()?;
+			let float: f64 = f.into();
+
+			if float.is_infinite() {
+				return Some(if float < 0.0 { "-INF" } else { "INF" }.to_owned());
+			}
+
+			// Rust omits the decimal point when the mantissa is an integer.
+			let formatted = format!("{f:E}");
+			let (mantissa, exponent) = formatted.split_once('E')?;
+			if mantissa.contains('.') {
+				Some(formatted)
+			} else {
+				Some(format!("{mantissa}.0E{exponent}"))
+			}
+		}
+		_ => None,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use iref::IriBuf;
+
+	use crate::{Literal, LiteralType};
+
+	fn typed(value: &str, ty: &str) -> Literal {
+		Literal::new(
+			value.to_owned(),
+			LiteralType::Any(
+				IriBuf::new(format!("http://www.w3.org/2001/XMLSchema#{ty}")).unwrap(),
+			),
+		)
+	}
+
+	fn canonical(value: &str, ty: &str) -> String {
+		typed(value, ty).canonical_lexical().into_owned()
+	}
+
+	#[test]
+	fn booleans() {
+		assert_eq!(canonical("true", "boolean"), "true");
+		assert_eq!(canonical("1", "boolean"), "true");
+		assert_eq!(canonical(" 0 ", "boolean"), "false");
+		assert_eq!(canonical("yes", "boolean"), "yes");
+	}
+
+	#[test]
+	fn integers() {
+		assert_eq!(canonical("+1", "integer"), "1");
+		assert_eq!(canonical("-007", "integer"), "-7");
+		assert_eq!(canonical("-0", "integer"), "0");
+		assert_eq!(canonical("1.0", "integer"), "1.0");
+		assert_eq!(canonical("0127", "byte"), "127");
+		assert_eq!(canonical("128", "byte"), "128");
+		assert_eq!(canonical("-1", "unsignedInt"), "-1");
+		assert_eq!(canonical("+0", "nonPositiveInteger"), "0");
+		assert_eq!(canonical("0", "positiveInteger"), "0");
+		assert_eq!(canonical("-00", "negativeInteger"), "-00");
+	}
+
+	#[test]
+	fn decimals() {
+		assert_eq!(canonical("1.0", "decimal"), "1");
+		assert_eq!(canonical("+01.50", "decimal"), "1.5");
+		assert_eq!(canonical("-.5", "decimal"), "-0.5");
+		assert_eq!(canonical("-0.0", "decimal"), "0");
+		assert_eq!(canonical("1e1", "decimal"), "1e1");
+	}
+
+	#[test]
+	fn floating_points() {
+		assert_eq!(canonical("1.0e1", "double"), "1.0E1");
+		assert_eq!(canonical("10", "double"), "1.0E1");
+		assert_eq!(canonical("-0.0025", "double"), "-2.5E-3");
+		assert_eq!(canonical("0", "double"), "0.0E0");
+		assert_eq!(canonical("-0", "double"), "-0.0E0");
+		assert_eq!(canonical("+INF", "double"), "INF");
+		assert_eq!(canonical("1e400", "double"), "INF");
+		assert_eq!(canonical("NaN", "double"), "NaN");
+		assert_eq!(canonical("inf", "double"), "inf");
+		assert_eq!(canonical("0.1", "float"), "1.0E-1");
+		assert_eq!(canonical("1e39", "float"), "INF");
+	}
+
+	#[test]
+	fn parse_lexical() {
+		let ty = |ty: &str| IriBuf::new(format!("http://www.w3.org/2001/XMLSchema#{ty}")).unwrap();
+
+		let literal = Literal::parse_lexical(" 012 ", &ty("byte")).unwrap();
+		assert_eq!(literal.value, " 012 ");
+		assert!(Literal::parse_lexical("abc", &ty("integer")).is_err());
+		assert!(Literal::parse_lexical("1.5", &ty("long")).is_err());
+		assert!(Literal::parse_lexical("-1", &ty("unsignedByte")).is_err());
+		assert!(Literal::parse_lexical("maybe", &ty("boolean")).is_err());
+		assert!(Literal::parse_lexical("1e", &ty("double")).is_err());
+		assert!(Literal::parse_lexical("abc", &ty("string")).is_ok());
+		assert!(Literal::parse_lexical("abc", &ty("date")).is_ok());
+		assert!(Literal::parse_lexical(
+			"abc",
+			&IriBuf::new("http://example.org/".to_owned()).unwrap()
+		)
+		.is_ok())
+	}
+
+	#[test]
+	fn unrecognized() {
+		assert_eq!(canonical(" 01 ", "string"), " 01 ");
+		assert_eq!(canonical("2020-01-01Z", "date"), "2020-01-01Z");
+		assert_eq!(
+			Literal::new(
+				"01",
+				LiteralType::<IriBuf>::LangString("en".parse().unwrap())
+			)
+			.canonical_lexical(),
+			"01"
+		);
+	}
+}
@@ -2,12 +2,14 @@ use crate::vocabulary::{
 	EmbedIntoVocabulary, EmbeddedIntoVocabulary, ExtractFromVocabulary, ExtractedFromVocabulary,
 	IriVocabulary, IriVocabularyMut, LiteralVocabularyMut,
 };
-use crate::{IsXsdStringIri, RdfDisplay};
+use crate::{IsXsdStringIri, RdfDisplay, XSD_BOOLEAN, XSD_INTEGER, XSD_STRING};
 use educe::Educe;
-use iref::IriBuf;
+use indexmap::Equivalent;
+use iref::{Iri, IriBuf};
 use langtag::LangTag;
-use std::borrow::Borrow;
-use std::fmt;
+use std::borrow::{Borrow, Cow};
+use std::sync::Arc;
+use std::{fmt, io};
 
 #[cfg(feature = "contextual")]
 use contextual::DisplayWithContext;
@@ -15,19 +17,82 @@ use contextual::DisplayWithContext;
 mod r#type;
 pub use r#type::*;
 
+mod canonical;
+pub use canonical::*;
+
+mod numeric;
+pub use numeric::*;
+
+mod value;
+
+mod visit;
+pub use visit::*;
+
+/// Backing type of literal values.
+///
+/// This is [`String`] by default. If the `smol_str` feature is enabled, this
+/// is [`SmolStr`](smol_str::SmolStr) instead, which stores short values inline
+/// without allocating.
+///
+/// This type never appears in the [`Literal`] API, which only exposes
+/// [`String`] and [`str`] values whatever the enabled features.
+#[cfg(not(feature = "smol_str"))]
+pub type LiteralValue = String;
+
+/// Backing type of literal values.
+///
+/// This is [`String`] by default. If the `smol_str` feature is enabled, this
+/// is [`SmolStr`](smol_str::SmolStr) instead, which stores short values inline
+/// without allocating.
+///
+/// This type never appears in the [`Literal`] API, which only exposes
+/// [`String`] and [`str`] values whatever the enabled features.
+#[cfg(feature = "smol_str")]
+pub type LiteralValue = smol_str::SmolStr;
+
+#[cfg(not(feature = "smol_str"))]
+fn string_into_literal_value(value: String) -> LiteralValue {
+	value
+}
+
+#[cfg(feature = "smol_str")]
+fn string_into_literal_value(value: String) -> LiteralValue {
+	value.into()
+}
+
+#[cfg(not(feature = "smol_str"))]
+fn literal_value_into_string(value: LiteralValue) -> String {
+	value
+}
+
+#[cfg(feature = "smol_str")]
+fn literal_value_into_string(value: LiteralValue) -> String {
+	value.into()
+}
+
 /// RDF Literal.
 #[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Literal<I = IriBuf> {
 	/// Literal value.
-	pub value: String,
+	///
+	/// Stored in the [`LiteralValue`] backing type, and only exposed as
+	/// [`str`] or [`String`] so that the API does not depend on the enabled
+	/// features.
+	pub(crate) value: LiteralValue,
 
 	/// Literal type.
 	pub type_: LiteralType<I>,
 }
 
 impl<I> Literal<I> {
-	pub fn new(value: String, type_: LiteralType<I>) -> Self {
+	pub fn new(value: impl Into<String>, type_: LiteralType<I>) -> Self {
+		Self::from_literal_value(string_into_literal_value(value.into()), type_)
+	}
+
+	/// Creates a new literal from a value in its [`LiteralValue`] backing
+	/// type, without converting it.
+	pub(crate) fn from_literal_value(value: LiteralValue, type_: LiteralType<I>) -> Self {
 		Self { value, type_ }
 	}
 
@@ -43,28 +108,72 @@ impl<I> Literal<I> {
 		self.type_
 	}
 
-	pub fn as_value(&self) -> &String {
-		&self.value
+	pub fn as_value(&self) -> &str {
+		self.as_str()
 	}
 
-	pub fn as_value_mut(&mut self) -> &mut String {
-		&mut self.value
+	/// Replaces the value of the literal.
+	///
+	/// ```
+	/// use rdf_types::{Literal, LiteralType, XSD_STRING};
+	///
+	/// let mut literal = Literal::new("before", LiteralType::Any(XSD_STRING.to_owned()));
+	/// literal.set_value(format!("{}!", literal.as_value()));
+	/// assert_eq!(literal.as_value(), "before!");
+	/// ```
+	pub fn set_value(&mut self, value: impl Into<String>) {
+		self.value = string_into_literal_value(value.into())
 	}
 
 	pub fn into_value(self) -> String {
-		self.value
+		literal_value_into_string(self.value)
 	}
 
 	pub fn into_parts(self) -> (String, LiteralType<I>) {
+		(literal_value_into_string(self.value), self.type_)
+	}
+
+	/// Returns the value, in its [`LiteralValue`] backing type, and the type
+	/// of the literal, without converting the value.
+	pub(crate) fn into_literal_parts(self) -> (LiteralValue, LiteralType<I>) {
 		(self.value, self.type_)
 	}
 
 	pub fn as_str(&self) -> &str {
-		self.value.as_ref()
+		self.value.as_str()
 	}
 
 	pub fn as_bytes(&self) -> &[u8] {
-		self.value.as_ref()
+		self.value.as_bytes()
+	}
+
+	/// Returns the length of the literal value, in bytes, once encoded in
+	/// UTF-8.
+	pub fn value_len_utf8(&self) -> usize {
+		self.value.len()
+	}
+
+	/// Returns the longest prefix of the literal value that is at most
+	/// `max_bytes` long once encoded in UTF-8.
+	///
+	/// The value is truncated on a character boundary, so this never panics.
+	///
+	/// ```
+	/// use rdf_types::{Literal, LiteralType, XSD_STRING};
+	///
+	/// let literal = Literal::new("héllo".to_owned(), LiteralType::Any(XSD_STRING.to_owned()));
+	/// assert_eq!(literal.value_len_utf8(), 6);
+	/// assert_eq!(literal.truncated_value(2), "h");
+	/// assert_eq!(literal.truncated_value(3), "hé");
+	/// assert_eq!(literal.truncated_value(10), "héllo");
+	/// ```
+	pub fn truncated_value(&self, max_bytes: usize) -> &str {
+		let mut end = max_bytes.min(self.value.len());
+		while !self.value.is_char_boundary(end) {
+			end -= 1
+		}
+
+		&self.value[..end]
 	}
 
 	pub fn is_lang_string(&self) -> bool {
@@ -75,6 +184,11 @@ impl<I> Literal<I> {
 		self.type_.lang_tag()
 	}
 
+	/// Returns the base direction, if this is a directional language string.
+	pub fn direction(&self) -> Option<Direction> {
+		self.type_.direction()
+	}
+
 	pub fn insert_type_into_vocabulary<V>(self, vocabulary: &mut V) -> Literal<I::Embedded>
 	where
 		I: EmbedIntoVocabulary<V>,
@@ -100,9 +214,104 @@ impl<I> Literal<I> {
 	}
 }
 
+const XSD_NORMALIZED_STRING: &str = "http://www.w3.org/2001/XMLSchema#normalizedString";
+
+const XSD_TOKEN: &str = "http://www.w3.org/2001/XMLSchema#token";
+
+const XSD_REPLACED_WHITESPACE: &[char] = &['\t', '\n', '\r'];
+
+impl Literal {
+	/// Applies the XSD whitespace facet of the literal datatype to its value.
+	///
+	/// For `xsd:normalizedString`, every tab, line feed and carriage return is
+	/// replaced by a space (`replace` facet). For `xsd:token`, whitespace
+	/// is replaced, then leading and trailing spaces are removed and
+	/// consecutive spaces are collapsed into one (`collapse` facet).
+	///
+	/// Returns `None` for any other datatype. The value is borrowed if it is
+	/// already normalized.
+	///
+	/// ```
+	/// use rdf_types::{Literal, LiteralType};
+	/// use static_iref::iri;
+	///
+	/// let literal = Literal::new(
+	///   " hello \t  world\n".to_owned(),
+	///   LiteralType::Any(iri!("http://www.w3.org/2001/XMLSchema#token").to_owned())
+	/// );
+	///
+	/// assert_eq!(literal.whitespace_normalized().unwrap(), "hello world");
+	/// ```
+	pub fn whitespace_normalized(&self) -> Option<Cow<'_, str>> {
+		let LiteralType::Any(ty) = &self.type_ else {
+			return None;
+		};
+
+		fn is_xsd_whitespace(c: char) -> bool {
+			matches!(c, ' ' | '\t' | '\n' | '\r')
+		}
+
+		match ty.as_str() {
+			XSD_NORMALIZED_STRING => {
+				if self.value.contains(XSD_REPLACED_WHITESPACE) {
+					Some(Cow::Owned(self.value.replace(XSD_REPLACED_WHITESPACE, " ")))
+				} else {
+					Some(Cow::Borrowed(self.value.as_str()))
+				}
+			}
+			XSD_TOKEN => {
+				let trimmed = self.value.trim_matches(is_xsd_whitespace);
+				let collapsed =
+					!trimmed.contains(XSD_REPLACED_WHITESPACE) && !trimmed.contains("  ");
+
+				if collapsed {
+					Some(Cow::Borrowed(trimmed))
+				} else {
+					let mut result = String::with_capacity(trimmed.len());
+					for word in trimmed.split(is_xsd_whitespace).filter(|w| !w.is_empty()) {
+						if !result.is_empty() {
+							result.push(' ')
+						}
+
+						result.push_str(word)
+					}
+
+					Some(Cow::Owned(result))
+				}
+			}
+			_ => None,
+		}
+	}
+}
+
+/// Literal with a shared datatype IRI.
+///
+/// See [`SharedLiteralType`].
+pub type SharedLiteral = Literal<Arc<IriBuf>>;
+
+impl SharedLiteral {
+	/// Creates a new typed literal sharing the given datatype IRI.
+	pub fn with_interned_type(value: impl Into<String>, type_: Arc<IriBuf>) -> Self {
+		Self::new(value, LiteralType::Any(type_))
+	}
+
+	/// Turns this literal into a literal owning its datatype IRI.
+	///
+	/// The datatype IRI is cloned only if it is still shared.
+	pub fn into_unshared(self) -> Literal {
+		Literal::from_literal_value(self.value, self.type_.into_unshared())
+	}
+}
+
+impl From<Literal> for SharedLiteral {
+	fn from(value: Literal) -> Self {
+		Self::from_literal_value(value.value, value.type_.into())
+	}
+}
+
 impl<'a, I: PartialEq> PartialEq<LiteralRef<'a, I>> for Literal<I> {
 	fn eq(&self, other: &LiteralRef<'a, I>) -> bool {
-		self.type_ == other.type_ && self.value == other.value
+		self.type_ == other.type_ && self.value.as_str() == other.value
 	}
 }
 
@@ -128,8 +337,8 @@ impl<V: IriVocabulary> ExtractFromVocabulary<V> for Literal<V::Iri> {
 	type Extracted = Literal;
 
 	fn extract_from_vocabulary(self, vocabulary: &V) -> Self::Extracted {
-		let (value, type_) = self.into_parts();
-		Literal::new(value, type_.extract_from_vocabulary(vocabulary))
+		let (value, type_) = self.into_literal_parts();
+		Literal::from_literal_value(value, type_.extract_from_vocabulary(vocabulary))
 	}
 }
 
@@ -137,7 +346,7 @@ impl<V: IriVocabulary> ExtractedFromVocabulary<V> for Literal<V::Iri> {
 	type Extracted = Literal;
 
 	fn extracted_from_vocabulary(&self, vocabulary: &V) -> Self::Extracted {
-		Literal::new(
+		Literal::from_literal_value(
 			self.value.clone(),
 			self.type_.extracted_from_vocabulary(vocabulary),
 		)
@@ -156,6 +365,28 @@ impl<I> AsRef<str> for Literal<I> {
 	}
 }
 
+/// Creates an `xsd:boolean` literal, with value `true` or `false`.
+impl From<bool> for Literal {
+	fn from(value: bool) -> Self {
+		let value = if value { "true" } else { "false" };
+		Self::new(value, LiteralType::Any(XSD_BOOLEAN.to_owned()))
+	}
+}
+
+/// Creates an `xsd:integer` literal.
+impl From<i64> for Literal {
+	fn from(value: i64) -> Self {
+		Self::new(value.to_string(), LiteralType::Any(XSD_INTEGER.to_owned()))
+	}
+}
+
+/// Creates an `xsd:string` literal.
+impl<'a> From<&'a str> for Literal {
+	fn from(value: &'a str) -> Self {
+		Self::new(value, LiteralType::Any(XSD_STRING.to_owned()))
+	}
+}
+
 impl fmt::Display for Literal {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 		self.value.rdf_fmt(f)?;
@@ -176,6 +407,15 @@ impl<I: RdfDisplay + IsXsdStringIri> RdfDisplay for Literal<I> {
 			self.type_.rdf_fmt(f)
 		}
 	}
+
+	fn rdf_write<W: io::Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+		self.value.rdf_write(writer)?;
+		if self.type_.is_xsd_string() {
+			Ok(())
+		} else {
+			self.type_.rdf_write(writer)
+		}
+	}
 }
 
 #[cfg(feature = "contextual")]
@@ -266,12 +506,47 @@ impl<'a, I> LiteralRef<'a, I> {
 		self.type_.lang_tag()
 	}
 
+	/// Returns the base direction, if this is a directional language string.
+	pub fn direction(&self) -> Option<Direction> {
+		self.type_.direction()
+	}
+
+	/// Returns the datatype IRI of the literal.
+	///
+	/// This is `rdf:langString` ([`RDF_LANG_STRING`](crate::RDF_LANG_STRING))
+	/// for language strings, and `rdf:dirLangString`
+	/// ([`RDF_DIR_LANG_STRING`](crate::RDF_DIR_LANG_STRING)) for directional
+	/// language strings.
+	///
+	/// ```
+	/// use rdf_types::{Direction, Literal, LiteralType, RDF_DIR_LANG_STRING, RDF_LANG_STRING};
+	///
+	/// let literal: Literal = Literal::new(
+	///     "Hello".to_owned(),
+	///     LiteralType::LangString("en".parse().unwrap()),
+	/// );
+	/// assert_eq!(literal.as_ref().datatype_iri(), RDF_LANG_STRING);
+	///
+	/// let literal: Literal = Literal::new(
+	///     "שלום".to_owned(),
+	///     LiteralType::DirLangString("he".parse().unwrap(), Direction::Rtl),
+	/// );
+	/// assert_eq!(literal.as_ref().datatype_iri(), RDF_DIR_LANG_STRING);
+	/// assert!(!literal.type_.is_datatype(RDF_DIR_LANG_STRING));
+	/// ```
+	pub fn datatype_iri(&self) -> &'a Iri
+	where
+		I: Borrow<Iri>,
+	{
+		self.type_.datatype_iri()
+	}
+
 	pub fn insert_type_into_vocabulary<V>(self, vocabulary: &mut V) -> Literal<I::Embedded>
 	where
 		I: EmbeddedIntoVocabulary<V>,
 	{
 		Literal {
-			value: self.value.to_owned(),
+			value: self.value.into(),
 			type_: self.type_.embed_into_vocabulary(vocabulary),
 		}
 	}
@@ -281,7 +556,7 @@ impl<'a, I> LiteralRef<'a, I> {
 		I: EmbeddedIntoVocabulary<V>,
 	{
 		Literal {
-			value: self.value.to_owned(),
+			value: self.value.into(),
 			type_: self.type_.embedded_into_vocabulary(vocabulary),
 		}
 	}
@@ -289,7 +564,7 @@ impl<'a, I> LiteralRef<'a, I> {
 
 impl<'a, I: ToOwned> LiteralRef<'a, I> {
 	pub fn into_owned(self) -> Literal<I::Owned> {
-		Literal::new(self.value.to_owned(), self.type_.into_owned())
+		Literal::new(self.value, self.type_.into_owned())
 	}
 }
 
@@ -298,13 +573,22 @@ impl<'a, I> LiteralRef<'a, I> {
 	where
 		&'a I: Into<J>,
 	{
-		Literal::new(self.value.to_owned(), self.type_.cast_into_owned())
+		Literal::new(self.value, self.type_.cast_into_owned())
 	}
 }
 
 impl<'a, I: PartialEq> PartialEq<Literal<I>> for LiteralRef<'a, I> {
 	fn eq(&self, other: &Literal<I>) -> bool {
-		self.type_ == other.type_ && self.value == other.value
+		self.type_ == other.type_ && self.value == other.value.as_str()
+	}
+}
+
+/// The hash of a literal reference is the same as the hash of the owned
+/// literal it borrows, so it can be used to look up owned literals in an
+/// `IndexMap` or `IndexSet`.
+impl<'a, I: PartialEq> Equivalent<Literal<I>> for LiteralRef<'a, I> {
+	fn equivalent(&self, key: &Literal<I>) -> bool {
+		self == key
 	}
 }
 
@@ -335,7 +619,7 @@ impl<'a, V: IriVocabulary> ExtractFromVocabulary<V> for LiteralRef<'a, V::Iri> {
 
 	fn extract_from_vocabulary(self, vocabulary: &V) -> Self::Extracted {
 		let (value, type_) = self.into_parts();
-		Literal::new(value.to_owned(), type_.extract_from_vocabulary(vocabulary))
+		Literal::new(value, type_.extract_from_vocabulary(vocabulary))
 	}
 }
 
@@ -343,10 +627,7 @@ impl<'a, V: IriVocabulary> ExtractedFromVocabulary<V> for LiteralRef<'a, V::Iri>
 	type Extracted = Literal;
 
 	fn extracted_from_vocabulary(&self, vocabulary: &V) -> Self::Extracted {
-		Literal::new(
-			self.value.to_owned(),
-			self.type_.extracted_from_vocabulary(vocabulary),
-		)
+		Literal::new(self.value, self.type_.extracted_from_vocabulary(vocabulary))
 	}
 }
 
@@ -382,6 +663,15 @@ impl<'a, I: RdfDisplay + IsXsdStringIri> RdfDisplay for LiteralRef<'a, I> {
 			self.type_.rdf_fmt(f)
 		}
 	}
+
+	fn rdf_write<W: io::Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+		self.value.rdf_write(writer)?;
+		if self.type_.is_xsd_string() {
+			Ok(())
+		} else {
+			self.type_.rdf_write(writer)
+		}
+	}
 }
 
 #[cfg(feature = "contextual")]
@@ -0,0 +1,272 @@
+use std::cmp::Ordering;
+
+use iref::Iri;
+
+use crate::{Literal, LiteralType};
+
+pub(super) const XSD: &str = "http://www.w3.org/2001/XMLSchema#";
+
+/// Literal wrapper ordering numeric literals by value.
+///
+/// Literals are first ordered by type. Two literals sharing the same numeric
+/// XSD datatype (`xsd:decimal`, `xsd:integer` and its derived types,
+/// `xsd:float` and `xsd:double`) are then compared by numeric value, so that
+/// `"9"^^xsd:integer` comes before `"10"^^xsd:integer`. In every other case
+/// the lexical values are compared.
+///
+/// Within a numeric datatype, values that are not valid numbers come after
+/// all the valid ones, and numerically equal values (such as `"1"` and
+/// `"01"`) are ordered by lexical value. This ensures this ordering is total
+/// and consistent with literal equality.
+///
+/// ```
+/// use std::collections::BTreeSet;
+/// use rdf_types::{Literal, LiteralType, NumericallyOrdered};
+/// use static_iref::iri;
+///
+/// let xsd_integer = iri!("http://www.w3.org/2001/XMLSchema#integer");
+/// let integer = |value: &str| Literal::new(value.to_owned(), LiteralType::Any(xsd_integer.to_owned()));
+///
+/// let literals = [integer("10"), integer("9"), integer("-1")];
+/// let set: BTreeSet<_> = literals.iter().map(NumericallyOrdered).collect();
+/// let values: Vec<_> = set.into_iter().map(|l| l.0.as_str()).collect();
+/// assert_eq!(values, ["-1", "9", "10"]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NumericallyOrdered<'a>(pub &'a Literal);
+
+impl<'a> PartialOrd for NumericallyOrdered<'a> {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl<'a> Ord for NumericallyOrdered<'a> {
+	fn cmp(&self, other: &Self) -> Ordering {
+		let (a, b) = (self.0, other.0);
+		match (&a.type_, &b.type_) {
+			(LiteralType::Any(ty), LiteralType::Any(other_ty)) if ty == other_ty => {
+				match (
+					NumericValue::parse(ty, &a.value),
+					NumericValue::parse(ty, &b.value),
+				) {
+					(Some(x), Some(y)) => x.cmp(&y).then_with(|| a.value.cmp(&b.value)),
+					(Some(_), None) => Ordering::Less,
+					(None, Some(_)) => Ordering::Greater,
+					(None, None) => a.value.cmp(&b.value),
+				}
+			}
+			(ty, other_ty) => ty.cmp(other_ty).then_with(|| a.value.cmp(&b.value)),
+		}
+	}
+}
+
+/// Value of a numeric literal.
+enum NumericValue<'a> {
+	Decimal(Decimal<'a>),
+	Float(f64),
+}
+
+impl<'a> NumericValue<'a> {
+	fn parse(ty: &Iri, value: &'a str) -> Option<Self> {
+		match ty.as_str().strip_prefix(XSD)? {
+			"decimal" | "integer" | "nonPositiveInteger" | "negativeInteger" | "long" | "int"
+			| "short" | "byte" | "nonNegativeInteger" | "unsignedLong" | "unsignedInt"
+			| "unsignedShort" | "unsignedByte" | "positiveInteger" => {
+				Decimal::parse(value).map(Self::Decimal)
+			}
+			"float" | "double" => value.parse().ok().map(Self::Float),
+			_ => None,
+		}
+	}
+
+	fn cmp(&self, other: &Self) -> Ordering {
+		match (self, other) {
+			(Self::Decimal(a), Self::Decimal(b)) => a.cmp(b),
+			(Self::Float(a), Self::Float(b)) => a.total_cmp(b),
+			(Self::Decimal(_), Self::Float(_)) => Ordering::Less,
+			(Self::Float(_), Self::Decimal(_)) => Ordering::Greater,
+		}
+	}
+}
+
+/// Decimal number, without leading zeros in the integer part and without
+/// trailing zeros in the fractional part.
+pub(super) struct Decimal<'a> {
+	negative: bool,
+	integer: &'a str,
+	fraction: &'a str,
+}
+
+impl<'a> Decimal<'a> {
+	pub(super) fn parse(value: &'a str) -> Option<Self> {
+		let (negative, digits) = match value.as_bytes().first() {
+			Some(b'-') => (true, &value[1..]),
+			Some(b'+') => (false, &value[1..]),
+			_ => (false, value),
+		};
+
+		let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+		if (integer.is_empty() && fraction.is_empty())
+			|| !integer
+				.bytes()
+				.chain(fraction.bytes())
+				.all(|c| c.is_ascii_digit())
+		{
+			return None;
+		}
+
+		let integer = integer.trim_start_matches('0');
+		let fraction = fraction.trim_end_matches('0');
+
+		Some(Self {
+			negative: negative && !(integer.is_empty() && fraction.is_empty()),
+			integer,
+			fraction,
+		})
+	}
+
+	/// Returns the canonical representation of this decimal number.
+	///
+	/// The decimal point is omitted if the number is an integer.
+	pub(super) fn canonical(&self) -> String {
+		let mut result = String::new();
+
+		if self.negative {
+			result.push('-')
+		}
+
+		if self.integer.is_empty() {
+			result.push('0')
+		} else {
+			result.push_str(self.integer)
+		}
+
+		if !self.fraction.is_empty() {
+			result.push('.');
+			result.push_str(self.fraction)
+		}
+
+		result
+	}
+
+	fn cmp_magnitude(&self, other: &Self) -> Ordering {
+		self.integer
+			.len()
+			.cmp(&other.integer.len())
+			.then_with(|| self.integer.cmp(other.integer))
+			.then_with(|| self.fraction.cmp(other.fraction))
+	}
+
+	pub(super) fn cmp(&self, other: &Self) -> Ordering {
+		match (self.negative, other.negative) {
+			(false, false) => self.cmp_magnitude(other),
+			(true, true) => other.cmp_magnitude(self),
+			(true, false) => Ordering::Less,
+			(false, true) => Ordering::Greater,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::collections::BTreeSet;
+
+	use iref::IriBuf;
+
+	use super::NumericallyOrdered;
+	use crate::{Literal, LiteralType};
+
+	fn typed(value: &str, ty: &str) -> Literal {
+		Literal::new(
+			value.to_owned(),
+			LiteralType::Any(
+				IriBuf::new(format!("http://www.w3.org/2001/XMLSchema#{ty}")).unwrap(),
+			),
+		)
+	}
+
+	fn sorted(literals: &[Literal]) -> Vec<&Literal> {
+		let set: BTreeSet<_> = literals.iter().map(NumericallyOrdered).collect();
+		set.into_iter().map(|l| l.0).collect()
+	}
+
+	#[test]
+	fn integers() {
+		let literals = [
+			typed("10", "integer"),
+			typed("9", "integer"),
+			typed("-10", "integer"),
+			typed("-9", "integer"),
+			typed("0", "integer"),
+			typed("-0", "integer"),
+			typed("007", "integer"),
+		];
+
+		assert_eq!(
+			sorted(&literals),
+			[
+				&literals[2],
+				&literals[3],
+				&literals[5],
+				&literals[4],
+				&literals[6],
+				&literals[1],
+				&literals[0]
+			]
+		)
+	}
+
+	#[test]
+	fn decimals_and_floats() {
+		let literals = [
+			typed("1.50", "decimal"),
+			typed("1.05", "decimal"),
+			typed(".5", "decimal"),
+			typed("-0.5", "decimal"),
+			typed("1e1", "double"),
+			typed("2", "double"),
+			typed("-INF", "double"),
+		];
+
+		assert_eq!(
+			sorted(&literals),
+			[
+				&literals[3],
+				&literals[2],
+				&literals[1],
+				&literals[0],
+				&literals[6],
+				&literals[5],
+				&literals[4]
+			]
+		)
+	}
+
+	#[test]
+	fn fallback() {
+		let literals = [
+			typed("abc", "integer"),
+			typed("10", "integer"),
+			typed("9", "integer"),
+			typed("10", "string"),
+			typed("9", "string"),
+			typed("10", "long"),
+		];
+
+		// Literals are ordered by datatype IRI first, then numeric values
+		// come before invalid ones, and non-numeric datatypes are ordered
+		// lexically.
+		assert_eq!(
+			sorted(&literals),
+			[
+				&literals[2],
+				&literals[1],
+				&literals[0],
+				&literals[5],
+				&literals[3],
+				&literals[4]
+			]
+		)
+	}
+}
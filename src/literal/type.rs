@@ -1,4 +1,10 @@
 use core::fmt;
+use std::{
+	borrow::Borrow,
+	io,
+	str::FromStr,
+	sync::{Arc, OnceLock},
+};
 
 use educe::Educe;
 use iref::{Iri, IriBuf};
@@ -9,9 +15,58 @@ use crate::{
 		EmbedIntoVocabulary, EmbeddedIntoVocabulary, ExtractFromVocabulary,
 		ExtractedFromVocabulary, IriVocabulary,
 	},
-	IsXsdStringIri, RdfDisplay, XSD_STRING,
+	IsXsdStringIri, RdfDisplay, RDF_DIR_LANG_STRING, RDF_HTML, RDF_JSON, RDF_LANG_STRING,
+	RDF_XML_LITERAL, XSD_STRING,
 };
 
+/// Base direction of a directional language string.
+///
+/// Introduced by RDF 1.2, it is written after the language tag of a literal,
+/// separated by `--`, as in `"hello"@en--ltr`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Direction {
+	/// Left-to-right.
+	Ltr,
+
+	/// Right-to-left.
+	Rtl,
+}
+
+impl Direction {
+	/// Returns the lexical representation of this direction (`ltr` or `rtl`).
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			Self::Ltr => "ltr",
+			Self::Rtl => "rtl",
+		}
+	}
+}
+
+/// Invalid base direction.
+#[derive(Debug, thiserror::Error)]
+#[error("invalid base direction `{0}`")]
+pub struct InvalidDirection(pub String);
+
+impl FromStr for Direction {
+	type Err = InvalidDirection;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"ltr" => Ok(Self::Ltr),
+			"rtl" => Ok(Self::Rtl),
+			_ => Err(InvalidDirection(s.to_owned())),
+		}
+	}
+}
+
+impl fmt::Display for Direction {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
 /// RDF literal type.
 #[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -21,16 +76,27 @@ pub enum LiteralType<I = IriBuf> {
 
 	/// Language string.
 	LangString(LangTagBuf),
+
+	/// Directional language string.
+	DirLangString(LangTagBuf, Direction),
 }
 
 impl<I> LiteralType<I> {
 	pub fn is_lang_string(&self) -> bool {
-		matches!(self, Self::LangString(_))
+		matches!(self, Self::LangString(_) | Self::DirLangString(..))
 	}
 
 	pub fn lang_tag(&self) -> Option<&LangTag> {
 		match self {
-			Self::LangString(tag) => Some(tag),
+			Self::LangString(tag) | Self::DirLangString(tag, _) => Some(tag),
+			_ => None,
+		}
+	}
+
+	/// Returns the base direction, if this is a directional language string.
+	pub fn direction(&self) -> Option<Direction> {
+		match self {
+			Self::DirLangString(_, direction) => Some(*direction),
 			_ => None,
 		}
 	}
@@ -38,7 +104,7 @@ impl<I> LiteralType<I> {
 	pub fn is_xsd_string_with(&self, vocabulary: &impl IriVocabulary<Iri = I>) -> bool {
 		match self {
 			Self::Any(i) => vocabulary.iri(i).is_some_and(|iri| iri == XSD_STRING),
-			Self::LangString(_) => false,
+			Self::LangString(_) | Self::DirLangString(..) => false,
 		}
 	}
 
@@ -48,17 +114,73 @@ impl<I> LiteralType<I> {
 	{
 		match self {
 			Self::Any(iri) => iri.is_xsd_string_iri(),
-			Self::LangString(_) => false,
+			Self::LangString(_) | Self::DirLangString(..) => false,
 		}
 	}
 
+	/// Checks if this is the given datatype IRI.
+	///
+	/// Only explicit datatypes are compared: language strings, directional
+	/// or not, never match, even [`RDF_LANG_STRING`] or
+	/// [`RDF_DIR_LANG_STRING`]. Use [`Self::datatype_iri`] to take their
+	/// implicit datatype into account.
+	pub fn is_datatype(&self, iri: &Iri) -> bool
+	where
+		I: Borrow<Iri>,
+	{
+		match self {
+			Self::Any(i) => <I as Borrow<Iri>>::borrow(i) == iri,
+			Self::LangString(_) | Self::DirLangString(..) => false,
+		}
+	}
+
+	/// Returns the datatype IRI.
+	///
+	/// Language strings have no explicit datatype IRI, in which case
+	/// [`RDF_LANG_STRING`] is returned, or [`RDF_DIR_LANG_STRING`] for
+	/// directional language strings.
+	pub fn datatype_iri(&self) -> &Iri
+	where
+		I: Borrow<Iri>,
+	{
+		match self {
+			Self::Any(i) => <I as Borrow<Iri>>::borrow(i),
+			Self::LangString(_) => RDF_LANG_STRING,
+			Self::DirLangString(..) => RDF_DIR_LANG_STRING,
+		}
+	}
+
+	/// Checks if this is the `rdf:HTML` datatype ([`RDF_HTML`]).
+	pub fn is_rdf_html(&self) -> bool
+	where
+		I: Borrow<Iri>,
+	{
+		self.is_datatype(RDF_HTML)
+	}
+
+	/// Checks if this is the `rdf:XMLLiteral` datatype ([`RDF_XML_LITERAL`]).
+	pub fn is_rdf_xml_literal(&self) -> bool
+	where
+		I: Borrow<Iri>,
+	{
+		self.is_datatype(RDF_XML_LITERAL)
+	}
+
+	/// Checks if this is the `rdf:JSON` datatype ([`RDF_JSON`]).
+	pub fn is_rdf_json(&self) -> bool
+	where
+		I: Borrow<Iri>,
+	{
+		self.is_datatype(RDF_JSON)
+	}
+
 	pub fn is_iri(&self, iri: &I) -> bool
 	where
 		I: PartialEq,
 	{
 		match self {
 			Self::Any(i) => i == iri,
-			Self::LangString(_) => false,
+			Self::LangString(_) | Self::DirLangString(..) => false,
 		}
 	}
 
@@ -66,6 +188,7 @@ impl<I> LiteralType<I> {
 		match self {
 			Self::Any(i) => LiteralTypeRef::Any(i),
 			Self::LangString(l) => LiteralTypeRef::LangString(l),
+			Self::DirLangString(l, d) => LiteralTypeRef::DirLangString(l, *d),
 		}
 	}
 
@@ -76,24 +199,124 @@ impl<I> LiteralType<I> {
 		match self {
 			Self::Any(i) => LexicalLiteralTypeRef::Any(vocabulary.iri(i).unwrap()),
 			Self::LangString(l) => LexicalLiteralTypeRef::LangString(l),
+			Self::DirLangString(l, d) => LexicalLiteralTypeRef::DirLangString(l, *d),
 		}
 	}
 }
 
 impl LiteralType {
+	/// Creates the `rdf:HTML` datatype ([`RDF_HTML`]).
+	///
+	/// ```
+	/// use rdf_types::LiteralType;
+	///
+	/// assert!(LiteralType::rdf_html().is_rdf_html());
+	/// assert!(!LiteralType::rdf_json().is_rdf_html());
+	/// ```
+	pub fn rdf_html() -> Self {
+		Self::Any(RDF_HTML.to_owned())
+	}
+
+	/// Creates the `rdf:XMLLiteral` datatype ([`RDF_XML_LITERAL`]).
+	pub fn rdf_xml_literal() -> Self {
+		Self::Any(RDF_XML_LITERAL.to_owned())
+	}
+
+	/// Creates the `rdf:JSON` datatype ([`RDF_JSON`]).
+	pub fn rdf_json() -> Self {
+		Self::Any(RDF_JSON.to_owned())
+	}
+
 	pub fn as_lexical_type_ref(&self) -> LexicalLiteralTypeRef {
 		match self {
 			Self::Any(i) => LexicalLiteralTypeRef::Any(i),
 			Self::LangString(l) => LexicalLiteralTypeRef::LangString(l),
+			Self::DirLangString(l, d) => LexicalLiteralTypeRef::DirLangString(l, *d),
+		}
+	}
+}
+
+/// Literal type with a shared datatype IRI.
+///
+/// Cloning a shared literal type only increments a reference counter, so that
+/// literals with the same datatype share the storage of the datatype IRI
+/// instead of each owning a copy of it. The common XSD datatypes are available
+/// through associated constructors returning a process-wide shared IRI.
+///
+/// ```
+/// use rdf_types::{LiteralType, SharedLiteralType};
+///
+/// let (a, b) = (SharedLiteralType::xsd_integer(), SharedLiteralType::xsd_integer());
+///
+/// match (a, b) {
+///   (LiteralType::Any(a), LiteralType::Any(b)) => assert!(std::sync::Arc::ptr_eq(&a, &b)),
+///   _ => unreachable!()
+/// }
+/// ```
+pub type SharedLiteralType = LiteralType<Arc<IriBuf>>;
+
+impl SharedLiteralType {
+	/// Turns this shared literal type into an owned literal type.
+	///
+	/// The datatype IRI is cloned only if it is still shared.
+	pub fn into_unshared(self) -> LiteralType {
+		match self {
+			Self::Any(iri) => {
+				LiteralType::Any(Arc::try_unwrap(iri).unwrap_or_else(|iri| (*iri).clone()))
+			}
+			Self::LangString(tag) => LiteralType::LangString(tag),
+			Self::DirLangString(tag, d) => LiteralType::DirLangString(tag, d),
 		}
 	}
 }
 
+impl From<LiteralType> for SharedLiteralType {
+	fn from(value: LiteralType) -> Self {
+		match value {
+			LiteralType::Any(iri) => Self::Any(Arc::new(iri)),
+			LiteralType::LangString(tag) => Self::LangString(tag),
+			LiteralType::DirLangString(tag, d) => Self::DirLangString(tag, d),
+		}
+	}
+}
+
+macro_rules! shared_xsd_types {
+	($($id:ident: $name:literal = $iri:literal),* $(,)?) => {
+		impl SharedLiteralType {
+			$(
+				#[doc = concat!("Returns the shared `xsd:", $name, "` literal type.")]
+				pub fn $id() -> Self {
+					static IRI: OnceLock<Arc<IriBuf>> = OnceLock::new();
+					Self::Any(
+						IRI.get_or_init(|| Arc::new(static_iref::iri!($iri).to_owned()))
+							.clone(),
+					)
+				}
+			)*
+		}
+	};
+}
+
+shared_xsd_types! {
+	xsd_string: "string" = "http://www.w3.org/2001/XMLSchema#string",
+	xsd_boolean: "boolean" = "http://www.w3.org/2001/XMLSchema#boolean",
+	xsd_integer: "integer" = "http://www.w3.org/2001/XMLSchema#integer",
+	xsd_int: "int" = "http://www.w3.org/2001/XMLSchema#int",
+	xsd_long: "long" = "http://www.w3.org/2001/XMLSchema#long",
+	xsd_decimal: "decimal" = "http://www.w3.org/2001/XMLSchema#decimal",
+	xsd_float: "float" = "http://www.w3.org/2001/XMLSchema#float",
+	xsd_double: "double" = "http://www.w3.org/2001/XMLSchema#double",
+	xsd_date: "date" = "http://www.w3.org/2001/XMLSchema#date",
+	xsd_date_time: "dateTime" = "http://www.w3.org/2001/XMLSchema#dateTime",
+	xsd_any_uri: "anyURI" = "http://www.w3.org/2001/XMLSchema#anyURI",
+}
+
 impl<'a, I: PartialEq> PartialEq<LiteralTypeRef<'a, I>> for LiteralType<I> {
 	fn eq(&self, other: &LiteralTypeRef<'a, I>) -> bool {
 		match (self, *other) {
 			(Self::Any(a), LiteralTypeRef::Any(b)) => a == b,
 			(Self::LangString(a), LiteralTypeRef::LangString(b)) => a == b,
+			(Self::DirLangString(a, d), LiteralTypeRef::DirLangString(b, e)) => a == b && *d == e,
 			_ => false,
 		}
 	}
@@ -106,6 +329,7 @@ impl<V, I: EmbedIntoVocabulary<V>> EmbedIntoVocabulary<V> for LiteralType<I> {
 		match self {
 			Self::Any(i) => LiteralType::Any(i.embed_into_vocabulary(vocabulary)),
 			Self::LangString(l) => LiteralType::LangString(l),
+			Self::DirLangString(l, d) => LiteralType::DirLangString(l, d),
 		}
 	}
 }
@@ -117,6 +341,7 @@ impl<V, I: EmbeddedIntoVocabulary<V>> EmbeddedIntoVocabulary<V> for LiteralType<
 		match self {
 			Self::Any(i) => LiteralType::Any(i.embedded_into_vocabulary(vocabulary)),
 			Self::LangString(l) => LiteralType::LangString(l.clone()),
+			Self::DirLangString(l, d) => LiteralType::DirLangString(l.clone(), *d),
 		}
 	}
 }
@@ -128,6 +353,7 @@ impl<V: IriVocabulary> ExtractFromVocabulary<V> for LiteralType<V::Iri> {
 		match self {
 			Self::Any(t) => LiteralType::Any(vocabulary.owned_iri(t).ok().unwrap()),
 			Self::LangString(t) => LiteralType::LangString(t),
+			Self::DirLangString(t, d) => LiteralType::DirLangString(t, d),
 		}
 	}
 }
@@ -139,6 +365,7 @@ impl<V: IriVocabulary> ExtractedFromVocabulary<V> for LiteralType<V::Iri> {
 		match self {
 			Self::Any(t) => LiteralType::Any(vocabulary.iri(t).unwrap().to_owned()),
 			Self::LangString(t) => LiteralType::LangString(t.clone()),
+			Self::DirLangString(t, d) => LiteralType::DirLangString(t.clone(), *d),
 		}
 	}
 }
@@ -154,6 +381,30 @@ impl<I: RdfDisplay> RdfDisplay for LiteralType<I> {
 				write!(f, "@")?;
 				tag.rdf_fmt(f)
 			}
+			Self::DirLangString(tag, direction) => {
+				write!(f, "@")?;
+				tag.rdf_fmt(f)?;
+				write!(f, "--{direction}")
+			}
+		}
+	}
+
+	fn rdf_write<W: io::Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+		match self {
+			Self::Any(ty) => {
+				writer.write_all(b"^^")?;
+				ty.rdf_write(writer)
+			}
+			Self::LangString(tag) => {
+				writer.write_all(b"@")?;
+				tag.rdf_write(writer)
+			}
+			Self::DirLangString(tag, direction) => {
+				writer.write_all(b"@")?;
+				tag.rdf_write(writer)?;
+				writer.write_all(b"--")?;
+				writer.write_all(direction.as_str().as_bytes())
+			}
 		}
 	}
 }
@@ -170,6 +421,11 @@ impl<T: crate::RdfDisplayWithContext<V>, V> crate::RdfDisplayWithContext<V> for
 				write!(f, "@")?;
 				tag.rdf_fmt_with(vocabulary, f)
 			}
+			Self::DirLangString(tag, direction) => {
+				write!(f, "@")?;
+				tag.rdf_fmt_with(vocabulary, f)?;
+				write!(f, "--{direction}")
+			}
 		}
 	}
 }
@@ -184,16 +440,27 @@ pub enum LiteralTypeRef<'a, I = IriBuf> {
 
 	/// Language string.
 	LangString(&'a LangTag),
+
+	/// Directional language string.
+	DirLangString(&'a LangTag, Direction),
 }
 
 impl<'a, I> LiteralTypeRef<'a, I> {
 	pub fn is_lang_string(&self) -> bool {
-		matches!(self, Self::LangString(_))
+		matches!(self, Self::LangString(_) | Self::DirLangString(..))
 	}
 
 	pub fn lang_tag(&self) -> Option<&'a LangTag> {
 		match self {
-			Self::LangString(tag) => Some(tag),
+			Self::LangString(tag) | Self::DirLangString(tag, _) => Some(tag),
+			_ => None,
+		}
+	}
+
+	/// Returns the base direction, if this is a directional language string.
+	pub fn direction(&self) -> Option<Direction> {
+		match self {
+			Self::DirLangString(_, direction) => Some(*direction),
 			_ => None,
 		}
 	}
@@ -201,7 +468,7 @@ impl<'a, I> LiteralTypeRef<'a, I> {
 	pub fn is_xsd_string_with(&self, vocabulary: &impl IriVocabulary<Iri = I>) -> bool {
 		match self {
 			Self::Any(i) => vocabulary.iri(i).is_some_and(|iri| iri == XSD_STRING),
-			Self::LangString(_) => false,
+			Self::LangString(_) | Self::DirLangString(..) => false,
 		}
 	}
 
@@ -211,17 +478,73 @@ impl<'a, I> LiteralTypeRef<'a, I> {
 	{
 		match self {
 			Self::Any(iri) => iri.is_xsd_string_iri(),
-			Self::LangString(_) => false,
+			Self::LangString(_) | Self::DirLangString(..) => false,
+		}
+	}
+
+	/// Checks if this is the given datatype IRI.
+	///
+	/// Only explicit datatypes are compared: language strings, directional
+	/// or not, never match, even [`RDF_LANG_STRING`] or
+	/// [`RDF_DIR_LANG_STRING`]. Use [`Self::datatype_iri`] to take their
+	/// implicit datatype into account.
+	pub fn is_datatype(&self, iri: &Iri) -> bool
+	where
+		I: Borrow<Iri>,
+	{
+		match self {
+			Self::Any(i) => <I as Borrow<Iri>>::borrow(i) == iri,
+			Self::LangString(_) | Self::DirLangString(..) => false,
 		}
 	}
 
+	/// Returns the datatype IRI.
+	///
+	/// Language strings have no explicit datatype IRI, in which case
+	/// [`RDF_LANG_STRING`] is returned, or [`RDF_DIR_LANG_STRING`] for
+	/// directional language strings.
+	pub fn datatype_iri(&self) -> &'a Iri
+	where
+		I: Borrow<Iri>,
+	{
+		match self {
+			Self::Any(i) => <I as Borrow<Iri>>::borrow(i),
+			Self::LangString(_) => RDF_LANG_STRING,
+			Self::DirLangString(..) => RDF_DIR_LANG_STRING,
+		}
+	}
+
+	/// Checks if this is the `rdf:HTML` datatype ([`RDF_HTML`]).
+	pub fn is_rdf_html(&self) -> bool
+	where
+		I: Borrow<Iri>,
+	{
+		self.is_datatype(RDF_HTML)
+	}
+
+	/// Checks if this is the `rdf:XMLLiteral` datatype ([`RDF_XML_LITERAL`]).
+	pub fn is_rdf_xml_literal(&self) -> bool
+	where
+		I: Borrow<Iri>,
+	{
+		self.is_datatype(RDF_XML_LITERAL)
+	}
+
+	/// Checks if this is the `rdf:JSON` datatype ([`RDF_JSON`]).
+	pub fn is_rdf_json(&self) -> bool
+	where
+		I: Borrow<Iri>,
+	{
+		self.is_datatype(RDF_JSON)
+	}
+
 	pub fn is_iri(&self, iri: &I) -> bool
 	where
 		I: PartialEq,
 	{
 		match self {
 			Self::Any(i) => *i == iri,
-			Self::LangString(_) => false,
+			Self::LangString(_) | Self::DirLangString(..) => false,
 		}
 	}
 
@@ -232,6 +555,7 @@ impl<'a, I> LiteralTypeRef<'a, I> {
 		match self {
 			Self::Any(i) => LexicalLiteralTypeRef::Any(vocabulary.iri(i).unwrap()),
 			Self::LangString(l) => LexicalLiteralTypeRef::LangString(l),
+			Self::DirLangString(l, d) => LexicalLiteralTypeRef::DirLangString(l, *d),
 		}
 	}
 }
@@ -241,6 +565,7 @@ impl<'a, I: ToOwned> LiteralTypeRef<'a, I> {
 		match self {
 			Self::Any(i) => LiteralType::Any(i.to_owned()),
 			Self::LangString(l) => LiteralType::LangString(l.to_owned()),
+			Self::DirLangString(l, d) => LiteralType::DirLangString(l.to_owned(), d),
 		}
 	}
 }
@@ -253,6 +578,7 @@ impl<'a, I> LiteralTypeRef<'a, I> {
 		match self {
 			Self::Any(i) => LiteralType::Any(i.into()),
 			Self::LangString(l) => LiteralType::LangString(l.to_owned()),
+			Self::DirLangString(l, d) => LiteralType::DirLangString(l.to_owned(), d),
 		}
 	}
 }
@@ -262,6 +588,7 @@ impl<'a> LiteralTypeRef<'a> {
 		match self {
 			Self::Any(i) => LexicalLiteralTypeRef::Any(i),
 			Self::LangString(l) => LexicalLiteralTypeRef::LangString(l),
+			Self::DirLangString(l, d) => LexicalLiteralTypeRef::DirLangString(l, *d),
 		}
 	}
 }
@@ -271,6 +598,9 @@ impl<'a, I: PartialEq> PartialEq<LiteralType<I>> for LiteralTypeRef<'a, I> {
 		match (*self, other) {
 			(Self::Any(a), LiteralType::Any(b)) => a == b,
 			(Self::LangString(a), LiteralType::LangString(b)) => a == b.as_lang_tag(),
+			(Self::DirLangString(a, d), LiteralType::DirLangString(b, e)) => {
+				a == b.as_lang_tag() && d == *e
+			}
 			_ => false,
 		}
 	}
@@ -283,6 +613,7 @@ impl<'a, V, I: EmbeddedIntoVocabulary<V>> EmbedIntoVocabulary<V> for LiteralType
 		match self {
 			Self::Any(i) => LiteralType::Any(i.embedded_into_vocabulary(vocabulary)),
 			Self::LangString(l) => LiteralType::LangString(l.to_owned()),
+			Self::DirLangString(l, d) => LiteralType::DirLangString(l.to_owned(), d),
 		}
 	}
 }
@@ -294,6 +625,7 @@ impl<'a, V, I: EmbeddedIntoVocabulary<V>> EmbeddedIntoVocabulary<V> for LiteralT
 		match *self {
 			Self::Any(i) => LiteralType::Any(i.embedded_into_vocabulary(vocabulary)),
 			Self::LangString(l) => LiteralType::LangString(l.to_owned()),
+			Self::DirLangString(l, d) => LiteralType::DirLangString(l.to_owned(), d),
 		}
 	}
 }
@@ -305,6 +637,7 @@ impl<'a, V: IriVocabulary> ExtractFromVocabulary<V> for LiteralTypeRef<'a, V::Ir
 		match self {
 			Self::Any(t) => LiteralType::Any(vocabulary.iri(t).unwrap().to_owned()),
 			Self::LangString(t) => LiteralType::LangString(t.to_owned()),
+			Self::DirLangString(t, d) => LiteralType::DirLangString(t.to_owned(), d),
 		}
 	}
 }
@@ -316,6 +649,7 @@ impl<'a, V: IriVocabulary> ExtractedFromVocabulary<V> for LiteralTypeRef<'a, V::
 		match *self {
 			Self::Any(t) => LiteralType::Any(vocabulary.iri(t).unwrap().to_owned()),
 			Self::LangString(t) => LiteralType::LangString(t.to_owned()),
+			Self::DirLangString(t, d) => LiteralType::DirLangString(t.to_owned(), d),
 		}
 	}
 }
@@ -331,6 +665,30 @@ impl<'a, I: RdfDisplay> RdfDisplay for LiteralTypeRef<'a, I> {
 				write!(f, "@")?;
 				tag.rdf_fmt(f)
 			}
+			Self::DirLangString(tag, direction) => {
+				write!(f, "@")?;
+				tag.rdf_fmt(f)?;
+				write!(f, "--{direction}")
+			}
+		}
+	}
+
+	fn rdf_write<W: io::Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+		match self {
+			Self::Any(ty) => {
+				writer.write_all(b"^^")?;
+				ty.rdf_write(writer)
+			}
+			Self::LangString(tag) => {
+				writer.write_all(b"@")?;
+				tag.rdf_write(writer)
+			}
+			Self::DirLangString(tag, direction) => {
+				writer.write_all(b"@")?;
+				tag.rdf_write(writer)?;
+				writer.write_all(b"--")?;
+				writer.write_all(direction.as_str().as_bytes())
+			}
 		}
 	}
 }
@@ -349,6 +707,11 @@ impl<'a, T: crate::RdfDisplayWithContext<V>, V> crate::RdfDisplayWithContext<V>
 				write!(f, "@")?;
 				tag.rdf_fmt_with(vocabulary, f)
 			}
+			Self::DirLangString(tag, direction) => {
+				write!(f, "@")?;
+				tag.rdf_fmt_with(vocabulary, f)?;
+				write!(f, "--{direction}")
+			}
 		}
 	}
 }
@@ -416,13 +779,16 @@ pub enum LexicalLiteralTypeRef<'a> {
 
 	/// Language string.
 	LangString(&'a LangTag),
+
+	/// Directional language string.
+	DirLangString(&'a LangTag, Direction),
 }
 
 impl<'a> LexicalLiteralTypeRef<'a> {
 	pub fn is_iri(&self, iri: &Iri) -> bool {
 		match self {
 			Self::Any(i) => *i == iri,
-			Self::LangString(_) => false,
+			Self::LangString(_) | Self::DirLangString(..) => false,
 		}
 	}
 }
@@ -0,0 +1,297 @@
+use std::cmp::Ordering;
+
+use crate::{Literal, LiteralType};
+
+use super::numeric::{Decimal, XSD};
+
+impl Literal {
+	/// Checks if this literal has the same value as `other`.
+	///
+	/// Contrarily to [`PartialEq`], which compares literals lexically, this
+	/// compares the values of literals with the following recognized XSD
+	/// datatypes:
+	///   - `xsd:decimal`, `xsd:integer` and its derived types, whose values
+	///     are all decimal numbers, so that `"1"^^xsd:integer`,
+	///     `"+1"^^xsd:integer` and `"1.0"^^xsd:decimal` are equal;
+	///   - `xsd:float` and `xsd:double`, compared as floating point numbers
+	///     (`NaN` is hence not equal to itself);
+	///   - `xsd:boolean`, where `"1"` is equal to `"true"`;
+	///   - `xsd:dateTime`, where two date-times with timezones are equal if
+	///     they refer to the same instant. A date-time without timezone is
+	///     never equal to a date-time with one.
+	///
+	/// Values of floats, doubles, booleans and date-times are only compared
+	/// to values of the same datatype. Literals with any other type, or whose
+	/// lexical value is not valid for its datatype, are compared lexically.
+	///
+	/// ```
+	/// use rdf_types::{Literal, LiteralType};
+	/// use static_iref::iri;
+	///
+	/// let integer = Literal::new("+1".to_owned(), LiteralType::Any(iri!("http://www.w3.org/2001/XMLSchema#integer").to_owned()));
+	/// let decimal = Literal::new("1.0".to_owned(), LiteralType::Any(iri!("http://www.w3.org/2001/XMLSchema#decimal").to_owned()));
+	///
+	/// assert_ne!(integer, decimal);
+	/// assert!(integer.value_eq(&decimal));
+	/// ```
+	pub fn value_eq(&self, other: &Self) -> bool {
+		match (&self.type_, &other.type_) {
+			(LiteralType::Any(a_ty), LiteralType::Any(b_ty)) => {
+				match (
+					Value::parse(a_ty.as_str(), &self.value),
+					Value::parse(b_ty.as_str(), &other.value),
+				) {
+					(Some(Value::Decimal(a)), Some(Value::Decimal(b))) => {
+						a.cmp(&b) == Ordering::Equal
+					}
+					(Some(a), Some(b)) if a_ty == b_ty => a == b,
+					_ => self == other,
+				}
+			}
+			_ => self == other,
+		}
+	}
+}
+
+/// Value of a literal with a recognized datatype.
+enum Value<'a> {
+	Decimal(Decimal<'a>),
+	Float(f64),
+	Boolean(bool),
+	DateTime(DateTime<'a>),
+}
+
+impl<'a> Value<'a> {
+	fn parse(ty: &str, value: &'a str) -> Option<Self> {
+		let value = value.trim_matches(|c: char| matches!(c, ' ' | '\t' | '\n' | '\r'));
+		match ty.strip_prefix(XSD)? {
+			"decimal" => Decimal::parse(value).map(Self::Decimal),
+			"integer" | "nonPositiveInteger" | "negativeInteger" | "long" | "int" | "short"
+			| "byte" | "nonNegativeInteger" | "unsignedLong" | "unsignedInt" | "unsignedShort"
+			| "unsignedByte" | "positiveInteger" => {
+				if value.contains('.') {
+					None
+				} else {
+					Decimal::parse(value).map(Self::Decimal)
+				}
+			}
+			"float" => value.parse::<f32>().ok().map(|f| Self::Float(f64::from(f))),
+			"double" => value.parse().ok().map(Self::Float),
+			"boolean" => match value {
+				"true" | "1" => Some(Self::Boolean(true)),
+				"false" | "0" => Some(Self::Boolean(false)),
+				_ => None,
+			},
+			"dateTime" => DateTime::parse(value).map(Self::DateTime),
+			_ => None,
+		}
+	}
+}
+
+impl<'a> PartialEq for Value<'a> {
+	fn eq(&self, other: &Self) -> bool {
+		match (self, other) {
+			(Self::Decimal(a), Self::Decimal(b)) => a.cmp(b) == Ordering::Equal,
+			(Self::Float(a), Self::Float(b)) => a == b,
+			(Self::Boolean(a), Self::Boolean(b)) => a == b,
+			(Self::DateTime(a), Self::DateTime(b)) => a == b,
+			_ => false,
+		}
+	}
+}
+
+/// `xsd:dateTime` value.
+#[derive(PartialEq)]
+struct DateTime<'a> {
+	/// Seconds since the Unix epoch, in UTC if a timezone is given.
+	seconds: i64,
+
+	/// Fractional seconds digits, without trailing zeros.
+	fraction: &'a str,
+
+	/// Whether a timezone is given.
+	timezoned: bool,
+}
+
+impl<'a> DateTime<'a> {
+	/// Parses a `[-]YYYY-MM-DDThh:mm:ss[.s+][Z|(+|-)hh:mm]` date-time.
+	fn parse(value: &'a str) -> Option<Self> {
+		let (negative, value) = match value.strip_prefix('-') {
+			Some(value) => (true, value),
+			None => (false, value),
+		};
+
+		let (date, time) = value.split_once('T')?;
+		let mut date = date.splitn(3, '-');
+		let year = date.next().filter(|y| y.len() >= 4)?;
+		let year: i64 = digits(year)?;
+		let year = if negative { -year } else { year };
+		let month: i64 = digits(date.next().filter(|m| m.len() == 2)?)?;
+		let day: i64 = digits(date.next().filter(|d| d.len() == 2)?)?;
+
+		if !(1..=12).contains(&month) || !(1..=days_in_month(year, month)).contains(&day) {
+			return None;
+		}
+
+		let (time, offset) = match time.find(['Z', '+', '-'].as_slice()) {
+			Some(i) => (&time[..i], Some(timezone_offset(&time[i..])?)),
+			None => (time, None),
+		};
+
+		let (time, fraction) = match time.split_once('.') {
+			Some((time, fraction)) => {
+				if fraction.is_empty() || !fraction.bytes().all(|c| c.is_ascii_digit()) {
+					return None;
+				}
+
+				(time, fraction.trim_end_matches('0'))
+			}
+			None => (time, ""),
+		};
+
+		let mut time = time.split(':');
+		let hours: i64 = digits(time.next().filter(|h| h.len() == 2)?)?;
+		let minutes: i64 = digits(time.next().filter(|m| m.len() == 2)?)?;
+		let seconds: i64 = digits(time.next().filter(|s| s.len() == 2)?)?;
+		if time.next().is_some() || minutes > 59 || seconds > 59 {
+			return None;
+		}
+
+		if hours > 24 || (hours == 24 && (minutes > 0 || seconds > 0 || !fraction.is_empty())) {
+			return None;
+		}
+
+		Some(Self {
+			seconds: days_from_civil(year, month, day) * 86400
+				+ hours * 3600
+				+ minutes * 60
+				+ seconds - offset.unwrap_or(0) * 60,
+			fraction,
+			timezoned: offset.is_some(),
+		})
+	}
+}
+
+/// Parses a non-empty sequence of ASCII digits.
+fn digits(s: &str) -> Option<i64> {
+	if !s.is_empty() && s.bytes().all(|c| c.is_ascii_digit()) {
+		s.parse().ok()
+	} else {
+		None
+	}
+}
+
+/// Parses a `Z` or `(+|-)hh:mm` timezone, returning its offset in minutes.
+fn timezone_offset(tz: &str) -> Option<i64> {
+	if tz == "Z" {
+		return Some(0);
+	}
+
+	let (negative, tz) = match tz.split_at(1) {
+		("+", tz) => (false, tz),
+		("-", tz) => (true, tz),
+		_ => return None,
+	};
+
+	let (hours, minutes) = tz.split_once(':')?;
+	let hours = digits(hours).filter(|_| hours.len() == 2)?;
+	let minutes = digits(minutes).filter(|_| minutes.len() == 2)?;
+	if minutes > 59 || hours > 14 || (hours == 14 && minutes > 0) {
+		return None;
+	}
+
+	let offset = hours * 60 + minutes;
+	Some(if negative { -offset } else { offset })
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+	match month {
+		2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+		2 => 28,
+		4 | 6 | 9 | 11 => 30,
+		_ => 31,
+	}
+}
+
+/// Returns the number of days between the Unix epoch and the given date of
+/// the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+	let year = if month <= 2 { year - 1 } else { year };
+	let era = year.div_euclid(400);
+	let year_of_era = year.rem_euclid(400);
+	let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+	let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+	era * 146097 + day_of_era - 719468
+}
+
+#[cfg(test)]
+mod tests {
+	use iref::IriBuf;
+
+	use crate::{Literal, LiteralType};
+
+	fn typed(value: &str, ty: &str) -> Literal {
+		Literal::new(
+			value.to_owned(),
+			LiteralType::Any(
+				IriBuf::new(format!("http://www.w3.org/2001/XMLSchema#{ty}")).unwrap(),
+			),
+		)
+	}
+
+	#[test]
+	fn decimals() {
+		assert!(typed("1", "integer").value_eq(&typed("+1", "integer")));
+		assert!(typed("1", "integer").value_eq(&typed("01", "long")));
+		assert!(typed("1", "integer").value_eq(&typed("1.0", "decimal")));
+		assert!(typed("-0", "integer").value_eq(&typed("0.0", "decimal")));
+		assert!(!typed("1", "integer").value_eq(&typed("2", "integer")));
+		assert!(!typed("1.0", "integer").value_eq(&typed("1", "integer")));
+		assert!(!typed("1", "integer").value_eq(&typed("1", "double")));
+	}
+
+	#[test]
+	fn floats() {
+		assert!(typed("1", "double").value_eq(&typed("1.0E0", "double")));
+		assert!(typed("0.1", "float").value_eq(&typed("0.100000001", "float")));
+		assert!(!typed("0.1", "double").value_eq(&typed("0.100000001", "double")));
+		assert!(!typed("1", "float").value_eq(&typed("1", "double")));
+		assert!(!typed("NaN", "double").value_eq(&typed("NaN", "double")));
+	}
+
+	#[test]
+	fn booleans() {
+		assert!(typed("true", "boolean").value_eq(&typed("1", "boolean")));
+		assert!(typed("false", "boolean").value_eq(&typed(" 0 ", "boolean")));
+		assert!(!typed("true", "boolean").value_eq(&typed("false", "boolean")));
+	}
+
+	#[test]
+	fn date_times() {
+		assert!(typed("2020-01-01T00:00:00Z", "dateTime")
+			.value_eq(&typed("2019-12-31T23:00:00.000-01:00", "dateTime")));
+		assert!(typed("2020-01-01T24:00:00", "dateTime")
+			.value_eq(&typed("2020-01-02T00:00:00", "dateTime")));
+		assert!(typed("2020-02-29T12:30:00.50", "dateTime")
+			.value_eq(&typed("2020-02-29T12:30:00.5", "dateTime")));
+		assert!(!typed("2020-01-01T00:00:00Z", "dateTime")
+			.value_eq(&typed("2020-01-01T00:00:00", "dateTime")));
+		assert!(!typed("2019-02-29T00:00:00", "dateTime")
+			.value_eq(&typed("2019-03-01T00:00:00", "dateTime")));
+	}
+
+	#[test]
+	fn fallback() {
+		assert!(!typed("1", "string").value_eq(&typed("01", "string")));
+		assert!(typed("abc", "integer").value_eq(&typed("abc", "integer")));
+		assert!(!typed("2020-01-01Z", "date").value_eq(&typed("2020-01-01+00:00", "date")));
+		assert!(!Literal::new(
+			"1".to_owned(),
+			LiteralType::LangString("en".parse().unwrap())
+		)
+		.value_eq(&Literal::new(
+			"01".to_owned(),
+			LiteralType::LangString("en".parse().unwrap())
+		)));
+	}
+}
@@ -0,0 +1,221 @@
+use std::borrow::Borrow;
+
+use iref::Iri;
+use langtag::LangTag;
+
+use crate::{Direction, Literal, LiteralType, XSD_BOOLEAN, XSD_DOUBLE, XSD_INTEGER, XSD_STRING};
+
+/// Literal visitor, dispatching on the literal datatype.
+///
+/// See [`Literal::visit`].
+pub trait LiteralVisitor {
+	/// Visit result.
+	type Output;
+
+	/// Visits an `xsd:string` literal.
+	fn visit_string(self, value: &str) -> Self::Output;
+
+	/// Visits a language string.
+	fn visit_lang_string(self, value: &str, lang_tag: &LangTag) -> Self::Output;
+
+	/// Visits a directional language string.
+	///
+	/// By default, the direction is ignored and this calls
+	/// [`Self::visit_lang_string`].
+	fn visit_dir_lang_string(
+		self,
+		value: &str,
+		lang_tag: &LangTag,
+		_direction: Direction,
+	) -> Self::Output
+	where
+		Self: Sized,
+	{
+		self.visit_lang_string(value, lang_tag)
+	}
+
+	/// Visits an `xsd:integer` literal whose value fits in an [`i64`].
+	fn visit_integer(self, value: i64) -> Self::Output;
+
+	/// Visits a valid `xsd:double` literal.
+	fn visit_double(self, value: f64) -> Self::Output;
+
+	/// Visits a valid `xsd:boolean` literal.
+	fn visit_boolean(self, value: bool) -> Self::Output;
+
+	/// Visits any other literal, given its datatype and lexical value.
+	///
+	/// This includes literals with one of the above datatypes but whose
+	/// lexical value is invalid.
+	fn visit_other(self, datatype: &Iri, value: &str) -> Self::Output;
+}
+
+impl<I: Borrow<Iri>> Literal<I> {
+	/// Visits this literal, calling the `visitor` method matching its
+	/// datatype.
+	///
+	/// Integers, doubles and booleans are parsed from their lexical value,
+	/// leading and trailing whitespaces excluded. If the value is not valid,
+	/// [`LiteralVisitor::visit_other`] is called instead.
+	///
+	/// ```
+	/// use rdf_types::{Iri, Literal, LiteralVisitor};
+	/// use langtag::LangTag;
+	///
+	/// struct Describe;
+	///
+	/// impl LiteralVisitor for Describe {
+	///     type Output = String;
+	///
+	///     fn visit_string(self, value: &str) -> String { format!("string {value}") }
+	///     fn visit_lang_string(self, value: &str, tag: &LangTag) -> String { format!("{tag} string {value}") }
+	///     fn visit_integer(self, value: i64) -> String { format!("integer {value}") }
+	///     fn visit_double(self, value: f64) -> String { format!("double {value}") }
+	///     fn visit_boolean(self, value: bool) -> String { format!("boolean {value}") }
+	///     fn visit_other(self, ty: &Iri, value: &str) -> String { format!("<{ty}> {value}") }
+	/// }
+	///
+	/// assert_eq!(Literal::from(42i64).visit(Describe), "integer 42");
+	/// assert_eq!(Literal::from("42").visit(Describe), "string 42");
+	/// ```
+	pub fn visit<V: LiteralVisitor>(&self, visitor: V) -> V::Output {
+		match &self.type_ {
+			LiteralType::LangString(tag) => visitor.visit_lang_string(&self.value, tag),
+			LiteralType::DirLangString(tag, direction) => {
+				visitor.visit_dir_lang_string(&self.value, tag, *direction)
+			}
+			LiteralType::Any(ty) => {
+				let ty = ty.borrow();
+				let trimmed = self
+					.value
+					.trim_matches(|c: char| matches!(c, ' ' | '\t' | '\n' | '\r'));
+
+				if ty == XSD_STRING {
+					visitor.visit_string(&self.value)
+				} else if ty == XSD_INTEGER {
+					match trimmed.parse() {
+						Ok(i) => visitor.visit_integer(i),
+						Err(_) => visitor.visit_other(ty, &self.value),
+					}
+				} else if ty == XSD_DOUBLE {
+					match parse_double(trimmed) {
+						Some(d) => visitor.visit_double(d),
+						None => visitor.visit_other(ty, &self.value),
+					}
+				} else if ty == XSD_BOOLEAN {
+					match trimmed {
+						"true" | "1" => visitor.visit_boolean(true),
+						"false" | "0" => visitor.visit_boolean(false),
+						_ => visitor.visit_other(ty, &self.value),
+					}
+				} else {
+					visitor.visit_other(ty, &self.value)
+				}
+			}
+		}
+	}
+}
+
+/// Parses an `xsd:double` lexical value.
+fn parse_double(value: &str) -> Option<f64> {
+	match value {
+		"INF" | "+INF" => Some(f64::INFINITY),
+		"-INF" => Some(f64::NEG_INFINITY),
+		"NaN" => Some(f64::NAN),
+		_ if value
+			.bytes()
+			.all(|c| c.is_ascii_digit() || matches!(c, b'+' | b'-' | b'.' | b'e' | b'E')) =>
+		{
+			value.parse().ok()
+		}
+		_ => None,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use iref::{Iri, IriBuf};
+	use langtag::LangTag;
+	use static_iref::iri;
+
+	use super::LiteralVisitor;
+	use crate::{Literal, LiteralType, XSD_DOUBLE};
+
+	#[derive(Debug, PartialEq)]
+	enum Visited {
+		String(String),
+		LangString(String, String),
+		Integer(i64),
+		Double(f64),
+		Boolean(bool),
+		Other(String, String),
+	}
+
+	struct Visitor;
+
+	impl LiteralVisitor for Visitor {
+		type Output = Visited;
+
+		fn visit_string(self, value: &str) -> Visited {
+			Visited::String(value.to_owned())
+		}
+
+		fn visit_lang_string(self, value: &str, lang_tag: &LangTag) -> Visited {
+			Visited::LangString(value.to_owned(), lang_tag.to_string())
+		}
+
+		fn visit_integer(self, value: i64) -> Visited {
+			Visited::Integer(value)
+		}
+
+		fn visit_double(self, value: f64) -> Visited {
+			Visited::Double(value)
+		}
+
+		fn visit_boolean(self, value: bool) -> Visited {
+			Visited::Boolean(value)
+		}
+
+		fn visit_other(self, datatype: &Iri, value: &str) -> Visited {
+			Visited::Other(datatype.to_string(), value.to_owned())
+		}
+	}
+
+	fn typed(value: &str, ty: &Iri) -> Literal {
+		Literal::new(value.to_owned(), LiteralType::Any(ty.to_owned()))
+	}
+
+	#[test]
+	fn visit() {
+		assert_eq!(
+			Literal::from("a").visit(Visitor),
+			Visited::String("a".to_owned())
+		);
+		assert_eq!(
+			Literal::new(
+				"a".to_owned(),
+				LiteralType::<IriBuf>::LangString("en".parse().unwrap())
+			)
+			.visit(Visitor),
+			Visited::LangString("a".to_owned(), "en".to_owned())
+		);
+		assert_eq!(Literal::from(-7i64).visit(Visitor), Visited::Integer(-7));
+		assert_eq!(Literal::from(true).visit(Visitor), Visited::Boolean(true));
+		assert_eq!(
+			typed(" 1.5e1 ", XSD_DOUBLE).visit(Visitor),
+			Visited::Double(15.0)
+		);
+		assert_eq!(
+			typed("-INF", XSD_DOUBLE).visit(Visitor),
+			Visited::Double(f64::NEG_INFINITY)
+		);
+		assert_eq!(
+			typed("inf", XSD_DOUBLE).visit(Visitor),
+			Visited::Other(XSD_DOUBLE.to_string(), "inf".to_owned())
+		);
+		assert_eq!(
+			typed("a", iri!("http://example.org/datatype")).visit(Visitor),
+			Visited::Other("http://example.org/datatype".to_owned(), "a".to_owned())
+		);
+	}
+}
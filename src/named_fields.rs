@@ -0,0 +1,144 @@
+//! Named fields serialization of triples and quads.
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{Quad, Triple};
+
+/// Triple or quad wrapper (de)serialized with named fields.
+///
+/// [`Triple`] and [`Quad`] are (de)serialized as sequences of components.
+/// Wrapped in `NamedFields`, they are instead (de)serialized as structures
+/// with a `subject`, `predicate`, `object` and, for quads, an optional
+/// `graph` field.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NamedFields<T>(pub T);
+
+impl<T> NamedFields<T> {
+	/// Returns the wrapped value.
+	pub fn into_inner(self) -> T {
+		self.0
+	}
+}
+
+impl<T> From<T> for NamedFields<T> {
+	fn from(value: T) -> Self {
+		Self(value)
+	}
+}
+
+#[derive(Serialize)]
+#[serde(rename = "Triple")]
+struct TripleRef<'a, S, P, O> {
+	subject: &'a S,
+	predicate: &'a P,
+	object: &'a O,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "Triple")]
+struct TripleFields<S, P, O> {
+	subject: S,
+	predicate: P,
+	object: O,
+}
+
+#[derive(Serialize)]
+#[serde(rename = "Quad")]
+struct QuadRef<'a, S, P, O, G> {
+	subject: &'a S,
+	predicate: &'a P,
+	object: &'a O,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	graph: Option<&'a G>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "Quad")]
+struct QuadFields<S, P, O, G> {
+	subject: S,
+	predicate: P,
+	object: O,
+	#[serde(default = "Option::default")]
+	graph: Option<G>,
+}
+
+impl<S: Serialize, P: Serialize, O: Serialize> Serialize for NamedFields<Triple<S, P, O>> {
+	fn serialize<E: Serializer>(&self, serializer: E) -> Result<E::Ok, E::Error> {
+		TripleRef {
+			subject: &self.0 .0,
+			predicate: &self.0 .1,
+			object: &self.0 .2,
+		}
+		.serialize(serializer)
+	}
+}
+
+impl<'de, S: Deserialize<'de>, P: Deserialize<'de>, O: Deserialize<'de>> Deserialize<'de>
+	for NamedFields<Triple<S, P, O>>
+{
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let t = TripleFields::deserialize(deserializer)?;
+		Ok(Self(Triple(t.subject, t.predicate, t.object)))
+	}
+}
+
+impl<S: Serialize, P: Serialize, O: Serialize, G: Serialize> Serialize
+	for NamedFields<Quad<S, P, O, G>>
+{
+	fn serialize<E: Serializer>(&self, serializer: E) -> Result<E::Ok, E::Error> {
+		QuadRef {
+			subject: &self.0 .0,
+			predicate: &self.0 .1,
+			object: &self.0 .2,
+			graph: self.0 .3.as_ref(),
+		}
+		.serialize(serializer)
+	}
+}
+
+impl<'de, S: Deserialize<'de>, P: Deserialize<'de>, O: Deserialize<'de>, G: Deserialize<'de>>
+	Deserialize<'de> for NamedFields<Quad<S, P, O, G>>
+{
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let q = QuadFields::deserialize(deserializer)?;
+		Ok(Self(Quad(q.subject, q.predicate, q.object, q.graph)))
+	}
+}
+
+#[cfg(all(test, feature = "json"))]
+mod tests {
+	use super::NamedFields;
+	use crate::{Quad, Triple};
+
+	#[test]
+	fn triple() {
+		let triple: Triple<u32> = Triple(0, 1, 2);
+		let json = serde_json::to_value(NamedFields(triple)).unwrap();
+		assert_eq!(
+			json,
+			serde_json::json!({ "subject": 0, "predicate": 1, "object": 2 })
+		);
+
+		let NamedFields(parsed): NamedFields<Triple<u32>> = serde_json::from_value(json).unwrap();
+		assert_eq!(parsed, triple)
+	}
+
+	#[test]
+	fn quad() {
+		let quad: Quad<u32> = Quad(0, 1, 2, Some(3));
+		let json = serde_json::to_value(NamedFields(quad)).unwrap();
+		assert_eq!(
+			json,
+			serde_json::json!({ "subject": 0, "predicate": 1, "object": 2, "graph": 3 })
+		);
+
+		let NamedFields(parsed): NamedFields<Quad<u32>> = serde_json::from_value(json).unwrap();
+		assert_eq!(parsed, quad);
+
+		let NamedFields(parsed): NamedFields<Quad<u32>> = serde_json::from_value(
+			serde_json::json!({ "subject": 0, "predicate": 1, "object": 2 }),
+		)
+		.unwrap();
+		let expected: Quad<u32> = Quad(0, 1, 2, None);
+		assert_eq!(parsed, expected)
+	}
+}
@@ -1,9 +1,11 @@
+use std::fmt;
+
 use crate::{
 	pattern::{
 		triple::{self, CanonicalTriplePattern},
 		QuadPattern, ResourceOrVar, TriplePattern,
 	},
-	Quad, Triple,
+	Quad, RdfDisplay, Triple,
 };
 
 /// Canonical triple pattern.
@@ -185,6 +187,75 @@ impl<T> CanonicalQuadPattern<T> {
 			}
 		}
 	}
+
+	/// Checks if the given quad matches this pattern.
+	pub fn matches(&self, Quad(s, p, o, g): Quad<&T>) -> bool
+	where
+		T: PartialEq,
+	{
+		let subject = match self.subject() {
+			PatternSubject::Any => true,
+			PatternSubject::Given(id) => id == s,
+		};
+
+		let predicate = match self.predicate() {
+			PatternPredicate::Any => true,
+			PatternPredicate::SameAsSubject => p == s,
+			PatternPredicate::Given(id) => id == p,
+		};
+
+		let object = match self.object() {
+			PatternObject::Any => true,
+			PatternObject::SameAsSubject => o == s,
+			PatternObject::SameAsPredicate => o == p,
+			PatternObject::Given(id) => id == o,
+		};
+
+		let graph = match self.graph() {
+			PatternGraph::Any => true,
+			PatternGraph::SameAsSubject => g == Some(s),
+			PatternGraph::SameAsPredicate => g == Some(p),
+			PatternGraph::SameAsObject => g == Some(o),
+			PatternGraph::Given(id) => id == g,
+		};
+
+		subject && predicate && object && graph
+	}
+}
+
+/// Displays the pattern with the variables named after the position where they
+/// first appear (`?s`, `?p`, `?o` and `?g`).
+///
+/// The graph is omitted if the pattern matches the default graph.
+impl<T: RdfDisplay> fmt::Display for CanonicalQuadPattern<T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self.subject() {
+			PatternSubject::Any => write!(f, "?s")?,
+			PatternSubject::Given(s) => s.rdf_fmt(f)?,
+		}
+
+		match self.predicate() {
+			PatternPredicate::Any => write!(f, " ?p")?,
+			PatternPredicate::SameAsSubject => write!(f, " ?s")?,
+			PatternPredicate::Given(p) => write!(f, " {}", p.rdf_display())?,
+		}
+
+		match self.object() {
+			PatternObject::Any => write!(f, " ?o")?,
+			PatternObject::SameAsSubject => write!(f, " ?s")?,
+			PatternObject::SameAsPredicate => write!(f, " ?p")?,
+			PatternObject::Given(o) => write!(f, " {}", o.rdf_display())?,
+		}
+
+		match self.graph() {
+			PatternGraph::Any => write!(f, " ?g"),
+			PatternGraph::SameAsSubject => write!(f, " ?s"),
+			PatternGraph::SameAsPredicate => write!(f, " ?p"),
+			PatternGraph::SameAsObject => write!(f, " ?o"),
+			PatternGraph::Given(Some(g)) => write!(f, " {}", g.rdf_display()),
+			PatternGraph::Given(None) => Ok(()),
+		}
+	}
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -1240,3 +1311,63 @@ impl<T> GivenSubjectGivenPredicateGivenObject<T> {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use static_iref::iri;
+
+	use crate::{
+		pattern::{CanonicalQuadPattern, ResourceOrVar},
+		Quad, Term,
+	};
+
+	#[test]
+	fn construction_paths() {
+		let a = Term::iri(iri!("http://example.org/a").to_owned());
+
+		// Variable names do not matter, only how they are shared.
+		let x: CanonicalQuadPattern<Term> = Quad(
+			ResourceOrVar::Var('x'),
+			ResourceOrVar::Resource(a.clone()),
+			ResourceOrVar::Var('x'),
+			Some(ResourceOrVar::Var('y')),
+		)
+		.into();
+		let y: CanonicalQuadPattern<Term> = Quad(
+			ResourceOrVar::Var(1),
+			ResourceOrVar::Resource(a.clone()),
+			ResourceOrVar::Var(1),
+			Some(ResourceOrVar::Var(0)),
+		)
+		.into();
+		assert_eq!(x, y);
+		assert_eq!(x.to_string(), "?s <http://example.org/a> ?s ?g");
+
+		// Distinct variables are equivalent to wildcards.
+		let x: CanonicalQuadPattern<Term> = Quad(
+			ResourceOrVar::Var('x'),
+			ResourceOrVar::Var('y'),
+			ResourceOrVar::Resource(a.clone()),
+			None,
+		)
+		.into();
+		let y: CanonicalQuadPattern<Term> = Quad(None, None, Some(a.clone()), Some(None)).into();
+		assert_eq!(x, y);
+		assert_eq!(x.to_string(), "?s ?p <http://example.org/a>");
+
+		// Given resources are never merged with variables, even when equal.
+		let x: CanonicalQuadPattern<Term> = Quad(a.clone(), a.clone(), a.clone(), None).into();
+		let y: CanonicalQuadPattern<Term> = Quad(
+			ResourceOrVar::<_, char>::Resource(a.clone()),
+			ResourceOrVar::Resource(a.clone()),
+			ResourceOrVar::Resource(a),
+			None,
+		)
+		.into();
+		assert_eq!(x, y);
+		assert_eq!(
+			x.to_string(),
+			"<http://example.org/a> <http://example.org/a> <http://example.org/a>"
+		);
+	}
+}
@@ -1,2 +1,74 @@
+use super::{QuadPattern, ResourceOrVar};
+
 pub mod canonical;
 pub use canonical::CanonicalQuadPattern;
+
+mod parse;
+pub use parse::InvalidQuadPattern;
+
+impl<T, X: PartialEq> QuadPattern<T, X> {
+	/// Returns an iterator over the distinct variables of this pattern.
+	///
+	/// Variables are visited in subject, predicate, object, graph order.
+	pub fn variables(&self) -> PatternVariables<'_, X> {
+		let mut vars = [None; 4];
+		let mut len = 0;
+
+		let items = [Some(&self.0), Some(&self.1), Some(&self.2), self.3.as_ref()];
+		for x in items
+			.into_iter()
+			.flatten()
+			.filter_map(ResourceOrVar::as_var)
+		{
+			if !vars[..len].contains(&Some(x)) {
+				vars[len] = Some(x);
+				len += 1
+			}
+		}
+
+		PatternVariables {
+			vars: vars.into_iter(),
+		}
+	}
+}
+
+/// Iterator over the distinct variables of a quad pattern.
+///
+/// See [`QuadPattern::variables`].
+pub struct PatternVariables<'a, X> {
+	vars: std::array::IntoIter<Option<&'a X>, 4>,
+}
+
+impl<'a, X> Iterator for PatternVariables<'a, X> {
+	type Item = &'a X;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.vars.next().flatten()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{pattern::ResourceOrVar, Quad};
+
+	#[test]
+	fn variables() {
+		let pattern: Quad<ResourceOrVar<u32, char>> = Quad(
+			ResourceOrVar::Var('x'),
+			ResourceOrVar::Resource(0),
+			ResourceOrVar::Var('y'),
+			Some(ResourceOrVar::Var('x')),
+		);
+
+		assert_eq!(pattern.variables().collect::<Vec<_>>(), [&'x', &'y']);
+
+		let pattern: Quad<ResourceOrVar<u32, char>> = Quad(
+			ResourceOrVar::Resource(0),
+			ResourceOrVar::Resource(1),
+			ResourceOrVar::Resource(2),
+			None,
+		);
+
+		assert_eq!(pattern.variables().count(), 0)
+	}
+}
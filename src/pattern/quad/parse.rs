@@ -0,0 +1,218 @@
+//! Quad pattern parser.
+use std::{collections::HashSet, str::FromStr};
+
+use crate::{
+	pattern::{QuadPattern, ResourceOrVar},
+	term::{InvalidTerm, TermParser},
+	Quad, Term,
+};
+
+use super::CanonicalQuadPattern;
+
+/// Invalid quad pattern.
+///
+/// Error returned by [`CanonicalQuadPattern::parse`].
+#[derive(Debug, thiserror::Error)]
+pub enum InvalidQuadPattern {
+	#[error("unexpected character `{1}` at offset {0}")]
+	UnexpectedChar(usize, char),
+
+	#[error("expected 3 or 4 terms, found {0}")]
+	WrongTermCount(usize),
+
+	#[error("empty variable name at offset {0}")]
+	EmptyVariable(usize),
+
+	#[error(transparent)]
+	InvalidTerm(#[from] InvalidTerm),
+}
+
+impl CanonicalQuadPattern<Term> {
+	/// Parses a SPARQL-like quad pattern.
+	///
+	/// The pattern is composed of a subject, predicate and object, optionally
+	/// followed by a graph, and an optional final `.`. Each of them is either
+	/// a variable `?name`, an IRI `<iri>`, a blank node identifier `_:id` or a
+	/// literal `"value"`, `"value"@lang` or `"value"^^<iri>`. Omitting the
+	/// graph targets the default graph.
+	///
+	/// The name of every variable encountered is added to `vars`.
+	///
+	/// The result can be turned into a canonical pattern with
+	/// [`CanonicalQuadPattern::from_pattern`].
+	///
+	/// ```
+	/// use std::collections::HashSet;
+	/// use rdf_types::{pattern::{CanonicalQuadPattern, ResourceOrVar}, Term};
+	/// use static_iref::iri;
+	///
+	/// let mut vars = HashSet::new();
+	/// let pattern = CanonicalQuadPattern::parse("?s <http://example.org/p> ?o ?g", &mut vars).unwrap();
+	///
+	/// assert_eq!(pattern.1, ResourceOrVar::Resource(Term::iri(iri!("http://example.org/p").to_owned())));
+	/// assert_eq!(vars.len(), 3);
+	/// ```
+	pub fn parse(
+		s: &str,
+		vars: &mut HashSet<String>,
+	) -> Result<QuadPattern<Term, String>, InvalidQuadPattern> {
+		let mut parser = TermParser::new(s);
+
+		let mut items = Vec::with_capacity(4);
+		while let Some(item) = next_item(&mut parser)? {
+			if let ResourceOrVar::Var(x) = &item {
+				vars.insert(x.clone());
+			}
+
+			items.push(item)
+		}
+
+		let count = items.len();
+		let mut items = items.into_iter();
+		match (items.next(), items.next(), items.next(), items.next()) {
+			(Some(s), Some(p), Some(o), g) if count <= 4 => Ok(Quad(s, p, o, g)),
+			_ => Err(InvalidQuadPattern::WrongTermCount(count)),
+		}
+	}
+}
+
+impl FromStr for ResourceOrVar<Term, String> {
+	type Err = InvalidQuadPattern;
+
+	/// Parses a single pattern item, either a variable `?name` or a term.
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let mut parser = TermParser::new(s);
+		let item = next_item(&mut parser)?.ok_or(InvalidQuadPattern::WrongTermCount(0))?;
+
+		parser.skip_whitespaces();
+		match parser.chars.next() {
+			None => Ok(item),
+			Some((i, c)) => Err(InvalidQuadPattern::UnexpectedChar(i, c)),
+		}
+	}
+}
+
+fn next_item(
+	parser: &mut TermParser,
+) -> Result<Option<ResourceOrVar<Term, String>>, InvalidQuadPattern> {
+	parser.skip_whitespaces();
+	match parser.chars.peek().copied() {
+		None => Ok(None),
+		Some((i, '.')) => {
+			parser.chars.next();
+			parser.skip_whitespaces();
+			match parser.chars.next() {
+				None => Ok(None),
+				Some(_) => Err(InvalidQuadPattern::UnexpectedChar(i, '.')),
+			}
+		}
+		Some((i, '?')) => {
+			parser.chars.next();
+			let name = parser.take_while(|c| c.is_alphanumeric() || c == '_');
+			if name.is_empty() {
+				Err(InvalidQuadPattern::EmptyVariable(i))
+			} else {
+				Ok(Some(ResourceOrVar::Var(name.to_owned())))
+			}
+		}
+		Some(_) => Ok(Some(ResourceOrVar::Resource(parser.term()?))),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::collections::HashSet;
+
+	use static_iref::iri;
+
+	use super::InvalidQuadPattern;
+	use crate::{
+		pattern::{CanonicalQuadPattern, ResourceOrVar},
+		term::InvalidTerm,
+		BlankIdBuf, Literal, LiteralType, Quad, RdfDisplay, Term,
+	};
+
+	#[test]
+	fn parse() {
+		let mut vars = HashSet::new();
+		let pattern = CanonicalQuadPattern::parse(
+			"?s <http://example.org/p> \"a \\\"b\\\"\"@en _:g .",
+			&mut vars,
+		)
+		.unwrap();
+
+		assert_eq!(vars, HashSet::from(["s".to_owned()]));
+
+		let expected: Quad<ResourceOrVar<Term, String>> = Quad(
+			ResourceOrVar::Var("s".to_owned()),
+			ResourceOrVar::Resource(Term::iri(iri!("http://example.org/p").to_owned())),
+			ResourceOrVar::Resource(Term::Literal(Literal::new(
+				"a \"b\"".to_owned(),
+				LiteralType::LangString("en".parse().unwrap()),
+			))),
+			Some(ResourceOrVar::Resource(Term::blank(
+				BlankIdBuf::new("_:g".to_owned()).unwrap(),
+			))),
+		);
+
+		assert_eq!(pattern, expected)
+	}
+
+	#[test]
+	fn parse_typed_literal() {
+		let mut vars = HashSet::new();
+		let pattern = CanonicalQuadPattern::parse(
+			"?s ?p \"12\"^^<http://www.w3.org/2001/XMLSchema#integer>",
+			&mut vars,
+		)
+		.unwrap();
+
+		assert!(pattern.3.is_none());
+		assert_eq!(vars.len(), 2);
+
+		let expected: ResourceOrVar<Term, String> =
+			ResourceOrVar::Resource(Term::Literal(Literal::new(
+				"12".to_owned(),
+				LiteralType::Any(iri!("http://www.w3.org/2001/XMLSchema#integer").to_owned()),
+			)));
+		assert_eq!(pattern.2, expected)
+	}
+
+	#[test]
+	fn parse_item() {
+		let var: ResourceOrVar<Term, String> = "?x".parse().unwrap();
+		assert_eq!(var, ResourceOrVar::Var("x".to_owned()));
+		assert_eq!(var.to_string(), "?x");
+
+		let iri: ResourceOrVar<Term, String> = " <http://example.org/a> ".parse().unwrap();
+		assert_eq!(
+			iri,
+			ResourceOrVar::Resource(Term::iri(iri!("http://example.org/a").to_owned()))
+		);
+		assert_eq!(iri.rdf_display().to_string(), "<http://example.org/a>");
+
+		assert!("?x ?y".parse::<ResourceOrVar<Term, String>>().is_err());
+		assert!("".parse::<ResourceOrVar<Term, String>>().is_err())
+	}
+
+	#[test]
+	fn parse_errors() {
+		let mut vars = HashSet::new();
+		assert!(matches!(
+			CanonicalQuadPattern::parse("?s ?p", &mut vars),
+			Err(InvalidQuadPattern::WrongTermCount(2))
+		));
+		assert!(matches!(
+			CanonicalQuadPattern::parse("?s ?p ?o ?g ?h", &mut vars),
+			Err(InvalidQuadPattern::WrongTermCount(5))
+		));
+		assert!(matches!(
+			CanonicalQuadPattern::parse("?s <not an iri> ?o", &mut vars),
+			Err(InvalidQuadPattern::InvalidTerm(InvalidTerm::InvalidIri(_)))
+		));
+		assert!(matches!(
+			CanonicalQuadPattern::parse("?s ? ?o", &mut vars),
+			Err(InvalidQuadPattern::EmptyVariable(3))
+		));
+	}
+}
@@ -1,4 +1,6 @@
-use crate::vocabulary::EmbedIntoVocabulary;
+use std::{fmt, io};
+
+use crate::{vocabulary::EmbedIntoVocabulary, RdfDisplay};
 
 /// Resource or variable.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -23,6 +25,20 @@ impl<T, X> ResourceOrVar<T, X> {
 		}
 	}
 
+	pub fn as_var(&self) -> Option<&X> {
+		match self {
+			Self::Resource(_) => None,
+			Self::Var(x) => Some(x),
+		}
+	}
+
+	pub fn into_var(self) -> Option<X> {
+		match self {
+			Self::Resource(_) => None,
+			Self::Var(x) => Some(x),
+		}
+	}
+
 	pub fn is_id_or(&self, f: impl FnOnce(&X) -> bool) -> bool {
 		match self {
 			Self::Resource(_) => true,
@@ -37,6 +53,31 @@ impl<T, X> From<T> for ResourceOrVar<T, X> {
 	}
 }
 
+impl<T: fmt::Display, X: fmt::Display> fmt::Display for ResourceOrVar<T, X> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::Resource(t) => t.fmt(f),
+			Self::Var(x) => write!(f, "?{x}"),
+		}
+	}
+}
+
+impl<T: RdfDisplay, X: fmt::Display> RdfDisplay for ResourceOrVar<T, X> {
+	fn rdf_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::Resource(t) => t.rdf_fmt(f),
+			Self::Var(x) => write!(f, "?{x}"),
+		}
+	}
+
+	fn rdf_write<W: io::Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+		match self {
+			Self::Resource(t) => t.rdf_write(writer),
+			Self::Var(x) => write!(writer, "?{x}"),
+		}
+	}
+}
+
 impl<V, T: EmbedIntoVocabulary<V>, X> EmbedIntoVocabulary<V> for ResourceOrVar<T, X> {
 	type Embedded = ResourceOrVar<T::Embedded, X>;
 
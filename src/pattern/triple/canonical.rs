@@ -1,8 +1,10 @@
+use std::fmt;
+
 use replace_with::replace_with_or_abort_and_return;
 
 use crate::{
 	pattern::{quad, CanonicalQuadPattern, ResourceOrVar, TriplePattern},
-	Triple,
+	RdfDisplay, Triple,
 };
 
 /// Canonical triple pattern.
@@ -356,6 +358,146 @@ impl<T> CanonicalTriplePattern<T> {
 		self.set_object(o);
 		self
 	}
+
+	/// Checks if the given triple matches this pattern.
+	pub fn matches(&self, Triple(s, p, o): Triple<&T>) -> bool
+	where
+		T: PartialEq,
+	{
+		let subject = match self.subject() {
+			PatternSubject::Any => true,
+			PatternSubject::Given(id) => id == s,
+		};
+
+		let predicate = match self.predicate() {
+			PatternPredicate::Any => true,
+			PatternPredicate::SameAsSubject => p == s,
+			PatternPredicate::Given(id) => id == p,
+		};
+
+		let object = match self.object() {
+			PatternObject::Any => true,
+			PatternObject::SameAsSubject => o == s,
+			PatternObject::SameAsPredicate => o == p,
+			PatternObject::Given(id) => id == o,
+		};
+
+		subject && predicate && object
+	}
+
+	/// Intersects this pattern with `other`.
+	///
+	/// Returns the pattern matching exactly the triples matched by both
+	/// patterns, or `None` if no triple can match both (for instance if they
+	/// give different subjects).
+	pub fn intersect(self, other: Self) -> Option<Self>
+	where
+		T: Clone + PartialEq,
+	{
+		fn find(parents: &[usize; 3], mut i: usize) -> usize {
+			while parents[i] != i {
+				i = parents[i]
+			}
+
+			i
+		}
+
+		fn union(parents: &mut [usize; 3], i: usize, j: usize) {
+			let (i, j) = (find(parents, i), find(parents, j));
+			parents[i.max(j)] = i.min(j)
+		}
+
+		fn bind<T: PartialEq>(values: &mut [Option<T>; 3], i: usize, value: T) -> bool {
+			match &values[i] {
+				Some(current) => *current == value,
+				None => {
+					values[i] = Some(value);
+					true
+				}
+			}
+		}
+
+		// Positions are unified when a pattern requires them to be equal.
+		let mut parents = [0, 1, 2];
+		let mut values: [Option<T>; 3] = [None, None, None];
+
+		for pattern in [self, other] {
+			if let PatternSubject::Given(s) = pattern.subject() {
+				if !bind(&mut values, 0, s.clone()) {
+					return None;
+				}
+			}
+
+			match pattern.predicate() {
+				PatternPredicate::Any => (),
+				PatternPredicate::SameAsSubject => union(&mut parents, 1, 0),
+				PatternPredicate::Given(p) => {
+					if !bind(&mut values, 1, p.clone()) {
+						return None;
+					}
+				}
+			}
+
+			match pattern.into_object() {
+				PatternObject::Any => (),
+				PatternObject::SameAsSubject => union(&mut parents, 2, 0),
+				PatternObject::SameAsPredicate => union(&mut parents, 2, 1),
+				PatternObject::Given(o) => {
+					if !bind(&mut values, 2, o) {
+						return None;
+					}
+				}
+			}
+		}
+
+		// Every position of a class must be given the same value.
+		let mut class_values: [Option<T>; 3] = [None, None, None];
+		for (i, value) in values.into_iter().enumerate() {
+			if let Some(value) = value {
+				if !bind(&mut class_values, find(&parents, i), value) {
+					return None;
+				}
+			}
+		}
+
+		let position = |i: usize| {
+			let class = find(&parents, i);
+			match &class_values[class] {
+				Some(value) => ResourceOrVar::Resource(value.clone()),
+				None => ResourceOrVar::Var(class),
+			}
+		};
+
+		Some(Self::from_pattern(Triple(
+			position(0),
+			position(1),
+			position(2),
+		)))
+	}
+}
+
+/// Displays the pattern with the variables named after the position where they
+/// first appear (`?s`, `?p` and `?o`).
+impl<T: RdfDisplay> fmt::Display for CanonicalTriplePattern<T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self.subject() {
+			PatternSubject::Any => write!(f, "?s")?,
+			PatternSubject::Given(s) => s.rdf_fmt(f)?,
+		}
+
+		match self.predicate() {
+			PatternPredicate::Any => write!(f, " ?p")?,
+			PatternPredicate::SameAsSubject => write!(f, " ?s")?,
+			PatternPredicate::Given(p) => write!(f, " {}", p.rdf_display())?,
+		}
+
+		match self.object() {
+			PatternObject::Any => write!(f, " ?o"),
+			PatternObject::SameAsSubject => write!(f, " ?s"),
+			PatternObject::SameAsPredicate => write!(f, " ?p"),
+			PatternObject::Given(o) => write!(f, " {}", o.rdf_display()),
+		}
+	}
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -1000,3 +1142,69 @@ impl<T> GivenSubjectGivenPredicate<T> {
 		std::mem::replace(self, Self::GivenObject(t)).into_object()
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use static_iref::iri;
+
+	use crate::{
+		pattern::{CanonicalTriplePattern, ResourceOrVar},
+		Term, Triple,
+	};
+
+	#[test]
+	fn intersect() {
+		let resources = [0u32, 1, 2];
+		let terms = || {
+			[0u32, 1]
+				.into_iter()
+				.map(ResourceOrVar::Resource)
+				.chain((0..3usize).map(ResourceOrVar::Var))
+		};
+
+		let mut patterns = Vec::new();
+		for s in terms() {
+			for p in terms() {
+				for o in terms() {
+					patterns.push(CanonicalTriplePattern::from_pattern(Triple(s, p, o)))
+				}
+			}
+		}
+
+		for &a in &patterns {
+			for &b in &patterns {
+				let intersection = a.intersect(b);
+				let mut matched = false;
+
+				for s in &resources {
+					for p in &resources {
+						for o in &resources {
+							let triple = Triple(s, p, o);
+							let expected = a.matches(triple) && b.matches(triple);
+							let found = intersection.is_some_and(|c| c.matches(triple));
+							assert_eq!(found, expected, "{a:?} & {b:?} on {triple:?}");
+							matched |= found
+						}
+					}
+				}
+
+				assert_eq!(intersection.is_some(), matched, "{a:?} & {b:?}")
+			}
+		}
+	}
+
+	#[test]
+	fn display() {
+		let a = Term::iri(iri!("http://example.org/a").to_owned());
+		let pattern: CanonicalTriplePattern<Term> = Triple(
+			ResourceOrVar::Var('x'),
+			ResourceOrVar::Var('y'),
+			ResourceOrVar::Var('y'),
+		)
+		.into();
+		assert_eq!(pattern.to_string(), "?s ?p ?p");
+
+		let pattern: CanonicalTriplePattern<Term> = Triple(None, Some(a), None).into();
+		assert_eq!(pattern.to_string(), "?s <http://example.org/a> ?o")
+	}
+}
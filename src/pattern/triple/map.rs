@@ -1,3 +1,6 @@
+//! Triple pattern maps.
+//!
+//! See [`TriplePatternMap`].
 use crate::Triple;
 use educe::Educe;
 use std::collections::{HashMap, HashSet};
@@ -9,6 +12,40 @@ use super::canonical::{
 };
 
 /// Data-structure that maps triple patterns to values.
+///
+/// Each value is associated to one or more [`CanonicalTriplePattern`]s. Given
+/// a triple, the map can then efficiently find all the values associated to a
+/// pattern matching this triple, without testing each pattern one by one. This
+/// is useful, for instance, to index the rules of a rule engine by the pattern
+/// triggering them.
+///
+/// Values are stored in sets, meaning that the same value is stored at most
+/// once per pattern.
+///
+/// # Example
+///
+/// ```
+/// use rdf_types::{Triple, pattern::{CanonicalTriplePattern, TriplePatternMap, ResourceOrVar}};
+///
+/// let mut map = TriplePatternMap::default();
+///
+/// // Pattern `?x 1 ?x`.
+/// map.insert(CanonicalTriplePattern::from_pattern(Triple(
+///   ResourceOrVar::Var(0),
+///   ResourceOrVar::Resource(1),
+///   ResourceOrVar::Var(0)
+/// )), "reflexive");
+///
+/// // Pattern `?x 1 ?y`.
+/// map.insert(CanonicalTriplePattern::from_pattern(Triple(
+///   ResourceOrVar::Var(0),
+///   ResourceOrVar::Resource(1),
+///   ResourceOrVar::Var(1)
+/// )), "any");
+///
+/// assert_eq!(map.matching(Triple(&0, &1, &2)).collect::<Vec<_>>(), [&"any"]);
+/// assert_eq!(map.matching(Triple(&0, &1, &0)).count(), 2);
+/// ```
 #[derive(Debug, Educe)]
 #[educe(Default)]
 pub struct TriplePatternMap<V, T> {
@@ -16,7 +53,18 @@ pub struct TriplePatternMap<V, T> {
 	given: HashMap<T, GivenSubjectMap<V, T>>,
 }
 
+impl<V, T> TriplePatternMap<V, T> {
+	/// Creates a new empty map.
+	pub fn new() -> Self {
+		Self::default()
+	}
+}
+
 impl<V: Eq + Hash, T: Eq + Hash> TriplePatternMap<V, T> {
+	/// Associates the given value to the given pattern.
+	///
+	/// Returns `true` if the value was not already associated to this
+	/// pattern, and `false` if it was.
 	pub fn insert(&mut self, pattern: CanonicalTriplePattern<T>, value: V) -> bool {
 		match pattern {
 			CanonicalTriplePattern::AnySubject(rest) => self.any.insert(rest, value),
@@ -28,14 +76,29 @@ impl<V: Eq + Hash, T: Eq + Hash> TriplePatternMap<V, T> {
 }
 
 impl<V, T: Eq + Hash> TriplePatternMap<V, T> {
-	pub fn get(&self, triple: Triple<&T>) -> Values<V> {
+	/// Returns an iterator over all the values associated to a pattern
+	/// matching the given triple.
+	///
+	/// A value associated to more than one matching pattern is yielded once
+	/// for each matching pattern.
+	pub fn matching(&self, triple: Triple<&T>) -> Values<'_, V> {
 		Values {
 			any: self.any.get(triple),
 			given: self.given.get(triple.subject()).map(|s| s.get(triple)),
 		}
 	}
+
+	/// Returns an iterator over all the values associated to a pattern
+	/// matching the given triple.
+	///
+	/// This is the same as [`Self::matching`].
+	pub fn get(&self, triple: Triple<&T>) -> Values<'_, V> {
+		self.matching(triple)
+	}
 }
 
+/// Iterator over the values of a [`TriplePatternMap`] associated to patterns
+/// matching a given triple.
 pub struct Values<'a, V> {
 	any: AnySubjectValues<'a, V>,
 	given: Option<GivenSubjectValues<'a, V>>,
@@ -441,3 +504,60 @@ impl<'a, V> Iterator for AnySubjectGivenPredicateValues<'a, V> {
 			.or_else(|| self.given.as_mut().and_then(|i| i.next()))
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use crate::{
+		pattern::{CanonicalTriplePattern, ResourceOrVar},
+		Triple,
+	};
+
+	use super::TriplePatternMap;
+
+	#[test]
+	fn matching() {
+		let resources = [0u32, 1, 2];
+		let terms = || {
+			resources
+				.iter()
+				.copied()
+				.map(ResourceOrVar::Resource)
+				.chain((0..3usize).map(ResourceOrVar::Var))
+		};
+
+		let mut patterns = Vec::new();
+		for s in terms() {
+			for p in terms() {
+				for o in terms() {
+					patterns.push(CanonicalTriplePattern::from_pattern(Triple(s, p, o)))
+				}
+			}
+		}
+
+		let mut map = TriplePatternMap::new();
+		for (i, &pattern) in patterns.iter().enumerate() {
+			map.insert(pattern, i);
+		}
+
+		for &s in &resources {
+			for &p in &resources {
+				for &o in &resources {
+					let triple = Triple(&s, &p, &o);
+
+					let mut expected: Vec<_> = patterns
+						.iter()
+						.enumerate()
+						.filter(|(_, pattern)| pattern.matches(triple))
+						.map(|(i, _)| i)
+						.collect();
+
+					let mut found: Vec<_> = map.matching(triple).copied().collect();
+
+					expected.sort_unstable();
+					found.sort_unstable();
+					assert_eq!(found, expected)
+				}
+			}
+		}
+	}
+}
@@ -0,0 +1,328 @@
+//! Pretty RDF display with prefixed IRIs.
+use std::fmt;
+
+use iref::{Iri, IriBuf};
+
+use crate::{
+	BlankId, BlankIdBuf, Id, IsXsdStringIri, Literal, LiteralType, RdfDisplay, Term, Triple,
+};
+
+/// Prefix map.
+///
+/// Maps prefixes to IRI namespaces, used to abbreviate IRIs into
+/// `prefix:local` form.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct PrefixMap {
+	prefixes: Vec<(String, IriBuf)>,
+}
+
+impl PrefixMap {
+	/// Creates a new empty prefix map.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Returns the number of prefixes.
+	pub fn len(&self) -> usize {
+		self.prefixes.len()
+	}
+
+	/// Checks if the prefix map is empty.
+	pub fn is_empty(&self) -> bool {
+		self.prefixes.is_empty()
+	}
+
+	/// Binds the given prefix to the given namespace.
+	///
+	/// Returns the namespace previously bound to this prefix, if any.
+	pub fn insert(&mut self, prefix: impl Into<String>, namespace: IriBuf) -> Option<IriBuf> {
+		let prefix = prefix.into();
+		match self.prefixes.iter_mut().find(|(p, _)| *p == prefix) {
+			Some((_, ns)) => Some(std::mem::replace(ns, namespace)),
+			None => {
+				self.prefixes.push((prefix, namespace));
+				None
+			}
+		}
+	}
+
+	/// Returns the namespace bound to the given prefix, if any.
+	pub fn get(&self, prefix: &str) -> Option<&Iri> {
+		self.prefixes
+			.iter()
+			.find(|(p, _)| p == prefix)
+			.map(|(_, ns)| ns.as_iri())
+	}
+
+	/// Returns an iterator over the prefixes and their namespace, in
+	/// insertion order.
+	pub fn iter(&self) -> impl Iterator<Item = (&str, &Iri)> {
+		self.prefixes
+			.iter()
+			.map(|(p, ns)| (p.as_str(), ns.as_iri()))
+	}
+
+	/// Abbreviates the given IRI into a `(prefix, local)` pair.
+	///
+	/// The longest matching namespace is selected. Returns `None` if no
+	/// namespace matches, or if the remaining local part cannot be written
+	/// as a prefixed name.
+	pub fn abbreviate<'a>(&'a self, iri: &'a Iri) -> Option<(&'a str, &'a str)> {
+		self.prefixes
+			.iter()
+			.filter_map(|(p, ns)| {
+				let local = iri.as_str().strip_prefix(ns.as_str())?;
+				is_local_name(local).then_some((p.as_str(), local))
+			})
+			.min_by_key(|(_, local)| local.len())
+	}
+}
+
+/// Checks if the given string can be written as the local part of a prefixed
+/// name without escaping.
+fn is_local_name(s: &str) -> bool {
+	let mut chars = s.chars();
+	match chars.next() {
+		None => true,
+		Some(c) if c.is_ascii_alphanumeric() || c == '_' => {
+			chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+		}
+		Some(_) => false,
+	}
+}
+
+/// Display method for RDF syntax elements, abbreviating IRIs using a
+/// [`PrefixMap`].
+pub trait PrettyRdfDisplay {
+	/// Formats the value using the given prefixes and formatter.
+	fn pretty_rdf_fmt(&self, prefixes: &PrefixMap, f: &mut fmt::Formatter) -> fmt::Result;
+
+	/// Prepare the value to be formatted as an RDF syntax element using the
+	/// given prefixes.
+	#[inline(always)]
+	fn pretty_rdf_display<'a>(&'a self, prefixes: &'a PrefixMap) -> PrettyRdfDisplayed<'a, Self> {
+		PrettyRdfDisplayed(self, prefixes)
+	}
+}
+
+/// Value ready to be formatted as an RDF syntax element with prefixed IRIs.
+pub struct PrettyRdfDisplayed<'a, T: ?Sized>(&'a T, &'a PrefixMap);
+
+impl<'a, T: PrettyRdfDisplay + ?Sized> fmt::Display for PrettyRdfDisplayed<'a, T> {
+	#[inline(always)]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.0.pretty_rdf_fmt(self.1, f)
+	}
+}
+
+impl<T: PrettyRdfDisplay + ?Sized> PrettyRdfDisplay for &T {
+	#[inline(always)]
+	fn pretty_rdf_fmt(&self, prefixes: &PrefixMap, f: &mut fmt::Formatter) -> fmt::Result {
+		T::pretty_rdf_fmt(*self, prefixes, f)
+	}
+}
+
+impl PrettyRdfDisplay for Iri {
+	fn pretty_rdf_fmt(&self, prefixes: &PrefixMap, f: &mut fmt::Formatter) -> fmt::Result {
+		match prefixes.abbreviate(self) {
+			Some((prefix, local)) => write!(f, "{prefix}:{local}"),
+			None => self.rdf_fmt(f),
+		}
+	}
+}
+
+impl PrettyRdfDisplay for IriBuf {
+	#[inline(always)]
+	fn pretty_rdf_fmt(&self, prefixes: &PrefixMap, f: &mut fmt::Formatter) -> fmt::Result {
+		self.as_iri().pretty_rdf_fmt(prefixes, f)
+	}
+}
+
+impl PrettyRdfDisplay for BlankId {
+	#[inline(always)]
+	fn pretty_rdf_fmt(&self, _prefixes: &PrefixMap, f: &mut fmt::Formatter) -> fmt::Result {
+		fmt::Display::fmt(self, f)
+	}
+}
+
+impl PrettyRdfDisplay for BlankIdBuf {
+	#[inline(always)]
+	fn pretty_rdf_fmt(&self, _prefixes: &PrefixMap, f: &mut fmt::Formatter) -> fmt::Result {
+		fmt::Display::fmt(self, f)
+	}
+}
+
+impl<I: PrettyRdfDisplay, B: PrettyRdfDisplay> PrettyRdfDisplay for Id<I, B> {
+	fn pretty_rdf_fmt(&self, prefixes: &PrefixMap, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::Blank(id) => id.pretty_rdf_fmt(prefixes, f),
+			Self::Iri(iri) => iri.pretty_rdf_fmt(prefixes, f),
+		}
+	}
+}
+
+impl<I: PrettyRdfDisplay + IsXsdStringIri> PrettyRdfDisplay for Literal<I> {
+	fn pretty_rdf_fmt(&self, prefixes: &PrefixMap, f: &mut fmt::Formatter) -> fmt::Result {
+		self.as_str().rdf_fmt(f)?;
+		match &self.type_ {
+			LiteralType::Any(iri) if iri.is_xsd_string_iri() => Ok(()),
+			LiteralType::Any(iri) => {
+				write!(f, "^^")?;
+				iri.pretty_rdf_fmt(prefixes, f)
+			}
+			LiteralType::LangString(tag) => write!(f, "@{tag}"),
+			LiteralType::DirLangString(tag, direction) => write!(f, "@{tag}--{direction}"),
+		}
+	}
+}
+
+impl<I: PrettyRdfDisplay, L: PrettyRdfDisplay> PrettyRdfDisplay for Term<I, L> {
+	fn pretty_rdf_fmt(&self, prefixes: &PrefixMap, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::Id(id) => id.pretty_rdf_fmt(prefixes, f),
+			Self::Literal(lit) => lit.pretty_rdf_fmt(prefixes, f),
+		}
+	}
+}
+
+impl<S: PrettyRdfDisplay, P: PrettyRdfDisplay, O: PrettyRdfDisplay> PrettyRdfDisplay
+	for Triple<S, P, O>
+{
+	fn pretty_rdf_fmt(&self, prefixes: &PrefixMap, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(
+			f,
+			"{} {} {}",
+			self.0.pretty_rdf_display(prefixes),
+			self.1.pretty_rdf_display(prefixes),
+			self.2.pretty_rdf_display(prefixes)
+		)
+	}
+}
+
+/// Formats the prefix declarations of the given prefix map, followed by the
+/// given triples, grouping triples sharing the same subject (and predicate).
+///
+/// Triples are expected to be sorted, or at least grouped by subject then
+/// predicate.
+pub(crate) fn pretty_rdf_fmt_triples<'a, R: 'a + PrettyRdfDisplay + PartialEq>(
+	triples: impl IntoIterator<Item = Triple<&'a R>>,
+	prefixes: &PrefixMap,
+	f: &mut fmt::Formatter,
+) -> fmt::Result {
+	for (prefix, namespace) in prefixes.iter() {
+		writeln!(f, "@prefix {prefix}: {} .", namespace.rdf_display())?;
+	}
+
+	let mut current: Option<(&R, &R)> = None;
+	for Triple(s, p, o) in triples {
+		match current {
+			Some((cs, cp)) if cs == s && cp == p => write!(f, ", ")?,
+			Some((cs, _)) if cs == s => {
+				write!(f, " ;\n\t{} ", p.pretty_rdf_display(prefixes))?;
+			}
+			other => {
+				if other.is_some() {
+					writeln!(f, " .")?;
+				}
+
+				if other.is_some() || !prefixes.is_empty() {
+					writeln!(f)?;
+				}
+
+				write!(
+					f,
+					"{} {} ",
+					s.pretty_rdf_display(prefixes),
+					p.pretty_rdf_display(prefixes)
+				)?;
+			}
+		}
+
+		o.pretty_rdf_fmt(prefixes, f)?;
+		current = Some((s, p));
+	}
+
+	if current.is_some() {
+		writeln!(f, " .")?;
+	}
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use static_iref::iri;
+
+	use super::{PrefixMap, PrettyRdfDisplay};
+	use crate::{dataset::BTreeGraph, Literal, LiteralType, Term, Triple, XSD_STRING};
+
+	fn prefixes() -> PrefixMap {
+		let mut prefixes = PrefixMap::new();
+		prefixes.insert("ex", iri!("http://example.org/").to_owned());
+		prefixes.insert("exv", iri!("http://example.org/vocab#").to_owned());
+		prefixes.insert("xsd", iri!("http://www.w3.org/2001/XMLSchema#").to_owned());
+		prefixes
+	}
+
+	#[test]
+	fn abbreviate() {
+		let prefixes = prefixes();
+		assert_eq!(
+			prefixes.abbreviate(iri!("http://example.org/vocab#name")),
+			Some(("exv", "name"))
+		);
+		assert_eq!(
+			prefixes.abbreviate(iri!("http://example.org/a")),
+			Some(("ex", "a"))
+		);
+		assert_eq!(prefixes.abbreviate(iri!("http://example.org/a/b")), None);
+		assert_eq!(prefixes.abbreviate(iri!("http://example.com/a")), None);
+	}
+
+	#[test]
+	fn graph() {
+		let iri = |s: &str| Term::iri(iref::IriBuf::new(s.to_owned()).unwrap());
+		let graph: BTreeGraph = [
+			Triple(
+				iri("http://example.org/a"),
+				iri("http://example.org/vocab#name"),
+				Term::Literal(Literal::new(
+					"A".to_owned(),
+					LiteralType::Any(XSD_STRING.to_owned()),
+				)),
+			),
+			Triple(
+				iri("http://example.org/a"),
+				iri("http://example.org/vocab#knows"),
+				iri("http://example.org/b"),
+			),
+			Triple(
+				iri("http://example.org/a"),
+				iri("http://example.org/vocab#knows"),
+				iri("http://example.com/c"),
+			),
+			Triple(
+				iri("http://example.org/b"),
+				iri("http://example.org/vocab#age"),
+				Term::Literal(Literal::new(
+					"12".to_owned(),
+					LiteralType::Any(iri!("http://www.w3.org/2001/XMLSchema#integer").to_owned()),
+				)),
+			),
+		]
+		.into_iter()
+		.collect();
+
+		assert_eq!(
+			graph.pretty_rdf_display(&prefixes()).to_string(),
+			"@prefix ex: <http://example.org/> .\n\
+			@prefix exv: <http://example.org/vocab#> .\n\
+			@prefix xsd: <http://www.w3.org/2001/XMLSchema#> .\n\
+			\n\
+			ex:a exv:knows <http://example.com/c>, ex:b ;\n\
+			\texv:name \"A\" .\n\
+			\n\
+			ex:b exv:age \"12\"^^xsd:integer .\n"
+		)
+	}
+}
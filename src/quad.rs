@@ -1,4 +1,9 @@
-use std::{cmp::Ordering, fmt};
+use std::{
+	cmp::Ordering,
+	fmt,
+	hash::{Hash, Hasher},
+	io,
+};
 
 use iref::{Iri, IriBuf};
 
@@ -9,7 +14,7 @@ use crate::{
 		ExtractedFromVocabulary, TryExtractFromVocabulary,
 	},
 	GraphLabel, Id, Interpretation, LexicalGraphLabelRef, LexicalObjectRef, LexicalSubjectRef,
-	Object, RdfDisplay, Term, Triple,
+	Object, RdfDisplay, Term, Triple, TryAsIri,
 };
 
 #[cfg(feature = "contextual")]
@@ -28,6 +33,7 @@ pub type LexicalQuadRef<'a> =
 /// RDF quad.
 #[derive(Clone, Copy, Eq, Ord, Hash, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Quad<S = Term, P = S, O = S, G = S>(pub S, pub P, pub O, pub Option<G>);
 
 impl<S, P, O, G> Quad<S, P, O, G> {
@@ -40,6 +46,23 @@ impl<S, P, O, G> Quad<S, P, O, G> {
 	pub fn as_ref(&self) -> Quad<&S, &P, &O, &G> {
 		Quad(&self.0, &self.1, &self.2, self.3.as_ref())
 	}
+
+	/// Borrows the subject, predicate and object components of the quad as a
+	/// triple.
+	pub fn as_triple_ref(&self) -> Triple<&S, &P, &O> {
+		Triple(&self.0, &self.1, &self.2)
+	}
+
+	/// Checks that this quad and `other` have the same subject, predicate
+	/// and object, ignoring the graph component.
+	pub fn triple_eq<S2, P2, O2, G2>(&self, other: &Quad<S2, P2, O2, G2>) -> bool
+	where
+		S: PartialEq<S2>,
+		P: PartialEq<P2>,
+		O: PartialEq<O2>,
+	{
+		self.0 == other.0 && self.1 == other.1 && self.2 == other.2
+	}
 }
 
 impl<'s, 'p, 'o, 'g, S, P, O, G> Quad<&'s S, &'p P, &'o O, &'g G> {
@@ -236,6 +259,16 @@ impl<S, P, O, G> Quad<S, P, O, G> {
 		self.3
 	}
 
+	/// Returns a reference to the graph of the quad as a [`QuadGraph`].
+	pub fn graph_label(&self) -> QuadGraph<&G> {
+		self.3.as_ref().into()
+	}
+
+	/// Turns the quad into its graph as a [`QuadGraph`].
+	pub fn into_graph_label(self) -> QuadGraph<G> {
+		self.3.into()
+	}
+
 	pub fn into_parts(self) -> (S, P, O, Option<G>) {
 		(self.0, self.1, self.2, self.3)
 	}
@@ -278,11 +311,182 @@ impl<S, P, O, G> Quad<S, P, O, G> {
 	}
 }
 
+/// Graph of a quad.
+///
+/// This is equivalent to the `Option<G>` graph component of [`Quad`], where
+/// `None` stands for the default graph, but names the default graph
+/// explicitly.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum QuadGraph<G> {
+	/// Default graph.
+	#[default]
+	Default,
+
+	/// Named graph.
+	Named(G),
+}
+
+impl<G> QuadGraph<G> {
+	/// Checks if this is the default graph.
+	pub fn is_default(&self) -> bool {
+		matches!(self, Self::Default)
+	}
+
+	/// Checks if this is a named graph.
+	pub fn is_named(&self) -> bool {
+		matches!(self, Self::Named(_))
+	}
+
+	/// Returns the graph name, if any.
+	pub fn as_named(&self) -> Option<&G> {
+		match self {
+			Self::Named(g) => Some(g),
+			Self::Default => None,
+		}
+	}
+
+	/// Turns this graph into its name, if any.
+	pub fn into_named(self) -> Option<G> {
+		match self {
+			Self::Named(g) => Some(g),
+			Self::Default => None,
+		}
+	}
+
+	/// Borrows the graph name.
+	pub fn as_ref(&self) -> QuadGraph<&G> {
+		match self {
+			Self::Named(g) => QuadGraph::Named(g),
+			Self::Default => QuadGraph::Default,
+		}
+	}
+
+	/// Maps the graph name with the given function.
+	pub fn map<U>(self, f: impl FnOnce(G) -> U) -> QuadGraph<U> {
+		match self {
+			Self::Named(g) => QuadGraph::Named(f(g)),
+			Self::Default => QuadGraph::Default,
+		}
+	}
+}
+
+impl<G> From<Option<G>> for QuadGraph<G> {
+	fn from(value: Option<G>) -> Self {
+		match value {
+			Some(g) => Self::Named(g),
+			None => Self::Default,
+		}
+	}
+}
+
+impl<G> From<QuadGraph<G>> for Option<G> {
+	fn from(value: QuadGraph<G>) -> Self {
+		value.into_named()
+	}
+}
+
 impl<T> Quad<T, T, T, T> {
 	/// Maps the components with the given function.
 	pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> Quad<U, U, U, U> {
 		Quad(f(self.0), f(self.1), f(self.2), self.3.map(f))
 	}
+
+	/// Maps the components with the given function, producing two quads in
+	/// a single traversal.
+	pub fn map2<U, V>(self, mut f: impl FnMut(T) -> (U, V)) -> (Quad<U>, Quad<V>) {
+		let (s1, s2) = f(self.0);
+		let (p1, p2) = f(self.1);
+		let (o1, o2) = f(self.2);
+		let (g1, g2) = self.3.map(f).unzip();
+		(Quad(s1, p1, o1, g1), Quad(s2, p2, o2, g2))
+	}
+
+	/// Pairs the components of this quad with the components of `other`.
+	///
+	/// Returns `None` if only one of the two quads has a graph component.
+	pub fn zip<U>(self, other: Quad<U>) -> Option<Quad<(T, U)>> {
+		let g = match (self.3, other.3) {
+			(Some(a), Some(b)) => Some((a, b)),
+			(None, None) => None,
+			_ => return None,
+		};
+
+		Some(Quad(
+			(self.0, other.0),
+			(self.1, other.1),
+			(self.2, other.2),
+			g,
+		))
+	}
+
+	/// Turns the quad into its components, in order.
+	///
+	/// The graph component is optional, hence returned apart from the
+	/// subject, predicate and object, which are always present. Use
+	/// [`Self::into_triple`] to get the triple part as a [`Triple`].
+	pub fn into_components(self) -> ([T; 3], Option<T>) {
+		([self.0, self.1, self.2], self.3)
+	}
+
+	/// Compares two quads component-wise using the given resource
+	/// comparison function.
+	///
+	/// Components are compared in the same order as the derived [`Ord`]
+	/// implementation: subject, predicate, object then graph, the default
+	/// graph coming first. This is useful when the order of `T` does not
+	/// reflect the desired order, for instance when `T` is an index into a
+	/// vocabulary.
+	pub fn cmp_by(&self, other: &Self, mut cmp: impl FnMut(&T, &T) -> Ordering) -> Ordering {
+		cmp(&self.0, &other.0)
+			.then_with(|| cmp(&self.1, &other.1))
+			.then_with(|| cmp(&self.2, &other.2))
+			.then_with(|| match (&self.3, &other.3) {
+				(Some(a), Some(b)) => cmp(a, b),
+				(a, b) => a.is_some().cmp(&b.is_some()),
+			})
+	}
+
+	/// Feeds this quad into the given [`Hasher`], using the given function
+	/// to hash each resource.
+	///
+	/// This is useful when the [`Hash`] implementation of `T` does not
+	/// reflect the desired hash, for instance when `T` is an index into a
+	/// vocabulary: hashing its lexical representation instead makes the
+	/// quad hash independent of the interning order.
+	///
+	/// ```
+	/// use std::{collections::hash_map::DefaultHasher, hash::{Hash, Hasher}};
+	/// use rdf_types::Quad;
+	///
+	/// let names = ["a", "b", "c"];
+	/// let hash = |quad: Quad<usize>| {
+	///     let mut hasher = DefaultHasher::new();
+	///     quad.hash_with(&mut hasher, |i, h| names[*i].hash(h));
+	///     hasher.finish()
+	/// };
+	///
+	/// let other_names = ["c", "a", "b"];
+	/// let mut hasher = DefaultHasher::new();
+	/// Quad(1, 2, 0, None).hash_with(&mut hasher, |i, h| other_names[*i].hash(h));
+	///
+	/// assert_eq!(hash(Quad(0, 1, 2, None)), hasher.finish());
+	/// ```
+	pub fn hash_with<H: Hasher>(&self, state: &mut H, mut hash_resource: impl FnMut(&T, &mut H)) {
+		hash_resource(&self.0, state);
+		hash_resource(&self.1, state);
+		hash_resource(&self.2, state);
+		self.3.is_some().hash(state);
+		if let Some(g) = &self.3 {
+			hash_resource(g, state)
+		}
+	}
+}
+
+/// Sorts the given quads using the given resource comparison function.
+///
+/// See [`Quad::cmp_by`].
+pub fn sort_quads_by<T>(quads: &mut [Quad<T>], mut cmp: impl FnMut(&T, &T) -> Ordering) {
+	quads.sort_by(|a, b| a.cmp_by(b, &mut cmp))
 }
 
 impl<S: Interpret<I>, P: Interpret<I>, O: Interpret<I>, G: Interpret<I>, I: Interpretation>
@@ -300,6 +504,18 @@ impl<S: Interpret<I>, P: Interpret<I>, O: Interpret<I>, G: Interpret<I>, I: Inte
 	}
 }
 
+impl<S, P, O, G> From<(S, P, O, Option<G>)> for Quad<S, P, O, G> {
+	fn from((s, p, o, g): (S, P, O, Option<G>)) -> Self {
+		Self(s, p, o, g)
+	}
+}
+
+impl<S, P, O, G> From<(Triple<S, P, O>, Option<G>)> for Quad<S, P, O, G> {
+	fn from((Triple(s, p, o), g): (Triple<S, P, O>, Option<G>)) -> Self {
+		Self(s, p, o, g)
+	}
+}
+
 impl<
 		V,
 		S: ExtractFromVocabulary<V>,
@@ -364,6 +580,56 @@ impl<
 	}
 }
 
+impl<I: TryAsIri, L> Quad<Term<I, L>> {
+	/// Checks that this quad is a valid RDF quad.
+	///
+	/// Generalized RDF allows any term at any position. Strict RDF requires
+	/// the subject and graph label to be IRIs or blank node identifiers, and
+	/// the predicate to be an IRI.
+	pub fn validate_rdf(&self) -> Result<(), RdfShapeError> {
+		if self.0.is_literal() {
+			return Err(RdfShapeError::LiteralSubject);
+		}
+
+		if !self.1.is_iri() {
+			return Err(RdfShapeError::NonIriPredicate);
+		}
+
+		if self.3.as_ref().is_some_and(Term::is_literal) {
+			return Err(RdfShapeError::LiteralGraph);
+		}
+
+		Ok(())
+	}
+}
+
+impl Quad {
+	/// Reifies the triple of this quad using the given `statement` node.
+	///
+	/// The reification quads are put in the same graph as this quad.
+	/// See [`Triple::reify`].
+	pub fn reify(self, statement: Term) -> [Quad; 4] {
+		let Quad(s, p, o, g) = self;
+		Triple(s, p, o)
+			.reify(statement)
+			.map(|triple| triple.into_quad(g.clone()))
+	}
+}
+
+/// Error returned by [`Quad::validate_rdf`] and [`Triple::validate_rdf`] when
+/// a term is not allowed at its position in strict RDF.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum RdfShapeError {
+	#[error("invalid subject: expected an IRI or blank node identifier, found a literal")]
+	LiteralSubject,
+
+	#[error("invalid predicate: expected an IRI")]
+	NonIriPredicate,
+
+	#[error("invalid graph label: expected an IRI or blank node identifier, found a literal")]
+	LiteralGraph,
+}
+
 /// Type that can turn a `Quad<S, P, O, G>` into a `Quad`.
 pub trait TryExportQuad<S, P, O, G> {
 	type Error;
@@ -514,6 +780,20 @@ impl<S: RdfDisplay, P: RdfDisplay, O: RdfDisplay, G: RdfDisplay> RdfDisplay for
 			),
 		}
 	}
+
+	fn rdf_write<W: io::Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+		self.0.rdf_write(writer)?;
+		writer.write_all(b" ")?;
+		self.1.rdf_write(writer)?;
+		writer.write_all(b" ")?;
+		self.2.rdf_write(writer)?;
+		if let Some(graph) = self.graph() {
+			writer.write_all(b" ")?;
+			graph.rdf_write(writer)?;
+		}
+
+		Ok(())
+	}
 }
 
 #[cfg(feature = "contextual")]
@@ -575,3 +855,143 @@ impl<
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::{Quad, QuadGraph};
+	use crate::{Literal, LiteralType, RdfShapeError, Term, Triple, XSD_STRING};
+	use static_iref::iri;
+
+	#[test]
+	fn graph_label() {
+		let quad: Quad<u32> = Quad(0, 1, 2, None);
+		assert_eq!(quad.graph_label(), QuadGraph::Default);
+		assert!(quad.graph_label().is_default());
+
+		let quad: Quad<u32> = Quad(0, 1, 2, Some(3));
+		assert_eq!(quad.graph_label(), QuadGraph::Named(&3));
+		assert_eq!(quad.into_graph_label().into_named(), Some(3));
+
+		let graph: Option<u32> = QuadGraph::Named(3).into();
+		assert_eq!(QuadGraph::from(graph), QuadGraph::Named(3));
+		assert_eq!(Option::<u32>::from(QuadGraph::Default), None)
+	}
+
+	#[test]
+	fn validate_rdf() {
+		let iri: Term = Term::iri(iri!("http://example.org/").to_owned());
+		let blank: Term = Term::blank("_:b".parse().unwrap());
+		let literal: Term = Term::Literal(Literal::new(
+			"value".to_owned(),
+			LiteralType::Any(XSD_STRING.to_owned()),
+		));
+
+		let quad = Quad(
+			blank.clone(),
+			iri.clone(),
+			literal.clone(),
+			Some(iri.clone()),
+		);
+		assert_eq!(quad.validate_rdf(), Ok(()));
+		assert_eq!(
+			Quad(literal.clone(), iri.clone(), iri.clone(), None).validate_rdf(),
+			Err(RdfShapeError::LiteralSubject)
+		);
+		assert_eq!(
+			Quad(iri.clone(), blank.clone(), iri.clone(), None).validate_rdf(),
+			Err(RdfShapeError::NonIriPredicate)
+		);
+		assert_eq!(
+			Quad(iri.clone(), iri.clone(), iri.clone(), Some(literal)).validate_rdf(),
+			Err(RdfShapeError::LiteralGraph)
+		);
+		assert_eq!(
+			Triple(iri.clone(), iri, blank.clone()).validate_rdf(),
+			Ok(())
+		);
+	}
+
+	#[test]
+	fn from_tuples() {
+		let triples: Vec<Triple<u32>> = [(0, 1, 2), (3, 4, 5)]
+			.into_iter()
+			.map(Triple::from)
+			.collect();
+		let expected: [Triple<u32>; 2] = [Triple(0, 1, 2), Triple(3, 4, 5)];
+		assert_eq!(triples, expected);
+
+		let triple: Triple<u32> = [0, 1, 2].into();
+		assert_eq!(triple, expected[0]);
+
+		let quad: Quad<u32> = (0, 1, 2, Some(3)).into();
+		let expected: Quad<u32> = Quad(0, 1, 2, Some(3));
+		assert_eq!(quad, expected);
+
+		let quad: Quad<u32> = (triple, None).into();
+		let expected: Quad<u32> = Quad(0, 1, 2, None);
+		assert_eq!(quad, expected);
+	}
+
+	#[test]
+	fn triple_eq() {
+		let a = Quad(0, 1, 2, Some(3));
+		let b = Quad(0, 1, 2, None::<i32>);
+		let c = Quad(0, 1, 4, Some(3));
+
+		assert!(a.triple_eq(&b));
+		assert!(b.triple_eq(&a));
+		assert!(!a.triple_eq(&c));
+		assert_eq!(a.as_triple_ref(), b.as_triple_ref());
+	}
+
+	#[test]
+	fn sort_quads_by() {
+		let mut quads = [
+			Quad(1, 2, 3, Some(4)),
+			Quad(1, 2, 3, None),
+			Quad(3, 2, 1, None),
+			Quad(1, 3, 0, Some(0)),
+		];
+
+		super::sort_quads_by(&mut quads, |a, b| b.cmp(a));
+
+		assert_eq!(
+			quads,
+			[
+				Quad(3, 2, 1, None),
+				Quad(1, 3, 0, Some(0)),
+				Quad(1, 2, 3, None),
+				Quad(1, 2, 3, Some(4))
+			]
+		)
+	}
+
+	#[test]
+	fn cloned_copied() {
+		let (s, p, o, g) = (0, 1, 2, 3);
+		let named = Quad(&s, &p, &o, Some(&g));
+		let default = Quad(&s, &p, &o, None::<&i32>);
+
+		assert_eq!(named.cloned(), Quad(0, 1, 2, Some(3)));
+		assert_eq!(named.copied(), named.into_cloned());
+		assert_eq!(default.copied(), Quad(0, 1, 2, None::<i32>));
+		assert_eq!(default.into_copied(), default.cloned());
+	}
+
+	#[test]
+	fn map2_zip() {
+		let (a, b) = Quad(1, 2, 3, Some(4)).map2(|i| (i, i * 10));
+		assert_eq!(a, Quad(1, 2, 3, Some(4)));
+		assert_eq!(b, Quad(10, 20, 30, Some(40)));
+
+		assert_eq!(
+			a.zip(b),
+			Some(Quad((1, 10), (2, 20), (3, 30), Some((4, 40))))
+		);
+		assert_eq!(
+			Quad(1, 2, 3, None).zip(Quad('a', 'b', 'c', None)),
+			Some(Quad((1, 'a'), (2, 'b'), (3, 'c'), None))
+		);
+		assert_eq!(Quad(1, 2, 3, None).zip(Quad(1, 2, 3, Some(4))), None);
+	}
+}
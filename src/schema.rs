@@ -1,6 +1,13 @@
+use std::sync::OnceLock;
+
 use iref::Iri;
 use static_iref::iri;
 
+use crate::{
+	dataset::PatternMatchingDataset, pattern::CanonicalQuadPattern, Literal, LiteralType, Quad,
+	Term, XSD_STRING,
+};
+
 pub const RDFS_RESOURCE: &Iri = iri!("http://www.w3.org/2000/01/rdf-schema#Resource");
 pub const RDFS_CLASS: &Iri = iri!("http://www.w3.org/2000/01/rdf-schema#Class");
 pub const RDFS_LITERAL: &Iri = iri!("http://www.w3.org/2000/01/rdf-schema#Literal");
@@ -20,6 +27,8 @@ pub const RDFS_SEE_ALSO: &Iri = iri!("http://www.w3.org/2000/01/rdf-schema#seeAl
 pub const RDFS_IS_DEFINED_BY: &Iri = iri!("http://www.w3.org/2000/01/rdf-schema#isDefinedBy");
 
 pub const RDF_LANG_STRING: &Iri = iri!("http://www.w3.org/1999/02/22-rdf-syntax-ns#langString");
+pub const RDF_DIR_LANG_STRING: &Iri =
+	iri!("http://www.w3.org/1999/02/22-rdf-syntax-ns#dirLangString");
 pub const RDF_HTML: &Iri = iri!("http://www.w3.org/1999/02/22-rdf-syntax-ns#HTML");
 pub const RDF_XML_LITERAL: &Iri = iri!("http://www.w3.org/1999/02/22-rdf-syntax-ns#XMLLiteral");
 pub const RDF_JSON: &Iri = iri!("http://www.w3.org/1999/02/22-rdf-syntax-ns#JSON");
@@ -39,3 +48,217 @@ pub const RDF_OBJECT: &Iri = iri!("http://www.w3.org/1999/02/22-rdf-syntax-ns#ob
 pub const RDF_VALUE: &Iri = iri!("http://www.w3.org/1999/02/22-rdf-syntax-ns#value");
 
 pub const RDF_NIL: &Iri = iri!("http://www.w3.org/1999/02/22-rdf-syntax-ns#nil");
+
+/// Returns an iterator over the types of `subject` in the given graph of
+/// `dataset`, the objects of its `rdf:type` ([`RDF_TYPE`]) properties.
+pub fn types_of<'a, 'p, D>(
+	dataset: &'a D,
+	graph: Option<&'p Term>,
+	subject: &'p Term,
+) -> IriPropertyObjects<'a, 'p, D>
+where
+	D: ?Sized + PatternMatchingDataset<Resource = Term>,
+{
+	static PROPERTY: OnceLock<Term> = OnceLock::new();
+	IriPropertyObjects {
+		inner: dataset.quad_pattern_matching(CanonicalQuadPattern::from_option_quad(Quad(
+			Some(subject),
+			Some(iri_term(&PROPERTY, RDF_TYPE)),
+			None,
+			Some(graph),
+		))),
+	}
+}
+
+/// Returns an iterator over the direct subclasses of `class` in the given
+/// graph of `dataset`, the subjects of the `rdfs:subClassOf`
+/// ([`RDFS_SUB_CLASS_OF`]) properties whose object is `class`.
+///
+/// The subclass relation is not computed transitively.
+pub fn subclasses_of<'a, 'p, D>(
+	dataset: &'a D,
+	graph: Option<&'p Term>,
+	class: &'p Term,
+) -> IriPropertySubjects<'a, 'p, D>
+where
+	D: ?Sized + PatternMatchingDataset<Resource = Term>,
+{
+	static PROPERTY: OnceLock<Term> = OnceLock::new();
+	IriPropertySubjects {
+		inner: dataset.quad_pattern_matching(CanonicalQuadPattern::from_option_quad(Quad(
+			None,
+			Some(iri_term(&PROPERTY, RDFS_SUB_CLASS_OF)),
+			Some(class),
+			Some(graph),
+		))),
+	}
+}
+
+/// Returns the best `rdfs:label` ([`RDFS_LABEL`]) of `subject` in the given
+/// graph of `dataset`, for the given language.
+///
+/// Labels are ranked as follows, the first label of the best rank being
+/// returned:
+///   1. language strings tagged with `lang` (case-insensitive);
+///   2. language strings whose tag starts with `lang`, such as `en-US`
+///      for `en`;
+///   3. `xsd:string` literals;
+///   4. any other literal.
+///
+/// Labels that are not literals are ignored.
+pub fn label_of<'a, D>(
+	dataset: &'a D,
+	graph: Option<&Term>,
+	subject: &Term,
+	lang: Option<&str>,
+) -> Option<&'a Literal>
+where
+	D: ?Sized + PatternMatchingDataset<Resource = Term>,
+{
+	let rank = |literal: &Literal| match (&literal.type_, lang) {
+		(LiteralType::LangString(tag) | LiteralType::DirLangString(tag, _), Some(lang)) => {
+			let tag = tag.as_str();
+			if tag.eq_ignore_ascii_case(lang) {
+				0
+			} else if tag.len() > lang.len()
+				&& tag.as_bytes()[lang.len()] == b'-'
+				&& tag[..lang.len()].eq_ignore_ascii_case(lang)
+			{
+				1
+			} else {
+				3
+			}
+		}
+		(LiteralType::Any(ty), _) if ty.as_iri() == XSD_STRING => 2,
+		_ => 3,
+	};
+
+	dataset
+		.quad_pattern_matching(CanonicalQuadPattern::from_option_quad(Quad(
+			Some(subject),
+			None,
+			None,
+			Some(graph),
+		)))
+		.filter(|quad| is_iri(quad.1, RDFS_LABEL))
+		.filter_map(|quad| quad.2.as_literal())
+		.min_by_key(|literal| rank(literal))
+}
+
+/// Checks that the given term is the given IRI.
+fn is_iri(term: &Term, iri: &Iri) -> bool {
+	term.as_iri().is_some_and(|i| i.as_iri() == iri)
+}
+
+/// Returns the term of the given IRI, stored in `cell`.
+fn iri_term(cell: &'static OnceLock<Term>, iri: &'static Iri) -> &'static Term {
+	cell.get_or_init(|| Term::iri(iri.to_owned()))
+}
+
+/// Iterator over the objects of an IRI property.
+///
+/// See [`types_of`].
+pub struct IriPropertyObjects<'a, 'p, D: 'a + ?Sized + PatternMatchingDataset>
+where
+	D::Resource: 'p,
+{
+	inner: D::QuadPatternMatching<'a, 'p>,
+}
+
+impl<'a, 'p, D> Iterator for IriPropertyObjects<'a, 'p, D>
+where
+	D: 'a + ?Sized + PatternMatchingDataset<Resource = Term>,
+{
+	type Item = &'a Term;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.inner.next().map(Quad::into_object)
+	}
+}
+
+/// Iterator over the subjects of an IRI property.
+///
+/// See [`subclasses_of`].
+pub struct IriPropertySubjects<'a, 'p, D: 'a + ?Sized + PatternMatchingDataset>
+where
+	D::Resource: 'p,
+{
+	inner: D::QuadPatternMatching<'a, 'p>,
+}
+
+impl<'a, 'p, D> Iterator for IriPropertySubjects<'a, 'p, D>
+where
+	D: 'a + ?Sized + PatternMatchingDataset<Resource = Term>,
+{
+	type Item = &'a Term;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.inner.next().map(Quad::into_subject)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{label_of, subclasses_of, types_of, RDFS_LABEL, RDFS_SUB_CLASS_OF, RDF_TYPE};
+	use crate::{dataset::IndexedBTreeDataset, Literal, LiteralType, Quad, Term, XSD_STRING};
+	use iref::IriBuf;
+
+	fn iri(suffix: &str) -> Term {
+		Term::iri(IriBuf::new(format!("http://example.org/{suffix}")).unwrap())
+	}
+
+	#[test]
+	fn schema_navigation() {
+		let label = |value: &str, tag: Option<&str>| {
+			Term::Literal(Literal::new(
+				value,
+				match tag {
+					Some(tag) => LiteralType::LangString(tag.parse().unwrap()),
+					None => LiteralType::Any(XSD_STRING.to_owned()),
+				},
+			))
+		};
+
+		let rdf_type = Term::iri(RDF_TYPE.to_owned());
+		let rdfs_label = Term::iri(RDFS_LABEL.to_owned());
+		let sub_class_of = Term::iri(RDFS_SUB_CLASS_OF.to_owned());
+
+		let dataset: IndexedBTreeDataset = [
+			Quad(iri("a"), rdf_type.clone(), iri("A"), None),
+			Quad(iri("a"), rdf_type.clone(), iri("B"), None),
+			Quad(iri("a"), iri("p"), iri("C"), None),
+			Quad(iri("a"), rdfs_label.clone(), label("plain", None), None),
+			Quad(
+				iri("a"),
+				rdfs_label.clone(),
+				label("colour", Some("en-GB")),
+				None,
+			),
+			Quad(
+				iri("a"),
+				rdfs_label.clone(),
+				label("couleur", Some("fr")),
+				None,
+			),
+			Quad(iri("B"), sub_class_of.clone(), iri("A"), None),
+			Quad(iri("C"), sub_class_of.clone(), iri("B"), None),
+		]
+		.into_iter()
+		.collect();
+
+		let mut types: Vec<_> = types_of(&dataset, None, &iri("a")).collect();
+		types.sort_unstable();
+		assert_eq!(types, [&iri("A"), &iri("B")]);
+
+		let subclasses: Vec<_> = subclasses_of(&dataset, None, &iri("A")).collect();
+		assert_eq!(subclasses, [&iri("B")]);
+
+		let label_value =
+			|lang| label_of(&dataset, None, &iri("a"), lang).map(|l| l.as_value().to_owned());
+		assert_eq!(label_value(Some("FR")).as_deref(), Some("couleur"));
+		assert_eq!(label_value(Some("en")).as_deref(), Some("colour"));
+		assert_eq!(label_value(Some("de")).as_deref(), Some("plain"));
+		assert_eq!(label_value(None).as_deref(), Some("plain"));
+		assert_eq!(label_of(&dataset, None, &iri("b"), None), None)
+	}
+}
@@ -0,0 +1,164 @@
+use std::{borrow::Borrow, fmt, io, ops::Deref, sync::Arc};
+
+use educe::Educe;
+
+use crate::{Id, Literal, RdfDisplay};
+
+use super::Term;
+
+/// Reference-counted term.
+///
+/// Cloning an `ArcTerm` only increments a reference counter, meaning that the
+/// IRIs and literals it contains are shared between all the copies. This is
+/// useful to store the same terms in many datasets or graphs, for instance
+/// as the resource type of an
+/// [`IndexedBTreeDataset`](crate::dataset::IndexedBTreeDataset), without
+/// duplicating them.
+///
+/// Comparison, ordering and hashing are delegated to the underlying [`Term`]
+/// without cloning it. Just like [`Term`], the `Hash` implementation is
+/// transparent, which makes it consistent with the
+/// [`Borrow<Term>`](Borrow) implementation.
+///
+/// ```
+/// use rdf_types::{dataset::IndexedBTreeDataset, ArcTerm, Quad, Term};
+/// use static_iref::iri;
+///
+/// let a = ArcTerm::new(Term::iri(iri!("http://example.org/a").to_owned()));
+///
+/// let mut dataset: IndexedBTreeDataset<ArcTerm> = IndexedBTreeDataset::new();
+/// dataset.insert(Quad(a.clone(), a.clone(), a.clone(), None));
+/// assert!(dataset.contains(Quad(&a, &a, &a, None)));
+/// ```
+#[derive(Educe, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[educe(Clone)]
+pub struct ArcTerm<I = Id, L = Literal>(Arc<Term<I, L>>);
+
+impl<I, L> ArcTerm<I, L> {
+	/// Creates a new reference-counted term.
+	pub fn new(term: Term<I, L>) -> Self {
+		Self(Arc::new(term))
+	}
+
+	/// Returns a reference to the underlying term.
+	pub fn as_term(&self) -> &Term<I, L> {
+		&self.0
+	}
+
+	/// Returns the underlying reference-counted pointer.
+	pub fn as_arc(&self) -> &Arc<Term<I, L>> {
+		&self.0
+	}
+
+	/// Checks if both terms share the same storage.
+	pub fn ptr_eq(&self, other: &Self) -> bool {
+		Arc::ptr_eq(&self.0, &other.0)
+	}
+
+	/// Returns the underlying term, cloning it only if it is shared with
+	/// other `ArcTerm`s.
+	pub fn into_term(self) -> Term<I, L>
+	where
+		I: Clone,
+		L: Clone,
+	{
+		Arc::try_unwrap(self.0).unwrap_or_else(|arc| (*arc).clone())
+	}
+}
+
+impl<I, L> From<Term<I, L>> for ArcTerm<I, L> {
+	fn from(value: Term<I, L>) -> Self {
+		Self::new(value)
+	}
+}
+
+impl<I, L> From<Arc<Term<I, L>>> for ArcTerm<I, L> {
+	fn from(value: Arc<Term<I, L>>) -> Self {
+		Self(value)
+	}
+}
+
+impl<I, L> From<ArcTerm<I, L>> for Arc<Term<I, L>> {
+	fn from(value: ArcTerm<I, L>) -> Self {
+		value.0
+	}
+}
+
+impl<I, L> Deref for ArcTerm<I, L> {
+	type Target = Term<I, L>;
+
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+
+impl<I, L> AsRef<Term<I, L>> for ArcTerm<I, L> {
+	fn as_ref(&self) -> &Term<I, L> {
+		&self.0
+	}
+}
+
+impl<I, L> Borrow<Term<I, L>> for ArcTerm<I, L> {
+	fn borrow(&self) -> &Term<I, L> {
+		&self.0
+	}
+}
+
+impl<I: fmt::Debug, L: fmt::Debug> fmt::Debug for ArcTerm<I, L> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.0.fmt(f)
+	}
+}
+
+impl<I: fmt::Display, L: fmt::Display> fmt::Display for ArcTerm<I, L> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.0.fmt(f)
+	}
+}
+
+impl<I: RdfDisplay, L: RdfDisplay> RdfDisplay for ArcTerm<I, L> {
+	fn rdf_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.0.rdf_fmt(f)
+	}
+
+	fn rdf_write<W: io::Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+		self.0.rdf_write(writer)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::collections::BTreeSet;
+
+	use static_iref::iri;
+
+	use super::ArcTerm;
+	use crate::{dataset::IndexedBTreeDataset, Quad, RdfDisplay, Term};
+
+	#[test]
+	fn shared_storage() {
+		let a = ArcTerm::new(Term::iri(iri!("http://example.org/a").to_owned()));
+		let b = ArcTerm::new(Term::iri(iri!("http://example.org/b").to_owned()));
+
+		let mut dataset: IndexedBTreeDataset<ArcTerm> = IndexedBTreeDataset::new();
+		dataset.insert(Quad(a.clone(), b.clone(), a.clone(), None));
+		dataset.insert(Quad(b.clone(), b.clone(), a.clone(), Some(b.clone())));
+
+		let copy = dataset.clone();
+		let resources: BTreeSet<_> = copy
+			.iter()
+			.flat_map(|q| [q.0.clone(), q.2.clone()])
+			.collect();
+		assert!(resources.iter().any(|r| r.ptr_eq(&a)));
+
+		let term: &Term = a.as_term();
+		let same = ArcTerm::new(term.clone());
+		assert!(!same.ptr_eq(&a));
+		assert_eq!(same, a);
+		assert!(dataset.contains(Quad(&same, &b, &same, None)));
+		assert!(resources.contains(term));
+
+		assert_eq!(a.to_string(), "http://example.org/a");
+		assert_eq!(b.rdf_display().to_string(), "<http://example.org/b>")
+	}
+}
@@ -1,5 +1,6 @@
+use indexmap::Equivalent;
 use iref::{Iri, IriBuf};
-use std::{cmp::Ordering, fmt, hash::Hash};
+use std::{cmp::Ordering, fmt, hash::Hash, io};
 
 #[cfg(feature = "meta")]
 use locspan_derive::*;
@@ -23,6 +24,10 @@ use crate::{
 /// It is guaranteed that the `Hash` implementation of `Id` is
 /// *transparent*, meaning that the hash of `Term::Blank(id)` the same as `id`
 /// and the hash of `Subject::Iri(iri)` is the same as `iri`.
+///
+/// The hash of a [`LexicalIdRef`] is also the same as the hash of the owned
+/// identifier it borrows, and it implements [`Equivalent<Id>`] so it can be
+/// used to look up owned identifiers in an `IndexMap` or `IndexSet`.
 #[derive(Clone, Copy, Eq, Ord, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(
@@ -258,6 +263,36 @@ impl Id {
 			Self::Iri(i) => Id::Iri(vocabulary.insert(i.as_iri())),
 		}
 	}
+
+	/// Compares this identifier with the given IRI, as if it was wrapped in
+	/// an [`Id::Iri`], without allocating.
+	///
+	/// Blank node identifiers are ordered before IRIs.
+	pub fn cmp_iri(&self, iri: &Iri) -> Ordering {
+		match self {
+			Self::Blank(_) => Ordering::Less,
+			Self::Iri(i) => i.as_iri().cmp(iri),
+		}
+	}
+
+	/// Compares this identifier with the given blank node identifier, as if
+	/// it was wrapped in an [`Id::Blank`], without allocating.
+	///
+	/// Blank node identifiers are ordered before IRIs.
+	pub fn cmp_blank_id(&self, blank_id: &BlankId) -> Ordering {
+		match self {
+			Self::Blank(b) => b.as_blank_id_ref().cmp(blank_id),
+			Self::Iri(_) => Ordering::Greater,
+		}
+	}
+
+	/// Creates an IRI identifier from the given UUID, using the `urn:uuid:`
+	/// URN scheme.
+	#[cfg(feature = "uuid")]
+	pub fn from_uuid_urn(uuid: uuid::Uuid) -> Self {
+		// A UUID URN is always a valid IRI.
+		Self::Iri(unsafe { IriBuf::new_unchecked(uuid.to_urn().to_string()) })
+	}
 }
 
 impl<'a> LexicalIdRef<'a> {
@@ -289,6 +324,16 @@ impl<I: Hash, B: Hash> locspan::StrippedHash for Id<I, B> {
 	}
 }
 
+impl<'a> Equivalent<Id> for LexicalIdRef<'a> {
+	fn equivalent(&self, key: &Id) -> bool {
+		match (self, key) {
+			(Self::Blank(a), Id::Blank(b)) => *a == b.as_blank_id_ref(),
+			(Self::Iri(a), Id::Iri(b)) => *a == b.as_iri(),
+			_ => false,
+		}
+	}
+}
+
 impl<I1: PartialEq<I2>, B1: PartialEq<B2>, I2, B2> PartialEq<Id<I2, B2>> for Id<I1, B1> {
 	fn eq(&self, other: &Id<I2, B2>) -> bool {
 		match (self, other) {
@@ -339,6 +384,17 @@ impl<I: fmt::Display, B: fmt::Display> RdfDisplay for Id<I, B> {
 			Self::Iri(iri) => write!(f, "<{iri}>"),
 		}
 	}
+
+	fn rdf_write<W: io::Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+		match self {
+			Self::Blank(id) => write!(writer, "{id}"),
+			Self::Iri(iri) => {
+				writer.write_all(b"<")?;
+				write!(writer, "{iri}")?;
+				writer.write_all(b">")
+			}
+		}
+	}
 }
 
 #[cfg(feature = "contextual")]
@@ -418,3 +474,23 @@ impl<I, B> IntoId for Id<I, B> {
 		self
 	}
 }
+
+#[cfg(all(test, feature = "uuid"))]
+mod tests {
+	use iref::IriBuf;
+
+	use super::Id;
+
+	#[test]
+	fn from_uuid_urn() {
+		let uuid = uuid::Uuid::from_u128(0x936da01f_9abd_4d9d_80c7_02af85c822a8);
+		let id = Id::from_uuid_urn(uuid);
+		let iri = id.as_iri().unwrap();
+
+		assert_eq!(
+			iri.as_str(),
+			"urn:uuid:936da01f-9abd-4d9d-80c7-02af85c822a8"
+		);
+		assert_eq!(&IriBuf::new(iri.as_str().to_owned()).unwrap(), iri)
+	}
+}
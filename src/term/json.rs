@@ -0,0 +1,265 @@
+//! JSON representation of terms.
+use serde_json::{Map, Value};
+
+use crate::{BlankIdBuf, Id, InvalidDirection, IriBuf, Literal, LiteralType, Term, XSD_STRING};
+
+const ID: &str = "@id";
+const VALUE: &str = "@value";
+const TYPE: &str = "@type";
+const LANGUAGE: &str = "@language";
+const DIRECTION: &str = "@direction";
+
+/// Invalid JSON term.
+///
+/// Error returned by [`Term::from_json`].
+#[derive(Debug, thiserror::Error)]
+pub enum InvalidJsonTerm {
+	#[error("expected a JSON object")]
+	NotAnObject,
+
+	#[error("expected either an `@id` or a `@value` entry")]
+	MissingIdOrValue,
+
+	#[error("unexpected entry `{0}`")]
+	UnexpectedEntry(String),
+
+	#[error("expected a string for `{0}`")]
+	NotAString(&'static str),
+
+	#[error("invalid IRI `{0}`")]
+	InvalidIri(String),
+
+	#[error("invalid blank node identifier `{0}`")]
+	InvalidBlankId(String),
+
+	#[error("invalid language tag `{0}`")]
+	InvalidLangTag(String),
+
+	#[error("invalid base direction `{0}`")]
+	InvalidDirection(String),
+
+	#[error("a literal cannot have both a `@type` and a `@language`")]
+	TypeAndLanguage,
+}
+
+impl Id {
+	/// Returns the JSON representation of this identifier.
+	///
+	/// See [`Term::to_json`] for the exact JSON shape.
+	pub fn to_json(&self) -> Value {
+		let mut map = Map::new();
+		map.insert(ID.to_owned(), Value::String(self.as_str().to_owned()));
+		Value::Object(map)
+	}
+}
+
+impl Literal {
+	/// Returns the JSON representation of this literal.
+	///
+	/// See [`Term::to_json`] for the exact JSON shape.
+	pub fn to_json(&self) -> Value {
+		let mut map = Map::new();
+		map.insert(VALUE.to_owned(), Value::String(self.as_str().to_owned()));
+
+		match &self.type_ {
+			LiteralType::Any(ty) if ty == XSD_STRING => (),
+			LiteralType::Any(ty) => {
+				map.insert(TYPE.to_owned(), Value::String(ty.as_str().to_owned()));
+			}
+			LiteralType::LangString(tag) => {
+				map.insert(LANGUAGE.to_owned(), Value::String(tag.as_str().to_owned()));
+			}
+			LiteralType::DirLangString(tag, direction) => {
+				map.insert(LANGUAGE.to_owned(), Value::String(tag.as_str().to_owned()));
+				map.insert(
+					DIRECTION.to_owned(),
+					Value::String(direction.as_str().to_owned()),
+				);
+			}
+		}
+
+		Value::Object(map)
+	}
+}
+
+impl Term {
+	/// Returns the JSON representation of this term.
+	///
+	/// The representation follows the JSON-LD node and value object forms:
+	/// - an IRI is represented as `{ "@id": "iri" }`;
+	/// - a blank node identifier is represented as `{ "@id": "_:id" }`;
+	/// - a literal is represented as `{ "@value": "value", "@type": "iri" }`,
+	///   where the `@type` entry is omitted when the datatype is
+	///   `xsd:string`;
+	/// - a language-tagged string is represented as
+	///   `{ "@value": "value", "@language": "tag" }`, with an additional
+	///   `"@direction": "ltr"` or `"@direction": "rtl"` entry for directional
+	///   language strings.
+	///
+	/// The literal value is always a JSON string holding its lexical form, so
+	/// that [`Term::from_json`] gives back the exact same term.
+	///
+	/// ```
+	/// use rdf_types::{Literal, LiteralType, Term};
+	/// use serde_json::json;
+	/// use static_iref::iri;
+	///
+	/// let term = Term::Literal(Literal::new(
+	///     "12".to_owned(),
+	///     LiteralType::Any(iri!("http://www.w3.org/2001/XMLSchema#integer").to_owned()),
+	/// ));
+	///
+	/// let value = term.to_json();
+	/// assert_eq!(
+	///     value,
+	///     json!({ "@value": "12", "@type": "http://www.w3.org/2001/XMLSchema#integer" })
+	/// );
+	/// assert_eq!(Term::from_json(&value).unwrap(), term);
+	/// ```
+	pub fn to_json(&self) -> Value {
+		match self {
+			Self::Id(id) => id.to_json(),
+			Self::Literal(l) => l.to_json(),
+		}
+	}
+
+	/// Parses the JSON representation of a term.
+	///
+	/// See [`Term::to_json`] for the expected JSON shape.
+	pub fn from_json(value: &Value) -> Result<Self, InvalidJsonTerm> {
+		let map = value.as_object().ok_or(InvalidJsonTerm::NotAnObject)?;
+
+		if let Some(key) = map
+			.keys()
+			.find(|k| ![ID, VALUE, TYPE, LANGUAGE, DIRECTION].contains(&k.as_str()))
+		{
+			return Err(InvalidJsonTerm::UnexpectedEntry(key.clone()));
+		}
+
+		match (map.get(ID), map.get(VALUE)) {
+			(Some(id), None) => {
+				if let Some(key) = [TYPE, LANGUAGE, DIRECTION]
+					.into_iter()
+					.find(|k| map.contains_key(*k))
+				{
+					return Err(InvalidJsonTerm::UnexpectedEntry(key.to_owned()));
+				}
+
+				let id = json_string(ID, id)?;
+				if id.starts_with("_:") {
+					BlankIdBuf::new(id.to_owned())
+						.map(|b| Term::Id(Id::Blank(b)))
+						.map_err(|e| InvalidJsonTerm::InvalidBlankId(e.0))
+				} else {
+					IriBuf::new(id.to_owned())
+						.map(|i| Term::Id(Id::Iri(i)))
+						.map_err(|e| InvalidJsonTerm::InvalidIri(e.0))
+				}
+			}
+			(None, Some(value)) => {
+				let value = json_string(VALUE, value)?.to_owned();
+				let direction = match map.get(DIRECTION) {
+					Some(direction) => {
+						let direction = json_string(DIRECTION, direction)?;
+						Some(direction.parse().map_err(|e: InvalidDirection| {
+							InvalidJsonTerm::InvalidDirection(e.0)
+						})?)
+					}
+					None => None,
+				};
+
+				let type_ = match (map.get(TYPE), map.get(LANGUAGE)) {
+					(_, None) if direction.is_some() => {
+						return Err(InvalidJsonTerm::UnexpectedEntry(DIRECTION.to_owned()))
+					}
+					(None, None) => LiteralType::Any(XSD_STRING.to_owned()),
+					(Some(ty), None) => {
+						let ty = json_string(TYPE, ty)?;
+						LiteralType::Any(
+							IriBuf::new(ty.to_owned())
+								.map_err(|e| InvalidJsonTerm::InvalidIri(e.0))?,
+						)
+					}
+					(None, Some(tag)) => {
+						let tag = json_string(LANGUAGE, tag)?;
+						let tag = langtag::LangTagBuf::new(tag.to_owned())
+							.map_err(|e| InvalidJsonTerm::InvalidLangTag(e.0))?;
+
+						match direction {
+							Some(direction) => LiteralType::DirLangString(tag, direction),
+							None => LiteralType::LangString(tag),
+						}
+					}
+					(Some(_), Some(_)) => return Err(InvalidJsonTerm::TypeAndLanguage),
+				};
+
+				Ok(Term::Literal(Literal::new(value, type_)))
+			}
+			(Some(_), Some(_)) => Err(InvalidJsonTerm::UnexpectedEntry(VALUE.to_owned())),
+			(None, None) => Err(InvalidJsonTerm::MissingIdOrValue),
+		}
+	}
+}
+
+fn json_string<'a>(key: &'static str, value: &'a Value) -> Result<&'a str, InvalidJsonTerm> {
+	value.as_str().ok_or(InvalidJsonTerm::NotAString(key))
+}
+
+#[cfg(test)]
+mod tests {
+	use serde_json::json;
+	use static_iref::iri;
+
+	use crate::{BlankIdBuf, Direction, Id, Literal, LiteralType, Term, XSD_STRING};
+
+	#[test]
+	fn round_trip() {
+		let terms = [
+			Term::Id(Id::Iri(iri!("http://example.org/#a").to_owned())),
+			Term::Id(Id::Blank(BlankIdBuf::from_suffix("b0").unwrap())),
+			Term::Literal(Literal::new(
+				"text".to_owned(),
+				LiteralType::Any(XSD_STRING.to_owned()),
+			)),
+			Term::Literal(Literal::new(
+				"1.5".to_owned(),
+				LiteralType::Any(iri!("http://www.w3.org/2001/XMLSchema#decimal").to_owned()),
+			)),
+			Term::Literal(Literal::new(
+				"chat".to_owned(),
+				LiteralType::LangString("fr".parse().unwrap()),
+			)),
+			Term::Literal(Literal::new(
+				"salaam".to_owned(),
+				LiteralType::DirLangString("ar".parse().unwrap(), Direction::Rtl),
+			)),
+		];
+
+		for term in terms {
+			assert_eq!(Term::from_json(&term.to_json()).unwrap(), term)
+		}
+	}
+
+	#[test]
+	fn shape() {
+		let term = Term::Id(Id::Blank(BlankIdBuf::from_suffix("b0").unwrap()));
+		assert_eq!(term.to_json(), json!({ "@id": "_:b0" }));
+
+		let term = Term::Literal(Literal::new(
+			"text".to_owned(),
+			LiteralType::Any(XSD_STRING.to_owned()),
+		));
+		assert_eq!(term.to_json(), json!({ "@value": "text" }));
+	}
+
+	#[test]
+	fn invalid() {
+		assert!(Term::from_json(&json!("http://example.org/")).is_err());
+		assert!(Term::from_json(&json!({ "@id": 12 })).is_err());
+		assert!(
+			Term::from_json(&json!({ "@value": "a", "@type": "x", "@language": "en" })).is_err()
+		);
+		assert!(Term::from_json(&json!({ "@id": "_:b0", "@value": "a" })).is_err());
+		assert!(Term::from_json(&json!({ "@value": "a", "@direction": "ltr" })).is_err());
+	}
+}
@@ -3,23 +3,33 @@ use crate::vocabulary::{
 	ByRef, EmbedIntoVocabulary, EmbeddedIntoVocabulary, ExtractFromVocabulary,
 	ExtractedFromVocabulary, LiteralVocabulary, TryExtractFromVocabulary,
 };
-use crate::{BlankIdBuf, Literal, RdfDisplay};
-use iref::IriBuf;
-use std::fmt;
+use crate::{BlankId, BlankIdBuf, Literal, LiteralRef, RdfDisplay};
+use indexmap::Equivalent;
+use iref::{InvalidIri, Iri, IriBuf};
 use std::{cmp::Ordering, hash::Hash};
+use std::{fmt, io};
 
+mod arc;
 mod id;
 mod into;
 mod maybe_blank;
 mod maybe_iri;
 mod maybe_literal;
+mod parse;
 
+pub use arc::*;
 pub use id::*;
 pub use into::*;
 pub use maybe_blank::*;
 pub use maybe_iri::*;
+pub use parse::*;
 // pub use maybe_literal::*;
 
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "json")]
+pub use json::*;
+
 #[cfg(feature = "contextual")]
 use contextual::{AsRefWithContext, DisplayWithContext};
 
@@ -35,6 +45,23 @@ use locspan_derive::*;
 /// It is guaranteed that the `Hash` implementation of `Term` is *transparent*,
 /// meaning that the hash of `Term::Id(id)` the same as `id` and the hash of
 /// `Term::Literal(l)` is the same as `l`.
+///
+/// The hash of a [`LexicalTermRef`] is also the same as the hash of the owned
+/// term it borrows. Together with its [`Equivalent`] implementation, this
+/// allows looking up owned terms in an `IndexMap` or `IndexSet` using a
+/// borrowed term as key, without allocating.
+///
+/// ```
+/// use indexmap::IndexSet;
+/// use rdf_types::{LexicalTermRef, Term};
+/// use static_iref::iri;
+///
+/// let mut set = IndexSet::new();
+/// set.insert(<Term>::iri(iri!("http://example.org/").to_owned()));
+///
+/// let key: LexicalTermRef = Term::iri(iri!("http://example.org/"));
+/// assert!(set.contains(&key));
+/// ```
 #[derive(Clone, Copy, Eq, Ord, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(
@@ -136,6 +163,14 @@ impl<I, L> Term<I, L> {
 		}
 	}
 
+	/// Maps the literal with the given function, if this term is a literal.
+	pub fn map_literal<M>(self, f: impl FnOnce(L) -> M) -> Term<I, M> {
+		match self {
+			Self::Id(id) => Term::Id(id),
+			Self::Literal(lit) => Term::Literal(f(lit)),
+		}
+	}
+
 	pub fn is_blank(&self) -> bool
 	where
 		I: TryAsBlankId,
@@ -347,6 +382,77 @@ impl Term {
 	pub fn as_lexical_object_ref(&self) -> LexicalObjectRef {
 		self.as_lexical_term_ref()
 	}
+
+	/// Returns a reference to the literal, if this term is a literal.
+	pub fn as_literal_ref(&self) -> Option<LiteralRef<'_>> {
+		self.as_literal().map(Literal::as_ref)
+	}
+
+	/// Parses the given string as an IRI term.
+	///
+	/// Note that [`Term::from`] turns a `&str` into an `xsd:string` literal
+	/// term instead.
+	///
+	/// ```
+	/// use rdf_types::Term;
+	///
+	/// let iri = Term::iri_str("http://example.org/").unwrap();
+	/// assert!(iri.is_iri());
+	///
+	/// let literal = Term::from("http://example.org/");
+	/// assert!(literal.is_literal());
+	/// ```
+	pub fn iri_str(iri: &str) -> Result<Self, InvalidIri<&str>> {
+		Iri::new(iri).map(|iri| Self::iri(iri.to_owned()))
+	}
+
+	/// Compares this term with the given IRI, as if it was wrapped in a
+	/// term, without allocating.
+	///
+	/// Terms are ordered as follows: blank node identifiers first, then
+	/// IRIs, then literals. This is the order of [`Term`]'s [`Ord`]
+	/// implementation, so this function can be used to search a sorted list
+	/// of terms.
+	///
+	/// ```
+	/// use rdf_types::Term;
+	/// use static_iref::iri;
+	///
+	/// let terms: [Term; 3] = [
+	///   Term::blank("_:b".parse().unwrap()),
+	///   Term::iri(iri!("http://example.org/a").to_owned()),
+	///   Term::iri(iri!("http://example.org/b").to_owned()),
+	/// ];
+	///
+	/// let i = terms.binary_search_by(|t| t.cmp_iri(iri!("http://example.org/b")));
+	/// assert_eq!(i, Ok(2));
+	/// ```
+	pub fn cmp_iri(&self, iri: &Iri) -> Ordering {
+		match self {
+			Self::Id(id) => id.cmp_iri(iri),
+			Self::Literal(_) => Ordering::Greater,
+		}
+	}
+
+	/// Compares this term with the given blank node identifier, as if it was
+	/// wrapped in a term, without allocating.
+	///
+	/// See [`Self::cmp_iri`] for the order used.
+	pub fn cmp_blank_id(&self, blank_id: &BlankId) -> Ordering {
+		match self {
+			Self::Id(id) => id.cmp_blank_id(blank_id),
+			Self::Literal(_) => Ordering::Greater,
+		}
+	}
+
+	/// Creates an IRI term from the given UUID, using the `urn:uuid:` URN
+	/// scheme.
+	///
+	/// See [`Id::from_uuid_urn`].
+	#[cfg(feature = "uuid")]
+	pub fn uuid_urn(uuid: uuid::Uuid) -> Self {
+		Self::Id(Id::from_uuid_urn(uuid))
+	}
 }
 
 impl<V, I: EmbedIntoVocabulary<V>, L: EmbedIntoVocabulary<V>> EmbedIntoVocabulary<V>
@@ -375,6 +481,53 @@ impl<V, I: EmbeddedIntoVocabulary<V>, L: EmbeddedIntoVocabulary<V>> EmbeddedInto
 	}
 }
 
+/// Creates an `xsd:boolean` literal term.
+impl From<bool> for Term {
+	fn from(value: bool) -> Self {
+		Self::Literal(value.into())
+	}
+}
+
+/// Creates an `xsd:integer` literal term.
+///
+/// ```
+/// use rdf_types::{Term, Triple, XSD_INTEGER};
+///
+/// let triple: Triple = Triple(
+///   Term::iri_str("http://example.org/s").unwrap(),
+///   Term::iri_str("http://example.org/p").unwrap(),
+///   42.into()
+/// );
+///
+/// let literal = triple.2.as_literal().unwrap();
+/// assert_eq!(literal.as_str(), "42");
+/// assert!(literal.type_.is_datatype(XSD_INTEGER));
+/// ```
+impl From<i64> for Term {
+	fn from(value: i64) -> Self {
+		Self::Literal(value.into())
+	}
+}
+
+/// Creates an `xsd:string` literal term.
+///
+/// Use [`Term::iri_str`] to create an IRI term from a string.
+impl<'a> From<&'a str> for Term {
+	fn from(value: &'a str) -> Self {
+		Self::Literal(value.into())
+	}
+}
+
+impl<'a> Equivalent<Term> for LexicalTermRef<'a> {
+	fn equivalent(&self, key: &Term) -> bool {
+		match (self, key) {
+			(Self::Id(a), Term::Id(b)) => a.equivalent(b),
+			(Self::Literal(a), Term::Literal(b)) => *a == b,
+			_ => false,
+		}
+	}
+}
+
 impl<I1: PartialEq<I2>, L1: PartialEq<L2>, I2, L2> PartialEq<Term<I2, L2>> for Term<I1, L1> {
 	fn eq(&self, other: &Term<I2, L2>) -> bool {
 		match (self, other) {
@@ -412,6 +565,13 @@ impl<I: RdfDisplay, L: RdfDisplay> RdfDisplay for Term<I, L> {
 			Self::Literal(lit) => lit.rdf_fmt(f),
 		}
 	}
+
+	fn rdf_write<W: io::Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+		match self {
+			Self::Id(id) => id.rdf_write(writer),
+			Self::Literal(lit) => lit.rdf_write(writer),
+		}
+	}
 }
 
 #[cfg(feature = "contextual")]
@@ -453,6 +613,14 @@ impl<'a> LexicalTermRef<'a> {
 			Self::Literal(l) => Term::Literal(l.clone()),
 		}
 	}
+
+	/// Returns a reference to the literal, if this term is a literal.
+	pub fn as_literal_ref(&self) -> Option<LiteralRef<'a>> {
+		match self {
+			Self::Literal(l) => Some(Literal::as_ref(l)),
+			Self::Id(_) => None,
+		}
+	}
 }
 
 /// RDF triple/quad subject.
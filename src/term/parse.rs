@@ -0,0 +1,231 @@
+//! Term parser.
+use std::{iter::Peekable, str::CharIndices, str::FromStr};
+
+use iref::IriBuf;
+use langtag::LangTagBuf;
+
+use crate::{BlankIdBuf, Id, InvalidDirection, Literal, LiteralType, Term, XSD_STRING};
+
+/// Invalid term.
+///
+/// Error returned by the [`FromStr`] implementation of [`Term`].
+#[derive(Debug, thiserror::Error)]
+pub enum InvalidTerm {
+	#[error("empty term")]
+	Empty,
+
+	#[error("unexpected character `{1}` at offset {0}")]
+	UnexpectedChar(usize, char),
+
+	#[error("unterminated IRI")]
+	UnterminatedIri,
+
+	#[error("invalid IRI `{0}`")]
+	InvalidIri(String),
+
+	#[error("invalid blank node identifier `{0}`")]
+	InvalidBlankId(String),
+
+	#[error("unterminated literal")]
+	UnterminatedLiteral,
+
+	#[error("invalid escape sequence `\\{1}` at offset {0}")]
+	InvalidEscape(usize, char),
+
+	#[error("invalid language tag `{0}`")]
+	InvalidLangTag(String),
+
+	#[error(transparent)]
+	InvalidDirection(#[from] InvalidDirection),
+}
+
+impl FromStr for Term {
+	type Err = InvalidTerm;
+
+	/// Parses an IRI `<iri>`, a blank node identifier `_:id` or a literal
+	/// `"value"`, `"value"@lang`, `"value"@lang--dir` or `"value"^^<iri>`.
+	///
+	/// ```
+	/// use rdf_types::Term;
+	/// let term: Term = "<http://example.org/>".parse().unwrap();
+	/// assert_eq!(term.as_iri().unwrap().as_str(), "http://example.org/");
+	///
+	/// let term: Term = "\"chat\"@fr".parse().unwrap();
+	/// assert!(term.as_literal().unwrap().is_lang_string());
+	/// ```
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let mut parser = TermParser::new(s);
+		let term = parser.term()?;
+		match parser.chars.next() {
+			Some((i, c)) => Err(InvalidTerm::UnexpectedChar(i, c)),
+			None => Ok(term),
+		}
+	}
+}
+
+/// Term parser, also used to parse patterns.
+pub(crate) struct TermParser<'a> {
+	source: &'a str,
+	pub chars: Peekable<CharIndices<'a>>,
+}
+
+impl<'a> TermParser<'a> {
+	pub fn new(source: &'a str) -> Self {
+		Self {
+			source,
+			chars: source.char_indices().peekable(),
+		}
+	}
+
+	pub fn skip_whitespaces(&mut self) {
+		while self.chars.next_if(|(_, c)| c.is_whitespace()).is_some() {}
+	}
+
+	pub fn offset(&mut self) -> usize {
+		self.chars
+			.peek()
+			.map(|(i, _)| *i)
+			.unwrap_or(self.source.len())
+	}
+
+	/// Consumes characters while they satisfy `f`, returning them.
+	pub fn take_while(&mut self, f: impl Fn(char) -> bool) -> &'a str {
+		let start = self.offset();
+		while self.chars.next_if(|(_, c)| f(*c)).is_some() {}
+		&self.source[start..self.offset()]
+	}
+
+	fn expect(&mut self, expected: char, end: InvalidTerm) -> Result<(), InvalidTerm> {
+		match self.chars.next() {
+			Some((_, c)) if c == expected => Ok(()),
+			Some((i, c)) => Err(InvalidTerm::UnexpectedChar(i, c)),
+			None => Err(end),
+		}
+	}
+
+	pub fn term(&mut self) -> Result<Term, InvalidTerm> {
+		match self.chars.peek().copied() {
+			None => Err(InvalidTerm::Empty),
+			Some((_, '<')) => Ok(Term::Id(Id::Iri(self.iri()?))),
+			Some((_, '_')) => {
+				let id = self.take_while(|c| !c.is_whitespace());
+				BlankIdBuf::new(id.to_owned())
+					.map(|id| Term::Id(Id::Blank(id)))
+					.map_err(|_| InvalidTerm::InvalidBlankId(id.to_owned()))
+			}
+			Some((_, '"')) => Ok(Term::Literal(self.literal()?)),
+			Some((i, c)) => Err(InvalidTerm::UnexpectedChar(i, c)),
+		}
+	}
+
+	fn iri(&mut self) -> Result<IriBuf, InvalidTerm> {
+		self.expect('<', InvalidTerm::UnterminatedIri)?;
+		let iri = self.take_while(|c| c != '>');
+		self.expect('>', InvalidTerm::UnterminatedIri)?;
+		IriBuf::new(iri.to_owned()).map_err(|_| InvalidTerm::InvalidIri(iri.to_owned()))
+	}
+
+	fn literal(&mut self) -> Result<Literal, InvalidTerm> {
+		self.expect('"', InvalidTerm::UnterminatedLiteral)?;
+		let mut value = String::new();
+		loop {
+			match self.chars.next() {
+				Some((_, '"')) => break,
+				Some((_, '\\')) => match self.chars.next() {
+					Some((_, 'n')) => value.push('\n'),
+					Some((_, 'r')) => value.push('\r'),
+					Some((_, 't')) => value.push('\t'),
+					Some((_, c @ ('"' | '\\'))) => value.push(c),
+					Some((i, c)) => return Err(InvalidTerm::InvalidEscape(i, c)),
+					None => return Err(InvalidTerm::UnterminatedLiteral),
+				},
+				Some((_, c)) => value.push(c),
+				None => return Err(InvalidTerm::UnterminatedLiteral),
+			}
+		}
+
+		let type_ = match self.chars.peek().copied() {
+			Some((_, '@')) => {
+				self.chars.next();
+				let tag = self.take_while(|c| c.is_ascii_alphanumeric() || c == '-');
+				let (tag, direction) = match tag.split_once("--") {
+					Some((tag, direction)) => (tag, Some(direction.parse()?)),
+					None => (tag, None),
+				};
+
+				let tag = LangTagBuf::new(tag.to_owned())
+					.map_err(|_| InvalidTerm::InvalidLangTag(tag.to_owned()))?;
+
+				match direction {
+					Some(direction) => LiteralType::DirLangString(tag, direction),
+					None => LiteralType::LangString(tag),
+				}
+			}
+			Some((_, '^')) => {
+				self.chars.next();
+				self.expect('^', InvalidTerm::UnterminatedIri)?;
+				LiteralType::Any(self.iri()?)
+			}
+			_ => LiteralType::Any(XSD_STRING.to_owned()),
+		};
+
+		Ok(Literal::new(value, type_))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use static_iref::iri;
+
+	use super::InvalidTerm;
+	use crate::{Direction, Literal, LiteralType, Term};
+
+	#[test]
+	fn parse_literal() {
+		let term: Term = "\"a \\\"b\\\"\\n\"^^<http://example.org/t>"
+			.parse()
+			.unwrap();
+		let expected: Term = Term::Literal(Literal::new(
+			"a \"b\"\n".to_owned(),
+			LiteralType::Any(iri!("http://example.org/t").to_owned()),
+		));
+		assert_eq!(term, expected)
+	}
+
+	#[test]
+	fn parse_dir_lang_string() {
+		let term: Term = "\"salaam\"@ar--rtl".parse().unwrap();
+		let expected: Term = Term::Literal(Literal::new(
+			"salaam".to_owned(),
+			LiteralType::DirLangString("ar".parse().unwrap(), Direction::Rtl),
+		));
+		assert_eq!(term, expected);
+		assert_eq!(term.to_string(), "\"salaam\"@ar--rtl");
+
+		assert!(matches!(
+			"\"abc\"@en--up".parse::<Term>(),
+			Err(InvalidTerm::InvalidDirection(_))
+		));
+	}
+
+	#[test]
+	fn parse_errors() {
+		assert!(matches!("".parse::<Term>(), Err(InvalidTerm::Empty)));
+		assert!(matches!(
+			"<http://example.org/".parse::<Term>(),
+			Err(InvalidTerm::UnterminatedIri)
+		));
+		assert!(matches!(
+			"\"abc".parse::<Term>(),
+			Err(InvalidTerm::UnterminatedLiteral)
+		));
+		assert!(matches!(
+			"\"abc\"@".parse::<Term>(),
+			Err(InvalidTerm::InvalidLangTag(_))
+		));
+		assert!(matches!(
+			"_:a b".parse::<Term>(),
+			Err(InvalidTerm::UnexpectedChar(3, ' '))
+		));
+	}
+}
@@ -1,4 +1,4 @@
-use std::{cmp::Ordering, fmt};
+use std::{cmp::Ordering, fmt, hash::Hasher, io};
 
 use iref::{Iri, IriBuf};
 
@@ -7,7 +7,8 @@ use crate::{
 		ByRef, EmbedIntoVocabulary, EmbeddedIntoVocabulary, ExtractFromVocabulary,
 		ExtractedFromVocabulary,
 	},
-	Id, LexicalObjectRef, LexicalSubjectRef, Object, Quad, RdfDisplay, Term,
+	Id, LexicalObjectRef, LexicalSubjectRef, Object, Quad, RdfDisplay, RdfShapeError, Term,
+	TryAsIri, RDF_OBJECT, RDF_PREDICATE, RDF_STATEMENT, RDF_SUBJECT, RDF_TYPE,
 };
 
 #[cfg(feature = "contextual")]
@@ -25,6 +26,7 @@ pub type LexicalTripleRef<'a> = Triple<LexicalSubjectRef<'a>, &'a Iri, LexicalOb
 /// RDF triple.
 #[derive(Clone, Copy, Eq, Ord, Hash, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Triple<S = Term, P = S, O = S>(pub S, pub P, pub O);
 
 impl<S1: PartialEq<S2>, P1: PartialEq<P2>, O1: PartialEq<O2>, S2, P2, O2>
@@ -119,6 +121,22 @@ impl<S, P, O> Triple<S, P, O> {
 		Quad(self.0, self.1, self.2, graph)
 	}
 
+	/// Turns the triple into a quad in the given named `graph`.
+	///
+	/// ```
+	/// use rdf_types::{Quad, Triple};
+	///
+	/// assert_eq!(Triple("s", "p", "o").in_graph("g"), Quad("s", "p", "o", Some("g")));
+	/// ```
+	pub fn in_graph<G>(self, graph: G) -> Quad<S, P, O, G> {
+		Quad(self.0, self.1, self.2, Some(graph))
+	}
+
+	/// Turns the triple into a quad in the default graph.
+	pub fn in_default_graph<G>(self) -> Quad<S, P, O, G> {
+		Quad(self.0, self.1, self.2, None)
+	}
+
 	/// Maps the subject with the given function.
 	pub fn map_subject<U>(self, f: impl FnOnce(S) -> U) -> Triple<U, P, O> {
 		Triple(f(self.0), self.1, self.2)
@@ -185,6 +203,77 @@ impl<T> Triple<T, T, T> {
 	pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> Triple<U, U, U> {
 		Triple(f(self.0), f(self.1), f(self.2))
 	}
+
+	/// Maps the components with the given function, producing two triples
+	/// in a single traversal.
+	pub fn map2<U, V>(self, mut f: impl FnMut(T) -> (U, V)) -> (Triple<U, U, U>, Triple<V, V, V>) {
+		let (s1, s2) = f(self.0);
+		let (p1, p2) = f(self.1);
+		let (o1, o2) = f(self.2);
+		(Triple(s1, p1, o1), Triple(s2, p2, o2))
+	}
+
+	/// Turns the triple into an array of its components, in order.
+	///
+	/// ```
+	/// use rdf_types::Triple;
+	///
+	/// let triple = Triple(1, 2, 3);
+	/// let sum: u32 = triple.into_components().into_iter().sum();
+	/// assert_eq!(sum, 6);
+	/// ```
+	pub fn into_components(self) -> [T; 3] {
+		[self.0, self.1, self.2]
+	}
+
+	/// Compares two triples component-wise using the given resource
+	/// comparison function.
+	///
+	/// Components are compared in the same order as the derived [`Ord`]
+	/// implementation: subject, predicate then object.
+	pub fn cmp_by(&self, other: &Self, mut cmp: impl FnMut(&T, &T) -> Ordering) -> Ordering {
+		cmp(&self.0, &other.0)
+			.then_with(|| cmp(&self.1, &other.1))
+			.then_with(|| cmp(&self.2, &other.2))
+	}
+
+	/// Feeds this triple into the given [`Hasher`], using the given function
+	/// to hash each resource.
+	///
+	/// This is useful when the [`Hash`] implementation of `T` does not
+	/// reflect the desired hash, for instance when `T` is an index into a
+	/// vocabulary and the hash should only depend on its lexical
+	/// representation.
+	///
+	/// ```
+	/// use std::{collections::hash_map::DefaultHasher, hash::{Hash, Hasher}};
+	/// use rdf_types::Triple;
+	///
+	/// let names = ["a", "b", "c"];
+	/// let hash = |triple: Triple<usize>| {
+	///     let mut hasher = DefaultHasher::new();
+	///     triple.hash_with(&mut hasher, |i, h| names[*i].hash(h));
+	///     hasher.finish()
+	/// };
+	///
+	/// let other_names = ["c", "a", "b"];
+	/// let mut hasher = DefaultHasher::new();
+	/// Triple(1, 2, 0).hash_with(&mut hasher, |i, h| other_names[*i].hash(h));
+	///
+	/// assert_eq!(hash(Triple(0, 1, 2)), hasher.finish());
+	/// ```
+	pub fn hash_with<H: Hasher>(&self, state: &mut H, mut hash_resource: impl FnMut(&T, &mut H)) {
+		hash_resource(&self.0, state);
+		hash_resource(&self.1, state);
+		hash_resource(&self.2, state)
+	}
+}
+
+/// Sorts the given triples using the given resource comparison function.
+///
+/// See [`Triple::cmp_by`].
+pub fn sort_triples_by<T>(triples: &mut [Triple<T>], mut cmp: impl FnMut(&T, &T) -> Ordering) {
+	triples.sort_by(|a, b| a.cmp_by(b, &mut cmp))
 }
 
 impl LexicalTriple {
@@ -203,6 +292,117 @@ impl<'a> LexicalTripleRef<'a> {
 	}
 }
 
+impl<I: TryAsIri, L> Triple<Term<I, L>> {
+	/// Checks that this triple is a valid RDF triple.
+	///
+	/// See [`Quad::validate_rdf`].
+	pub fn validate_rdf(&self) -> Result<(), RdfShapeError> {
+		if self.0.is_literal() {
+			return Err(RdfShapeError::LiteralSubject);
+		}
+
+		if !self.1.is_iri() {
+			return Err(RdfShapeError::NonIriPredicate);
+		}
+
+		Ok(())
+	}
+}
+
+impl Triple {
+	/// Reifies this triple using the given `statement` node.
+	///
+	/// Returns the four triples of the standard RDF reification vocabulary
+	/// describing this triple: `statement rdf:type rdf:Statement`,
+	/// `statement rdf:subject s`, `statement rdf:predicate p` and
+	/// `statement rdf:object o`.
+	///
+	/// See [`Triple::dereify`] for the inverse operation.
+	///
+	/// ```
+	/// use rdf_types::{Term, Triple};
+	/// use static_iref::iri;
+	///
+	/// let triple = Triple(
+	///     Term::iri(iri!("http://example.org/a").to_owned()),
+	///     Term::iri(iri!("http://example.org/p").to_owned()),
+	///     Term::iri(iri!("http://example.org/b").to_owned()),
+	/// );
+	///
+	/// let statement: Term = "_:stmt".parse().unwrap();
+	/// let reification = triple.clone().reify(statement.clone());
+	///
+	/// assert_eq!(
+	///     Triple::dereify(&statement, reification.iter().map(Triple::as_ref)),
+	///     Some(triple)
+	/// );
+	/// ```
+	pub fn reify(self, statement: Term) -> [Triple; 4] {
+		let Triple(s, p, o) = self;
+		[
+			Triple(
+				statement.clone(),
+				Term::iri(RDF_TYPE.to_owned()),
+				Term::iri(RDF_STATEMENT.to_owned()),
+			),
+			Triple(statement.clone(), Term::iri(RDF_SUBJECT.to_owned()), s),
+			Triple(statement.clone(), Term::iri(RDF_PREDICATE.to_owned()), p),
+			Triple(statement, Term::iri(RDF_OBJECT.to_owned()), o),
+		]
+	}
+
+	/// Reads back the triple described by the given reification `statement`
+	/// node.
+	///
+	/// The `rdf:subject`, `rdf:predicate` and `rdf:object` values of
+	/// `statement` are looked up among the given triples. The
+	/// `rdf:type rdf:Statement` triple is not required. Returns `None` if any
+	/// of the three values is missing, or if one of them is given more than
+	/// one distinct value.
+	pub fn dereify<'a>(
+		statement: &Term,
+		triples: impl IntoIterator<Item = Triple<&'a Term>>,
+	) -> Option<Triple> {
+		let mut components: [Option<&Term>; 3] = [None; 3];
+
+		for Triple(s, p, o) in triples {
+			if s != statement {
+				continue;
+			}
+
+			let i = match p.as_iri().map(IriBuf::as_iri) {
+				Some(iri) if iri == RDF_SUBJECT => 0,
+				Some(iri) if iri == RDF_PREDICATE => 1,
+				Some(iri) if iri == RDF_OBJECT => 2,
+				_ => continue,
+			};
+
+			match components[i] {
+				Some(value) if value != o => return None,
+				_ => components[i] = Some(o),
+			}
+		}
+
+		let [Some(s), Some(p), Some(o)] = components else {
+			return None;
+		};
+
+		Some(Triple(s.clone(), p.clone(), o.clone()))
+	}
+}
+
+impl<S, P, O> From<(S, P, O)> for Triple<S, P, O> {
+	fn from((s, p, o): (S, P, O)) -> Self {
+		Self(s, p, o)
+	}
+}
+
+impl<T> From<[T; 3]> for Triple<T> {
+	fn from([s, p, o]: [T; 3]) -> Self {
+		Self(s, p, o)
+	}
+}
+
 impl<V, S: ExtractFromVocabulary<V>, P: ExtractFromVocabulary<V>, O: ExtractFromVocabulary<V>>
 	ExtractFromVocabulary<V> for Triple<S, P, O>
 {
@@ -310,6 +510,14 @@ impl<S: RdfDisplay, P: RdfDisplay, O: RdfDisplay> RdfDisplay for Triple<S, P, O>
 			self.2.rdf_display()
 		)
 	}
+
+	fn rdf_write<W: io::Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+		self.0.rdf_write(writer)?;
+		writer.write_all(b" ")?;
+		self.1.rdf_write(writer)?;
+		writer.write_all(b" ")?;
+		self.2.rdf_write(writer)
+	}
 }
 
 #[cfg(feature = "contextual")]
@@ -341,3 +549,49 @@ impl<S: RdfDisplayWithContext<V>, P: RdfDisplayWithContext<V>, O: RdfDisplayWith
 		)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::Triple;
+	use crate::{Term, RDF_OBJECT, RDF_SUBJECT};
+	use static_iref::iri;
+
+	fn reification() -> (Term, [Triple; 4]) {
+		let statement: Term = "_:stmt".parse().unwrap();
+		let triple = Triple(
+			Term::iri(iri!("http://example.org/a").to_owned()),
+			Term::iri(iri!("http://example.org/p").to_owned()),
+			Term::iri(iri!("http://example.org/b").to_owned()),
+		);
+
+		(statement.clone(), triple.reify(statement))
+	}
+
+	#[test]
+	fn dereify_missing_component() {
+		let (statement, reification) = reification();
+
+		let triples = reification
+			.iter()
+			.map(Triple::as_ref)
+			.filter(|t| t.1.as_iri().map(|i| i.as_iri()) != Some(RDF_OBJECT));
+		assert_eq!(Triple::dereify(&statement, triples), None)
+	}
+
+	#[test]
+	fn dereify_conflicting_component() {
+		let (statement, reification) = reification();
+
+		let other = Triple(
+			statement.clone(),
+			Term::iri(RDF_SUBJECT.to_owned()),
+			Term::iri(iri!("http://example.org/c").to_owned()),
+		);
+		let triples = reification.iter().chain([&other]).map(Triple::as_ref);
+		assert_eq!(Triple::dereify(&statement, triples), None);
+
+		let duplicate = reification[1].clone();
+		let triples = reification.iter().chain([&duplicate]).map(Triple::as_ref);
+		assert!(Triple::dereify(&statement, triples).is_some())
+	}
+}
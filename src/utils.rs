@@ -1,6 +1,9 @@
-use std::marker::PhantomData;
+use std::{cmp::Ordering, collections::BTreeSet, marker::PhantomData, mem::size_of};
 
-use crate::{Quad, Triple};
+use raw_btree::RawBTree;
+use slab::Slab;
+
+use crate::{Quad, Term, Triple};
 
 pub struct InfallibleIterator<I>(pub I);
 
@@ -37,3 +40,76 @@ impl<I: Iterator> Iterator for OptionIterator<I> {
 		self.0.as_mut().and_then(I::next)
 	}
 }
+
+/// Maps the literals of the given indexed resources, collecting the quads
+/// over them into a new dataset.
+///
+/// Each resource is mapped once, then cloned into the quads using it, so that
+/// literals never need to be cloned.
+pub(crate) fn map_quads_literals<I: Clone, L, M: Clone, D>(
+	resources: impl IntoIterator<Item = (usize, Term<I, L>)>,
+	quads: impl IntoIterator<Item = Quad<usize>>,
+	mut f: impl FnMut(L) -> M,
+) -> D
+where
+	D: FromIterator<Quad<Term<I, M>>>,
+{
+	let resources: Slab<Term<I, M>> = resources
+		.into_iter()
+		.map(|(i, term)| (i, term.map_literal(&mut f)))
+		.collect();
+
+	quads
+		.into_iter()
+		.map(|quad| quad.map(|i| resources[i].clone()))
+		.collect()
+}
+
+/// Estimates the heap bytes allocated by the given slab.
+///
+/// Slab entries are either occupied by a value or vacant, storing the next
+/// vacant index, which is laid out like `Result<T, usize>`.
+pub(crate) fn slab_footprint<T>(slab: &Slab<T>) -> usize {
+	slab.capacity() * size_of::<Result<T, usize>>()
+}
+
+/// Computes the heap bytes allocated by the nodes of the given B-tree.
+pub(crate) fn raw_btree_footprint<T>(tree: &RawBTree<T>) -> usize {
+	let mut nodes = 0;
+	tree.visit_from_leaves(|_| nodes += 1);
+	nodes * size_of::<raw_btree::Node<T, raw_btree::storage::BoxStorage>>()
+}
+
+/// Clones the given B-tree.
+///
+/// `RawBTree::clone` does not link the cloned nodes to their parent (it is
+/// unsound as of raw-btree 0.4.0), so the tree is rebuilt instead.
+pub(crate) fn clone_raw_btree<T: Clone>(tree: &RawBTree<T>) -> RawBTree<T> {
+	raw_btree_from_sorted(tree.iter().cloned())
+}
+
+/// Builds a B-tree from the given items, assuming they are sorted.
+///
+/// Each item is inserted after all the previous ones, which does not require
+/// comparing them.
+pub(crate) fn raw_btree_from_sorted<T>(items: impl IntoIterator<Item = T>) -> RawBTree<T> {
+	let mut result = RawBTree::new();
+	for item in items {
+		result.insert(|_, _| Ordering::Less, item);
+	}
+	result
+}
+
+/// Maximum number of elements in a node of the standard B-tree.
+const BTREE_NODE_CAPACITY: usize = 11;
+
+/// Estimates the heap bytes allocated by the given B-tree set.
+///
+/// The standard library does not expose its nodes, so this assumes they are
+/// on average two-thirds full, and only accounts for the elements and the
+/// parent link of each node.
+pub(crate) fn btree_set_footprint<T>(set: &BTreeSet<T>) -> usize {
+	let per_node = BTREE_NODE_CAPACITY * 2 / 3;
+	let nodes = (set.len() + per_node - 1) / per_node;
+	nodes * (BTREE_NODE_CAPACITY * size_of::<T>() + size_of::<usize>() * 2)
+}
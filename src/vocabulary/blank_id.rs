@@ -16,6 +16,32 @@ pub trait BlankIdVocabulary {
 
 	/// Returns the vocabulary id of the given blank node identifier, if any.
 	fn get_blank_id(&self, id: &BlankId) -> Option<Self::BlankId>;
+
+	/// Returns the vocabulary id of each given blank node identifier, if
+	/// any, in order.
+	///
+	/// By default this calls [`Self::get_blank_id`] on each blank node
+	/// identifier. Vocabularies able to resolve many blank node identifiers
+	/// at once more efficiently can override it.
+	fn get_blank_ids<'b>(
+		&self,
+		ids: impl IntoIterator<Item = &'b BlankId>,
+	) -> Vec<Option<Self::BlankId>>
+	where
+		Self: Sized,
+	{
+		ids.into_iter().map(|id| self.get_blank_id(id)).collect()
+	}
+
+	/// Returns the external identifier from which the given blank node
+	/// identifier was created using
+	/// [`BlankIdVocabularyMut::insert_or_get_named`], if any.
+	///
+	/// Only vocabularies recording the mapping between external identifiers
+	/// and blank node identifiers can answer. By default this returns `None`.
+	fn external_name_of<'b>(&'b self, _id: &'b Self::BlankId) -> Option<&'b str> {
+		None
+	}
 }
 
 impl<'a, V: BlankIdVocabulary> BlankIdVocabulary for &'a V {
@@ -32,6 +58,17 @@ impl<'a, V: BlankIdVocabulary> BlankIdVocabulary for &'a V {
 	fn get_blank_id(&self, id: &BlankId) -> Option<Self::BlankId> {
 		V::get_blank_id(*self, id)
 	}
+
+	fn get_blank_ids<'b>(
+		&self,
+		ids: impl IntoIterator<Item = &'b BlankId>,
+	) -> Vec<Option<Self::BlankId>> {
+		V::get_blank_ids(*self, ids)
+	}
+
+	fn external_name_of<'b>(&'b self, id: &'b Self::BlankId) -> Option<&'b str> {
+		V::external_name_of(*self, id)
+	}
 }
 
 impl<'a, V: BlankIdVocabulary> BlankIdVocabulary for &'a mut V {
@@ -48,6 +85,17 @@ impl<'a, V: BlankIdVocabulary> BlankIdVocabulary for &'a mut V {
 	fn get_blank_id(&self, id: &BlankId) -> Option<Self::BlankId> {
 		V::get_blank_id(*self, id)
 	}
+
+	fn get_blank_ids<'b>(
+		&self,
+		ids: impl IntoIterator<Item = &'b BlankId>,
+	) -> Vec<Option<Self::BlankId>> {
+		V::get_blank_ids(*self, ids)
+	}
+
+	fn external_name_of<'b>(&'b self, id: &'b Self::BlankId) -> Option<&'b str> {
+		V::external_name_of(*self, id)
+	}
 }
 
 /// Mutable blank node identifier vocabulary.
@@ -61,6 +109,19 @@ pub trait BlankIdVocabularyMut: BlankIdVocabulary {
 	fn insert_owned_blank_id(&mut self, id: BlankIdBuf) -> Self::BlankId {
 		self.insert_blank_id(id.as_blank_id_ref())
 	}
+
+	/// Inserts the blank node identifier derived from the given external
+	/// identifier, and returns its id.
+	///
+	/// The blank node identifier is computed using
+	/// [`BlankIdBuf::from_external`], so the same external identifier always
+	/// maps to the same vocabulary id. Vocabularies recording the mapping
+	/// override this method so that
+	/// [`BlankIdVocabulary::external_name_of`] can give the external
+	/// identifier back.
+	fn insert_or_get_named(&mut self, external: &str) -> Self::BlankId {
+		self.insert_owned_blank_id(BlankIdBuf::from_external(external))
+	}
 }
 
 impl<'a, V: BlankIdVocabularyMut> BlankIdVocabularyMut for &'a mut V {
@@ -71,6 +132,10 @@ impl<'a, V: BlankIdVocabularyMut> BlankIdVocabularyMut for &'a mut V {
 	fn insert_owned_blank_id(&mut self, id: BlankIdBuf) -> Self::BlankId {
 		V::insert_owned_blank_id(*self, id)
 	}
+
+	fn insert_or_get_named(&mut self, external: &str) -> Self::BlankId {
+		V::insert_or_get_named(*self, external)
+	}
 }
 
 impl<'a, V: BlankIdVocabularyMut> EmbedIntoVocabulary<V> for &'a BlankId {
@@ -36,10 +36,8 @@ where
 	type Extracted = Literal;
 
 	fn extract_from_vocabulary(self, vocabulary: &V) -> Self::Extracted {
-		let literal = vocabulary.owned_literal(self).unwrap();
-		let value = literal.value;
-		let type_ = literal.type_.extract_from_vocabulary(vocabulary);
-		Literal::new(value, type_)
+		let (value, type_) = vocabulary.owned_literal(self).unwrap().into_literal_parts();
+		Literal::from_literal_value(value, type_.extract_from_vocabulary(vocabulary))
 	}
 }
 
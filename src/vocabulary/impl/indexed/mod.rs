@@ -1,11 +1,13 @@
+use std::collections::HashMap;
 use std::hash::Hash;
 use std::marker::PhantomData;
+use std::ops::Range;
 
 use crate::vocabulary::{
 	BlankIdVocabulary, BlankIdVocabularyMut, IriVocabulary, IriVocabularyMut, LiteralVocabulary,
 	LiteralVocabularyMut,
 };
-use crate::{BlankId, BlankIdBuf, Literal, LiteralRef};
+use crate::{BlankId, BlankIdBuf, Literal, LiteralRef, LiteralType};
 use indexmap::IndexSet;
 use iref::{Iri, IriBuf};
 
@@ -23,6 +25,8 @@ pub struct IndexVocabulary<I = IriIndex, B = BlankIdIndex, L = LiteralIndex> {
 	iri: IndexSet<IriBuf>,
 	blank_id: IndexSet<BlankIdBuf>,
 	literal: IndexSet<Literal<I>>,
+	named_blank_id: HashMap<String, BlankIdBuf>,
+	blank_id_name: HashMap<BlankIdBuf, String>,
 	bl: PhantomData<(B, L)>,
 }
 
@@ -32,6 +36,8 @@ impl<I, B, L> Default for IndexVocabulary<I, B, L> {
 			iri: IndexSet::new(),
 			blank_id: IndexSet::new(),
 			literal: IndexSet::new(),
+			named_blank_id: HashMap::new(),
+			blank_id_name: HashMap::new(),
 			bl: PhantomData,
 		}
 	}
@@ -43,6 +49,295 @@ impl<I, B> IndexVocabulary<I, B> {
 	}
 }
 
+impl<I, B, L> IndexVocabulary<I, B, L> {
+	/// Returns the number of interned IRIs.
+	pub fn iri_count(&self) -> usize {
+		self.iri.len()
+	}
+
+	/// Returns the number of interned blank node identifiers.
+	pub fn blank_id_count(&self) -> usize {
+		self.blank_id.len()
+	}
+
+	/// Returns the number of interned literals.
+	pub fn literal_count(&self) -> usize {
+		self.literal.len()
+	}
+
+	/// Returns an iterator over the interned IRIs, with their index.
+	pub fn iris(&self) -> Iris<'_, I> {
+		self.since(VocabMark::default())
+	}
+
+	/// Returns an iterator over the interned blank node identifiers, with
+	/// their index.
+	pub fn blank_ids(&self) -> BlankIds<'_, B> {
+		self.blank_ids_since(VocabMark::default())
+	}
+
+	/// Returns an iterator over the interned literals, with their index.
+	pub fn literals(&self) -> Literals<'_, I, L> {
+		self.literals_since(VocabMark::default())
+	}
+
+	/// Returns a checkpoint of the current state of the vocabulary.
+	///
+	/// The IRIs, blank node identifiers and literals interned after this
+	/// call can then be listed using [`Self::since`],
+	/// [`Self::blank_ids_since`] and [`Self::literals_since`].
+	/// Removing IRIs (with [`Self::remove_iri`] or [`Self::gc`]) shifts
+	/// indexes and invalidates previous marks.
+	pub fn mark(&self) -> VocabMark {
+		VocabMark {
+			iri: self.iri.len(),
+			blank_id: self.blank_id.len(),
+			literal: self.literal.len(),
+		}
+	}
+
+	/// Returns an iterator over the IRIs interned after the given mark,
+	/// with their index.
+	pub fn since(&self, mark: VocabMark) -> Iris<'_, I> {
+		Iris {
+			iris: &self.iri,
+			range: mark.iri.min(self.iri.len())..self.iri.len(),
+			i: PhantomData,
+		}
+	}
+
+	/// Returns an iterator over the blank node identifiers interned after
+	/// the given mark, with their index.
+	pub fn blank_ids_since(&self, mark: VocabMark) -> BlankIds<'_, B> {
+		BlankIds {
+			blank_ids: &self.blank_id,
+			range: mark.blank_id.min(self.blank_id.len())..self.blank_id.len(),
+			b: PhantomData,
+		}
+	}
+
+	/// Returns an iterator over the literals interned after the given mark,
+	/// with their index.
+	pub fn literals_since(&self, mark: VocabMark) -> Literals<'_, I, L> {
+		Literals {
+			literals: &self.literal,
+			range: mark.literal.min(self.literal.len())..self.literal.len(),
+			l: PhantomData,
+		}
+	}
+}
+
+impl<I: IndexedIri + Eq + Hash, B, L> IndexVocabulary<I, B, L> {
+	/// Removes the given IRI from the vocabulary, returning it.
+	///
+	/// Every IRI index greater than the removed one is shifted down by one,
+	/// and the datatypes of interned literals are updated accordingly.
+	/// The returned mapping from old to new IRI indexes must be used to
+	/// update every IRI index stored outside of the vocabulary, exactly as
+	/// with [`Self::gc`].
+	///
+	/// Returns `None`, leaving the vocabulary untouched, if `id` is not an
+	/// index of this vocabulary or if the IRI is used as the datatype of an
+	/// interned literal.
+	pub fn remove_iri(&mut self, id: &I) -> Option<(IriBuf, IndexRemapping)> {
+		let IriOrIndex::Index(i) = id.index() else {
+			return None;
+		};
+
+		let keep = self.iri_keep_flags(|j| j != i);
+		if *keep.get(i)? {
+			return None;
+		}
+
+		let (remapping, mut removed) = self.retain_iris(keep);
+		removed.pop().map(|iri| (iri, remapping))
+	}
+
+	/// Removes all the IRIs that are not `live` and compacts the IRI
+	/// indexes.
+	///
+	/// IRIs used as the datatype of an interned literal are always kept, and
+	/// the datatypes of interned literals are updated to the new indexes,
+	/// so literal indexes are left unchanged. Blank node identifiers and
+	/// literals are never collected.
+	///
+	/// Returns the mapping from old to new IRI indexes, which must be used
+	/// to update every IRI index stored outside of the vocabulary.
+	pub fn gc(&mut self, live: impl Fn(&I) -> bool) -> IndexRemapping {
+		let keep = self.iri_keep_flags(|i| live(&I::from(i)));
+		self.retain_iris(keep).0
+	}
+
+	fn iri_keep_flags(&self, live: impl Fn(usize) -> bool) -> Vec<bool> {
+		let mut keep: Vec<bool> = (0..self.iri.len()).map(live).collect();
+
+		for literal in &self.literal {
+			if let LiteralType::Any(ty) = &literal.type_ {
+				if let IriOrIndex::Index(i) = ty.index() {
+					if let Some(k) = keep.get_mut(i) {
+						*k = true
+					}
+				}
+			}
+		}
+
+		keep
+	}
+
+	fn retain_iris(&mut self, keep: Vec<bool>) -> (IndexRemapping, Vec<IriBuf>) {
+		let mut iris = IndexSet::with_capacity(self.iri.len());
+		let mut mapping = Vec::with_capacity(self.iri.len());
+		let mut removed = Vec::new();
+
+		for (iri, keep) in std::mem::take(&mut self.iri).into_iter().zip(keep) {
+			if keep {
+				mapping.push(Some(iris.insert_full(iri).0))
+			} else {
+				mapping.push(None);
+				removed.push(iri)
+			}
+		}
+
+		self.iri = iris;
+		let remapping = IndexRemapping(mapping);
+
+		self.literal = std::mem::take(&mut self.literal)
+			.into_iter()
+			.map(|literal| {
+				let (value, type_) = literal.into_literal_parts();
+				let type_ = match type_ {
+					LiteralType::Any(ty) => LiteralType::Any(
+						remapping
+							.remap_iri(ty)
+							.expect("datatype IRIs are never removed"),
+					),
+					LiteralType::LangString(tag) => LiteralType::LangString(tag),
+					LiteralType::DirLangString(tag, d) => LiteralType::DirLangString(tag, d),
+				};
+
+				Literal::from_literal_value(value, type_)
+			})
+			.collect();
+
+		(remapping, removed)
+	}
+}
+
+/// Checkpoint of an [`IndexVocabulary`].
+///
+/// Returned by [`IndexVocabulary::mark`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VocabMark {
+	iri: usize,
+	blank_id: usize,
+	literal: usize,
+}
+
+/// Iterator over the IRIs of an [`IndexVocabulary`], with their index.
+///
+/// Returned by [`IndexVocabulary::iris`] and [`IndexVocabulary::since`].
+pub struct Iris<'a, I> {
+	iris: &'a IndexSet<IriBuf>,
+	range: Range<usize>,
+	i: PhantomData<I>,
+}
+
+impl<'a, I: From<usize>> Iterator for Iris<'a, I> {
+	type Item = (I, &'a Iri);
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.range.size_hint()
+	}
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let i = self.range.next()?;
+		Some((i.into(), self.iris[i].as_iri()))
+	}
+}
+
+impl<'a, I: From<usize>> ExactSizeIterator for Iris<'a, I> {}
+
+/// Iterator over the blank node identifiers of an [`IndexVocabulary`], with
+/// their index.
+///
+/// Returned by [`IndexVocabulary::blank_ids`] and
+/// [`IndexVocabulary::blank_ids_since`].
+pub struct BlankIds<'a, B> {
+	blank_ids: &'a IndexSet<BlankIdBuf>,
+	range: Range<usize>,
+	b: PhantomData<B>,
+}
+
+impl<'a, B: From<usize>> Iterator for BlankIds<'a, B> {
+	type Item = (B, &'a BlankId);
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.range.size_hint()
+	}
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let i = self.range.next()?;
+		Some((i.into(), self.blank_ids[i].as_blank_id_ref()))
+	}
+}
+
+impl<'a, B: From<usize>> ExactSizeIterator for BlankIds<'a, B> {}
+
+/// Iterator over the literals of an [`IndexVocabulary`], with their index.
+///
+/// Returned by [`IndexVocabulary::literals`] and
+/// [`IndexVocabulary::literals_since`].
+pub struct Literals<'a, I, L> {
+	literals: &'a IndexSet<Literal<I>>,
+	range: Range<usize>,
+	l: PhantomData<L>,
+}
+
+impl<'a, I, L: From<usize>> Iterator for Literals<'a, I, L> {
+	type Item = (L, &'a Literal<I>);
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.range.size_hint()
+	}
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let i = self.range.next()?;
+		Some((i.into(), &self.literals[i]))
+	}
+}
+
+impl<'a, I, L: From<usize>> ExactSizeIterator for Literals<'a, I, L> {}
+
+/// Mapping from old to new indexes.
+///
+/// Returned by [`IndexVocabulary::gc`] and [`IndexVocabulary::remove_iri`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexRemapping(Vec<Option<usize>>);
+
+impl IndexRemapping {
+	/// Returns the new index of the given old index, or `None` if it has been
+	/// removed.
+	pub fn get(&self, index: usize) -> Option<usize> {
+		self.0.get(index).copied().flatten()
+	}
+
+	/// Remaps the given IRI identifier.
+	///
+	/// Non-indexed IRIs are returned unchanged. Returns `None` if the IRI has
+	/// been removed.
+	pub fn remap_iri<I: IndexedIri>(&self, id: I) -> Option<I> {
+		let index = match id.index() {
+			IriOrIndex::Index(i) => Some(i),
+			IriOrIndex::Iri(_) => None,
+		};
+
+		match index {
+			Some(i) => self.get(i).map(I::from),
+			None => Some(id),
+		}
+	}
+}
+
 impl<I: IndexedIri, B, L> IriVocabulary for IndexVocabulary<I, B, L> {
 	type Iri = I;
 
@@ -56,7 +351,7 @@ impl<I: IndexedIri, B, L> IriVocabulary for IndexVocabulary<I, B, L> {
 	fn get(&self, iri: &Iri) -> Option<I> {
 		match I::try_from(iri) {
 			Ok(id) => Some(id),
-			Err(_) => self.iri.get_index_of(&iri.to_owned()).map(I::from),
+			Err(_) => self.iri.get_index_of(iri).map(I::from),
 		}
 	}
 }
@@ -65,7 +360,10 @@ impl<I: IndexedIri, B, L> IriVocabularyMut for IndexVocabulary<I, B, L> {
 	fn insert(&mut self, iri: &Iri) -> I {
 		match I::try_from(iri) {
 			Ok(id) => id,
-			Err(_) => self.iri.insert_full(iri.to_owned()).0.into(),
+			Err(_) => match self.iri.get_index_of(iri) {
+				Some(i) => i.into(),
+				None => self.iri.insert_full(iri.to_owned()).0.into(),
+			},
 		}
 	}
 
@@ -97,6 +395,24 @@ impl<I, B: IndexedBlankId, L> BlankIdVocabulary for IndexVocabulary<I, B, L> {
 				.map(B::from),
 		}
 	}
+
+	/// Probes the interned blank node identifiers with the borrowed
+	/// identifiers directly, instead of building an owned key for each
+	/// lookup as [`Self::get_blank_id`] does.
+	fn get_blank_ids<'b>(&self, ids: impl IntoIterator<Item = &'b BlankId>) -> Vec<Option<B>> {
+		ids.into_iter()
+			.map(|id| match B::try_from(id) {
+				Ok(id) => Some(id),
+				Err(_) => self.blank_id.get_index_of(id).map(B::from),
+			})
+			.collect()
+	}
+
+	fn external_name_of<'b>(&'b self, id: &'b B) -> Option<&'b str> {
+		self.blank_id_name
+			.get(self.blank_id(id)?)
+			.map(String::as_str)
+	}
 }
 
 impl<I, B: IndexedBlankId, L> BlankIdVocabularyMut for IndexVocabulary<I, B, L> {
@@ -114,6 +430,18 @@ impl<I, B: IndexedBlankId, L> BlankIdVocabularyMut for IndexVocabulary<I, B, L>
 
 		self.blank_id.insert_full(id).0.into()
 	}
+
+	fn insert_or_get_named(&mut self, external: &str) -> Self::BlankId {
+		match self.named_blank_id.get(external).cloned() {
+			Some(id) => self.insert_owned_blank_id(id),
+			None => {
+				let id = BlankIdBuf::from_external(external);
+				self.named_blank_id.insert(external.to_owned(), id.clone());
+				self.blank_id_name.insert(id.clone(), external.to_owned());
+				self.insert_owned_blank_id(id)
+			}
+		}
+	}
 }
 
 impl<I: Clone + IndexedIri + Eq + Hash, B, L: IndexedLiteral<I>> LiteralVocabulary
@@ -147,6 +475,25 @@ impl<I: Clone + IndexedIri + Eq + Hash, B, L: IndexedLiteral<I>> LiteralVocabula
 				.map(L::from),
 		}
 	}
+
+	/// Probes the interned literals with the borrowed literals directly,
+	/// instead of building an owned key for each lookup as
+	/// [`Self::get_literal`] does.
+	fn get_literals<'l>(
+		&self,
+		literals: impl IntoIterator<Item = LiteralRef<'l, I>>,
+	) -> Vec<Option<L>>
+	where
+		I: 'l,
+	{
+		literals
+			.into_iter()
+			.map(|literal| match L::try_from(literal) {
+				Ok(id) => Some(id),
+				Err(_) => self.literal.get_index_of(&literal).map(L::from),
+			})
+			.collect()
+	}
 }
 
 impl<I: IndexedIri + Clone + Eq + Hash, B, L: IndexedLiteral<I>> LiteralVocabularyMut
@@ -166,3 +513,164 @@ impl<I: IndexedIri + Clone + Eq + Hash, B, L: IndexedLiteral<I>> LiteralVocabula
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use static_iref::iri;
+
+	use super::{IndexVocabulary, IriIndex};
+	use crate::{
+		vocabulary::{
+			BlankIdVocabulary, BlankIdVocabularyMut, IriVocabulary, IriVocabularyMut,
+			LiteralVocabulary, LiteralVocabularyMut,
+		},
+		BlankId, Literal, LiteralType,
+	};
+
+	#[test]
+	fn gc() {
+		let mut vocabulary: IndexVocabulary = IndexVocabulary::new();
+		let a = vocabulary.insert(iri!("http://example.org/a"));
+		let b = vocabulary.insert(iri!("http://example.org/b"));
+		let datatype = vocabulary.insert(iri!("http://example.org/datatype"));
+		let c = vocabulary.insert(iri!("http://example.org/c"));
+		let literal = vocabulary
+			.insert_owned_literal(Literal::new("value".to_owned(), LiteralType::Any(datatype)));
+
+		let remapping = vocabulary.gc(|i| *i == c);
+
+		assert_eq!(remapping.remap_iri(a), None);
+		assert_eq!(remapping.remap_iri(b), None);
+		let datatype = remapping.remap_iri(datatype).unwrap();
+		let c = remapping.remap_iri(c).unwrap();
+
+		assert_eq!(
+			vocabulary.iri(&datatype).unwrap(),
+			iri!("http://example.org/datatype")
+		);
+		assert_eq!(vocabulary.iri(&c).unwrap(), iri!("http://example.org/c"));
+		assert_eq!(
+			vocabulary.owned_literal(literal).unwrap().type_,
+			LiteralType::Any(datatype)
+		);
+		assert_eq!(
+			vocabulary.get(iri!("http://example.org/a")),
+			None::<IriIndex>
+		);
+	}
+
+	#[test]
+	fn remove_iri() {
+		let mut vocabulary: IndexVocabulary = IndexVocabulary::new();
+		let a = vocabulary.insert(iri!("http://example.org/a"));
+		let b = vocabulary.insert(iri!("http://example.org/b"));
+		let datatype = vocabulary.insert(iri!("http://example.org/datatype"));
+		vocabulary
+			.insert_owned_literal(Literal::new("value".to_owned(), LiteralType::Any(datatype)));
+
+		assert_eq!(vocabulary.remove_iri(&datatype), None);
+
+		let (removed, remapping) = vocabulary.remove_iri(&a).unwrap();
+		assert_eq!(removed, iri!("http://example.org/a"));
+		assert_eq!(remapping.remap_iri(a), None);
+
+		// Indexes still held after the removal must go through the remapping.
+		let b = remapping.remap_iri(b).unwrap();
+		let datatype = remapping.remap_iri(datatype).unwrap();
+		assert_eq!(vocabulary.iri(&b), Some(iri!("http://example.org/b")));
+		assert_eq!(
+			vocabulary.iri(&datatype),
+			Some(iri!("http://example.org/datatype"))
+		);
+		assert_eq!(vocabulary.get(iri!("http://example.org/b")), Some(b));
+		assert_eq!(vocabulary.remove_iri(&datatype), None);
+	}
+
+	#[test]
+	fn named_blank_ids() {
+		let mut vocabulary: IndexVocabulary = IndexVocabulary::new();
+		let a = vocabulary.insert_or_get_named("user/42");
+		let b = vocabulary.insert_blank_id(BlankId::new("_:x1").unwrap());
+
+		assert_eq!(vocabulary.insert_or_get_named("user/42"), a);
+		assert_eq!(vocabulary.external_name_of(&a), Some("user/42"));
+		assert_eq!(vocabulary.external_name_of(&b), None);
+		assert_eq!(vocabulary.blank_id_count(), 2);
+	}
+
+	#[test]
+	fn iteration() {
+		let mut vocabulary: IndexVocabulary = IndexVocabulary::new();
+		let a = vocabulary.insert(iri!("http://example.org/a"));
+		let b = vocabulary.insert(iri!("http://example.org/b"));
+		vocabulary.insert(iri!("http://example.org/a"));
+		let blank_id = vocabulary.insert_blank_id(BlankId::new("_:b").unwrap());
+		vocabulary.insert_blank_id(BlankId::new("_:b").unwrap());
+
+		assert_eq!(vocabulary.iri_count(), 2);
+		assert_eq!(vocabulary.blank_id_count(), 1);
+		assert_eq!(vocabulary.literal_count(), 0);
+		assert_eq!(
+			vocabulary.iris().collect::<Vec<_>>(),
+			[
+				(a, iri!("http://example.org/a")),
+				(b, iri!("http://example.org/b"))
+			]
+		);
+		assert_eq!(
+			vocabulary.blank_ids().collect::<Vec<_>>(),
+			[(blank_id, BlankId::new("_:b").unwrap())]
+		);
+		assert_eq!(vocabulary.literals().count(), 0)
+	}
+
+	#[test]
+	fn since() {
+		let mut vocabulary: IndexVocabulary = IndexVocabulary::new();
+		vocabulary.insert(iri!("http://example.org/a"));
+		vocabulary.insert_blank_id(BlankId::new("_:a").unwrap());
+		let mark = vocabulary.mark();
+
+		assert_eq!(vocabulary.since(mark).count(), 0);
+
+		let a = vocabulary.insert(iri!("http://example.org/a"));
+		let b = vocabulary.insert(iri!("http://example.org/b"));
+		let blank_id = vocabulary.insert_blank_id(BlankId::new("_:b").unwrap());
+		let literal =
+			vocabulary.insert_owned_literal(Literal::new("value".to_owned(), LiteralType::Any(a)));
+
+		assert_eq!(
+			vocabulary.since(mark).collect::<Vec<_>>(),
+			[(b, iri!("http://example.org/b"))]
+		);
+		assert_eq!(
+			vocabulary.blank_ids_since(mark).collect::<Vec<_>>(),
+			[(blank_id, BlankId::new("_:b").unwrap())]
+		);
+		assert_eq!(
+			vocabulary.literals_since(mark).collect::<Vec<_>>(),
+			[(
+				literal,
+				&Literal::new("value".to_owned(), LiteralType::Any(a))
+			)]
+		);
+		assert_eq!(vocabulary.since(vocabulary.mark()).count(), 0)
+	}
+
+	#[test]
+	fn get_or_insert_str() {
+		let mut vocabulary: IndexVocabulary = IndexVocabulary::new();
+		let a = vocabulary.insert(iri!("http://example.org/a"));
+		assert_eq!(
+			vocabulary.get_or_insert_str("http://example.org/a").ok(),
+			Some(a)
+		);
+
+		let b = vocabulary
+			.get_or_insert_str("http://example.org/b")
+			.unwrap();
+		assert_ne!(a, b);
+		assert_eq!(vocabulary.get(iri!("http://example.org/b")), Some(b));
+		assert!(vocabulary.get_or_insert_str("not an IRI").is_err())
+	}
+}
@@ -1,4 +1,4 @@
-use iref::{Iri, IriBuf};
+use iref::{InvalidIri, Iri, IriBuf};
 
 use super::{EmbedIntoVocabulary, EmbeddedIntoVocabulary};
 
@@ -16,6 +16,17 @@ pub trait IriVocabulary {
 
 	/// Returns the id of the given IRI, if any.
 	fn get(&self, iri: &Iri) -> Option<Self::Iri>;
+
+	/// Returns the id of each given IRI, if any, in order.
+	///
+	/// By default this calls [`Self::get`] on each IRI. Vocabularies able
+	/// to resolve many IRIs at once more efficiently can override it.
+	fn get_iris<'i>(&self, iris: impl IntoIterator<Item = &'i Iri>) -> Vec<Option<Self::Iri>>
+	where
+		Self: Sized,
+	{
+		iris.into_iter().map(|iri| self.get(iri)).collect()
+	}
 }
 
 impl<'a, V: IriVocabulary> IriVocabulary for &'a V {
@@ -32,6 +43,10 @@ impl<'a, V: IriVocabulary> IriVocabulary for &'a V {
 	fn get(&self, iri: &Iri) -> Option<Self::Iri> {
 		V::get(*self, iri)
 	}
+
+	fn get_iris<'i>(&self, iris: impl IntoIterator<Item = &'i Iri>) -> Vec<Option<Self::Iri>> {
+		V::get_iris(*self, iris)
+	}
 }
 
 impl<'a, V: IriVocabulary> IriVocabulary for &'a mut V {
@@ -48,6 +63,10 @@ impl<'a, V: IriVocabulary> IriVocabulary for &'a mut V {
 	fn get(&self, iri: &Iri) -> Option<Self::Iri> {
 		V::get(*self, iri)
 	}
+
+	fn get_iris<'i>(&self, iris: impl IntoIterator<Item = &'i Iri>) -> Vec<Option<Self::Iri>> {
+		V::get_iris(*self, iris)
+	}
 }
 
 /// Mutable IRI vocabulary.
@@ -61,6 +80,15 @@ pub trait IriVocabularyMut: IriVocabulary {
 	fn insert_owned(&mut self, iri: IriBuf) -> Self::Iri {
 		self.insert(iri.as_iri())
 	}
+
+	/// Parses the given string as an IRI and inserts it to the vocabulary,
+	/// returning its id.
+	///
+	/// Contrarily to first building an [`IriBuf`], no allocation occurs if
+	/// the IRI is already present in the vocabulary.
+	fn get_or_insert_str<'s>(&mut self, iri: &'s str) -> Result<Self::Iri, InvalidIri<&'s str>> {
+		Iri::new(iri).map(|iri| self.insert(iri))
+	}
 }
 
 impl<'a, V: IriVocabularyMut> IriVocabularyMut for &'a mut V {
@@ -71,6 +99,10 @@ impl<'a, V: IriVocabularyMut> IriVocabularyMut for &'a mut V {
 	fn insert_owned(&mut self, iri: IriBuf) -> Self::Iri {
 		V::insert_owned(*self, iri)
 	}
+
+	fn get_or_insert_str<'s>(&mut self, iri: &'s str) -> Result<Self::Iri, InvalidIri<&'s str>> {
+		V::get_or_insert_str(*self, iri)
+	}
 }
 
 impl<'a, V: IriVocabularyMut> EmbedIntoVocabulary<V> for &'a Iri {
@@ -0,0 +1,275 @@
+use std::{
+	cmp::Ordering,
+	fmt,
+	hash::{Hash, Hasher},
+};
+
+use crate::{Id, LiteralTypeRef, Term};
+
+use super::{
+	BlankIdIndex, BlankIdVocabulary, IriIndex, IriVocabulary, LiteralIndex, LiteralVocabulary,
+};
+
+/// Type whose values can be compared by their lexical representation,
+/// resolved using a vocabulary.
+pub trait LexicalCmp<V: ?Sized> {
+	/// Compares the lexical representations of `self` and `other`.
+	///
+	/// Values unknown to the vocabulary are ordered after all the known
+	/// values, and compared using their own order.
+	fn lexical_cmp(&self, other: &Self, vocabulary: &V) -> Ordering;
+}
+
+impl<V: ?Sized, T: LexicalCmp<V>> LexicalCmp<V> for &T {
+	fn lexical_cmp(&self, other: &Self, vocabulary: &V) -> Ordering {
+		T::lexical_cmp(*self, *other, vocabulary)
+	}
+}
+
+impl<V: ?Sized + IriVocabulary<Iri = IriIndex>> LexicalCmp<V> for IriIndex {
+	fn lexical_cmp(&self, other: &Self, vocabulary: &V) -> Ordering {
+		cmp_resolved(self, other, |i| vocabulary.iri(i))
+	}
+}
+
+impl<V: ?Sized + BlankIdVocabulary<BlankId = BlankIdIndex>> LexicalCmp<V> for BlankIdIndex {
+	fn lexical_cmp(&self, other: &Self, vocabulary: &V) -> Ordering {
+		cmp_resolved(self, other, |b| vocabulary.blank_id(b))
+	}
+}
+
+impl<V> LexicalCmp<V> for LiteralIndex
+where
+	V: ?Sized + LiteralVocabulary<Literal = LiteralIndex>,
+	V::Iri: LexicalCmp<V>,
+{
+	fn lexical_cmp(&self, other: &Self, vocabulary: &V) -> Ordering {
+		match (vocabulary.literal(self), vocabulary.literal(other)) {
+			(Some(a), Some(b)) => a.value.cmp(b.value).then_with(|| match (a.type_, b.type_) {
+				(LiteralTypeRef::Any(a), LiteralTypeRef::Any(b)) => a.lexical_cmp(b, vocabulary),
+				(LiteralTypeRef::Any(_), _) => Ordering::Less,
+				(_, LiteralTypeRef::Any(_)) => Ordering::Greater,
+				// Language strings come before directional language strings,
+				// as in the derived `Ord` of `LiteralType`.
+				(a, b) => (a.direction().is_some(), a.lang_tag(), a.direction()).cmp(&(
+					b.direction().is_some(),
+					b.lang_tag(),
+					b.direction(),
+				)),
+			}),
+			(Some(_), None) => Ordering::Less,
+			(None, Some(_)) => Ordering::Greater,
+			(None, None) => self.cmp(other),
+		}
+	}
+}
+
+impl<V: ?Sized, I: LexicalCmp<V>, B: LexicalCmp<V>> LexicalCmp<V> for Id<I, B> {
+	fn lexical_cmp(&self, other: &Self, vocabulary: &V) -> Ordering {
+		match (self, other) {
+			(Self::Blank(a), Self::Blank(b)) => a.lexical_cmp(b, vocabulary),
+			(Self::Blank(_), Self::Iri(_)) => Ordering::Less,
+			(Self::Iri(_), Self::Blank(_)) => Ordering::Greater,
+			(Self::Iri(a), Self::Iri(b)) => a.lexical_cmp(b, vocabulary),
+		}
+	}
+}
+
+impl<V: ?Sized, I: LexicalCmp<V>, L: LexicalCmp<V>> LexicalCmp<V> for Term<I, L> {
+	fn lexical_cmp(&self, other: &Self, vocabulary: &V) -> Ordering {
+		match (self, other) {
+			(Self::Id(a), Self::Id(b)) => a.lexical_cmp(b, vocabulary),
+			(Self::Id(_), Self::Literal(_)) => Ordering::Less,
+			(Self::Literal(_), Self::Id(_)) => Ordering::Greater,
+			(Self::Literal(a), Self::Literal(b)) => a.lexical_cmp(b, vocabulary),
+		}
+	}
+}
+
+/// Compares two identifiers by their resolved value, putting unresolved
+/// identifiers last.
+fn cmp_resolved<'a, T: Ord, U: ?Sized + Ord + 'a>(
+	a: &'a T,
+	b: &'a T,
+	resolve: impl Fn(&'a T) -> Option<&'a U>,
+) -> Ordering {
+	match (resolve(a), resolve(b)) {
+		(Some(a), Some(b)) => a.cmp(b),
+		(Some(_), None) => Ordering::Less,
+		(None, Some(_)) => Ordering::Greater,
+		(None, None) => a.cmp(b),
+	}
+}
+
+/// Resource ordered by its lexical representation.
+///
+/// Interned resources, such as [`IriIndex`], are ordered by interning order.
+/// This wrapper carries a reference to the vocabulary so that resources are
+/// instead ordered by their lexical representation (see [`LexicalCmp`]),
+/// following the same order as the equivalent lexical terms. For instance an
+/// [`IndexedBTreeDataset`](crate::dataset::IndexedBTreeDataset) of
+/// `LexicalOrd` resources iterates over its quads in lexical order.
+///
+/// Every resource borrows the vocabulary for `'v`, so the vocabulary must
+/// outlive any collection of such resources and cannot be mutated while it
+/// exists. Resources must be interned before being wrapped. Comparing
+/// resources wrapped with different vocabularies is meaningless: the
+/// vocabulary of the left-hand side operand is used.
+///
+/// ```
+/// use rdf_types::{
+///     dataset::IndexedBTreeDataset,
+///     vocabulary::{IndexVocabulary, IriVocabularyMut, LexicalOrd},
+///     Quad,
+/// };
+/// use static_iref::iri;
+///
+/// let mut vocabulary: IndexVocabulary = IndexVocabulary::new();
+/// let b = vocabulary.insert(iri!("http://example.org/b"));
+/// let a = vocabulary.insert(iri!("http://example.org/a"));
+///
+/// let mut dataset = IndexedBTreeDataset::new();
+/// let a = LexicalOrd::new(a, &vocabulary);
+/// let b = LexicalOrd::new(b, &vocabulary);
+/// dataset.insert(Quad(b, b, b, None));
+/// dataset.insert(Quad(a, a, a, None));
+///
+/// assert_eq!(dataset.iter().next().unwrap().0, &a);
+/// ```
+pub struct LexicalOrd<'v, V: ?Sized, T> {
+	value: T,
+	vocabulary: &'v V,
+}
+
+impl<'v, V: ?Sized, T> LexicalOrd<'v, V, T> {
+	/// Wraps the given resource, ordered using the given vocabulary.
+	pub fn new(value: T, vocabulary: &'v V) -> Self {
+		Self { value, vocabulary }
+	}
+
+	/// Returns a reference to the wrapped resource.
+	pub fn value(&self) -> &T {
+		&self.value
+	}
+
+	/// Returns the wrapped resource.
+	pub fn into_value(self) -> T {
+		self.value
+	}
+
+	/// Returns the vocabulary used to order the resource.
+	pub fn vocabulary(&self) -> &'v V {
+		self.vocabulary
+	}
+}
+
+impl<'v, V: ?Sized, T: Clone> Clone for LexicalOrd<'v, V, T> {
+	fn clone(&self) -> Self {
+		Self {
+			value: self.value.clone(),
+			vocabulary: self.vocabulary,
+		}
+	}
+}
+
+impl<'v, V: ?Sized, T: Copy> Copy for LexicalOrd<'v, V, T> {}
+
+impl<'v, V: ?Sized, T: LexicalCmp<V>> PartialEq for LexicalOrd<'v, V, T> {
+	fn eq(&self, other: &Self) -> bool {
+		self.cmp(other) == Ordering::Equal
+	}
+}
+
+impl<'v, V: ?Sized, T: LexicalCmp<V>> Eq for LexicalOrd<'v, V, T> {}
+
+impl<'v, V: ?Sized, T: LexicalCmp<V>> PartialOrd for LexicalOrd<'v, V, T> {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl<'v, V: ?Sized, T: LexicalCmp<V>> Ord for LexicalOrd<'v, V, T> {
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.value.lexical_cmp(&other.value, self.vocabulary)
+	}
+}
+
+/// Hashes the wrapped resource, which is consistent with [`Eq`] as long as
+/// the vocabulary maps distinct identifiers to distinct lexical values.
+impl<'v, V: ?Sized, T: Hash> Hash for LexicalOrd<'v, V, T> {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.value.hash(state)
+	}
+}
+
+impl<'v, V: ?Sized, T: fmt::Debug> fmt::Debug for LexicalOrd<'v, V, T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.value.fmt(f)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use static_iref::iri;
+
+	use super::{LexicalCmp, LexicalOrd};
+	use crate::{
+		dataset::IndexedBTreeDataset,
+		vocabulary::{
+			BlankIdIndex, BlankIdVocabularyMut, IndexVocabulary, IriIndex, IriVocabularyMut,
+			LiteralIndex, LiteralVocabularyMut,
+		},
+		BlankId, Direction, Id, Literal, LiteralType, Quad, Term,
+	};
+
+	type IndexTerm = Term<Id<IriIndex, BlankIdIndex>, LiteralIndex>;
+
+	#[test]
+	fn lexical_order() {
+		let mut vocabulary: IndexVocabulary = IndexVocabulary::new();
+		let c = vocabulary.insert(iri!("http://example.org/c"));
+		let a = vocabulary.insert(iri!("http://example.org/a"));
+		let blank = vocabulary.insert_blank_id(BlankId::new("_:b").unwrap());
+		let xsd_string = vocabulary.insert(iri!("http://www.w3.org/2001/XMLSchema#string"));
+		let z = vocabulary.insert_owned_literal(Literal::new("z", LiteralType::Any(xsd_string)));
+		let y = vocabulary.insert_owned_literal(Literal::new("y", LiteralType::Any(xsd_string)));
+
+		let terms: [IndexTerm; 5] = [
+			Term::Literal(z),
+			Term::iri(c),
+			Term::Literal(y),
+			Term::iri(a),
+			Term::blank(blank),
+		];
+
+		let mut dataset = IndexedBTreeDataset::new();
+		for t in terms {
+			let t = LexicalOrd::new(t, &vocabulary);
+			let p = LexicalOrd::new(Term::iri(a), &vocabulary);
+			dataset.insert(Quad(t, p, t, None));
+		}
+
+		let subjects: Vec<IndexTerm> = dataset.iter().map(|q| *q.0.value()).collect();
+		let expected: [IndexTerm; 5] = [
+			Term::blank(blank),
+			Term::iri(a),
+			Term::iri(c),
+			Term::Literal(y),
+			Term::Literal(z),
+		];
+		assert_eq!(subjects, expected)
+	}
+
+	#[test]
+	fn literal_type_order() {
+		let mut vocabulary: IndexVocabulary = IndexVocabulary::new();
+		let types = [
+			LiteralType::LangString("fr".parse().unwrap()),
+			LiteralType::DirLangString("en".parse().unwrap(), Direction::Ltr),
+		];
+		assert!(types[0] < types[1]);
+
+		let [a, b] = types.map(|ty| vocabulary.insert_owned_literal(Literal::new("a", ty)));
+		assert!(a.lexical_cmp(&b, &vocabulary).is_lt())
+	}
+}
@@ -12,6 +12,25 @@ pub trait LiteralVocabulary: IriVocabulary {
 
 	/// Returns the vocabulary id of the given literal identifier, if any.
 	fn get_literal(&self, id: LiteralRef<Self::Iri>) -> Option<Self::Literal>;
+
+	/// Returns the vocabulary id of each given literal, if any, in order.
+	///
+	/// By default this calls [`Self::get_literal`] on each literal.
+	/// Vocabularies able to resolve many literals at once more efficiently
+	/// can override it.
+	fn get_literals<'l>(
+		&self,
+		literals: impl IntoIterator<Item = LiteralRef<'l, Self::Iri>>,
+	) -> Vec<Option<Self::Literal>>
+	where
+		Self: Sized,
+		Self::Iri: 'l,
+	{
+		literals
+			.into_iter()
+			.map(|literal| self.get_literal(literal))
+			.collect()
+	}
 }
 
 impl<'a, V: LiteralVocabulary> LiteralVocabulary for &'a V {
@@ -28,6 +47,16 @@ impl<'a, V: LiteralVocabulary> LiteralVocabulary for &'a V {
 	fn get_literal(&self, id: LiteralRef<Self::Iri>) -> Option<Self::Literal> {
 		V::get_literal(*self, id)
 	}
+
+	fn get_literals<'l>(
+		&self,
+		literals: impl IntoIterator<Item = LiteralRef<'l, Self::Iri>>,
+	) -> Vec<Option<Self::Literal>>
+	where
+		Self::Iri: 'l,
+	{
+		V::get_literals(*self, literals)
+	}
 }
 
 impl<'a, V: LiteralVocabulary> LiteralVocabulary for &'a mut V {
@@ -44,6 +73,16 @@ impl<'a, V: LiteralVocabulary> LiteralVocabulary for &'a mut V {
 	fn get_literal(&self, id: LiteralRef<Self::Iri>) -> Option<Self::Literal> {
 		V::get_literal(*self, id)
 	}
+
+	fn get_literals<'l>(
+		&self,
+		literals: impl IntoIterator<Item = LiteralRef<'l, Self::Iri>>,
+	) -> Vec<Option<Self::Literal>>
+	where
+		Self::Iri: 'l,
+	{
+		V::get_literals(*self, literals)
+	}
 }
 
 /// Mutable literal value vocabulary.
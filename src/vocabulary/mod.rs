@@ -19,6 +19,9 @@ pub use literal::*;
 mod r#impl;
 pub use r#impl::*;
 
+mod lexical_ord;
+pub use lexical_ord::*;
+
 /// Vocabulary.
 ///
 /// A vocabulary is a collection that stores the lexical representation of
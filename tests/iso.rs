@@ -5,6 +5,11 @@ use static_iref::iri;
 use std::collections::BTreeMap;
 
 fn test(a: BTreeDataset, b: BTreeDataset) {
+	assert_eq!(
+		rdf_types::dataset::isomorphism::dataset_signature(&a),
+		rdf_types::dataset::isomorphism::dataset_signature(&b)
+	);
+
 	match rdf_types::dataset::isomorphism::find_bijection(&a, &b) {
 		Some(bijection) => {
 			let substitution: BTreeMap<_, _> = bijection
@@ -0,0 +1,65 @@
+use iref::IriBuf;
+use rdf_types::{
+	dataset::{BTreeDataset, SerializeRdf},
+	BlankIdBuf, Literal, LiteralType, Quad, Term, XSD_STRING,
+};
+use static_iref::iri;
+
+#[test]
+fn write_nquads() {
+	let s: Term = Term::iri(iri!("http://example.org/s").to_owned());
+	let p: Term = Term::iri(iri!("http://example.org/p").to_owned());
+	let g: Term = Term::iri(iri!("http://example.org/g").to_owned());
+
+	let mut dataset = BTreeDataset::new();
+	dataset.insert(Quad(
+		s.clone(),
+		p.clone(),
+		Term::iri(iri!("http://example.org/o").to_owned()),
+		None,
+	));
+	dataset.insert(Quad(
+		s.clone(),
+		p.clone(),
+		Term::Literal(Literal::new(
+			"hello \"world\"\n\\".to_owned(),
+			LiteralType::Any(XSD_STRING.to_owned()),
+		)),
+		None,
+	));
+	dataset.insert(Quad(
+		s,
+		p.clone(),
+		Term::Literal(Literal::new(
+			"bonjour".to_owned(),
+			LiteralType::LangString("fr".parse().unwrap()),
+		)),
+		Some(g),
+	));
+	dataset.insert(Quad(
+		Term::blank(BlankIdBuf::from_suffix("b0").unwrap()),
+		p.clone(),
+		Term::Literal(Literal::new(
+			"42".to_owned(),
+			LiteralType::Any(iri!("http://www.w3.org/2001/XMLSchema#integer").to_owned()),
+		)),
+		Some(Term::blank(BlankIdBuf::from_suffix("g").unwrap())),
+	));
+
+	// Not a valid IRI, but IRIs coming from lenient sources must still be
+	// written as valid N-Quads.
+	let unescaped = unsafe { IriBuf::new_unchecked("http://example.org/a b<c>".to_owned()) };
+	dataset.insert(Quad(
+		Term::iri(unescaped),
+		p,
+		Term::iri(iri!("http://example.org/o").to_owned()),
+		None,
+	));
+
+	let mut buffer = Vec::new();
+	dataset.write_nquads(&mut buffer).unwrap();
+	assert_eq!(
+		String::from_utf8(buffer).unwrap(),
+		include_str!("serialize.nq")
+	)
+}